@@ -0,0 +1,3 @@
+DROP FUNCTION IF EXISTS generate_ulid();
+
+DROP EXTENSION IF EXISTS pgcrypto;
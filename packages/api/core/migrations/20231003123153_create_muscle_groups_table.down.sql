@@ -0,0 +1 @@
+DROP TABLE IF EXISTS muscle_groups;
@@ -0,0 +1,15 @@
+DELETE FROM muscle_groups WHERE name IN (
+    'Arms',
+    'Calves',
+    'Chest',
+    'Core',
+    'Forearms',
+    'Glutes',
+    'Hamstrings',
+    'Hands',
+    'Lats',
+    'Lower Back',
+    'Quadriceps',
+    'Shoulders',
+    'Upper Back'
+);
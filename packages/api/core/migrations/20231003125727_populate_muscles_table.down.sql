@@ -0,0 +1,48 @@
+DELETE FROM muscles WHERE name IN (
+    'Biceps Brachii',
+    'Triceps Brachii',
+    'Calves',
+    'Pectoralis',
+    'Abdominals',
+    'Forearms',
+    'Glutes',
+    'Hamstrings',
+    'Palmar Fascia',
+    'Latissimus Dorsi',
+    'Lower Back',
+    'Shoulders',
+    'Quadriceps',
+    'Rhomboids',
+    'Trapezius',
+    'Biceps Brachii, Long Head',
+    'Biceps Brachii, Short Head',
+    'Triceps Brachii, Long Head',
+    'Triceps Brachii, Lateral Head',
+    'Triceps Brachii, Medial Head',
+    'Tibialis',
+    'Soleus',
+    'Gastrocnemius',
+    'Obliques',
+    'Rectus Abdominis',
+    'Transversus Abdominis',
+    'Pectoralis Major, Clavicular Head',
+    'Pectoralis Major, Sternocostal Head',
+    'Wrist Extensors',
+    'Wrist Flexors',
+    'Gluteus Maximus',
+    'Gluteus Medius',
+    'Hip Abductors',
+    'Medial Hamstrings',
+    'Lateral Hamstrings',
+    'Vastus Intermedius',
+    'Vastus Lateralis',
+    'Vastus Medialis',
+    'Rectus Femoris',
+    'Hip Adductors',
+    'Lateral Deltoid',
+    'Anterior Deltoid',
+    'Posterior Deltoid',
+    'Trapezius, Superior',
+    'Trapezius, Inferior',
+    'Serratus Anterior'
+);
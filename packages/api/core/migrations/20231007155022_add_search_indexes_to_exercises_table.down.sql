@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS exercises_name_alternative_trgm_idx;
+DROP INDEX IF EXISTS exercises_name_trgm_idx;
+DROP INDEX IF EXISTS exercises_search_vector_idx;
+
+ALTER TABLE exercises DROP COLUMN IF EXISTS search_vector;
+
+DROP EXTENSION IF EXISTS pg_trgm;
@@ -0,0 +1,13 @@
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+ALTER TABLE exercises ADD COLUMN IF NOT EXISTS search_vector TSVECTOR GENERATED ALWAYS AS (
+    setweight(to_tsvector('english', coalesce(name, '')), 'A') ||
+    setweight(to_tsvector('english', coalesce(name_alternative, '')), 'A') ||
+    setweight(to_tsvector('english', coalesce(description, '')), 'C')
+) STORED;
+
+CREATE INDEX IF NOT EXISTS exercises_search_vector_idx ON exercises USING GIN (search_vector);
+
+-- Trigram indexes for typo tolerant matching of names
+CREATE INDEX IF NOT EXISTS exercises_name_trgm_idx ON exercises USING GIN (name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS exercises_name_alternative_trgm_idx ON exercises USING GIN (name_alternative gin_trgm_ops);
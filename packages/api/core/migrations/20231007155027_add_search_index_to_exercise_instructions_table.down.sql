@@ -0,0 +1 @@
+DROP INDEX IF EXISTS exercise_instructions_content_search_idx;
@@ -0,0 +1,2 @@
+CREATE INDEX IF NOT EXISTS exercise_instructions_content_search_idx
+    ON exercise_instructions USING GIN (to_tsvector('english', content));
@@ -0,0 +1,20 @@
+DELETE FROM exercise_equipment WHERE name IN (
+    'barbell',
+    'bodyweight',
+    'bosu_ball',
+    'cable_machine',
+    'cardio_machine',
+    'dumbbell',
+    'exercise_ball',
+    'ez_bar',
+    'kettlebell',
+    'medicine_ball',
+    'plate',
+    'resistance_band',
+    'smith_machine',
+    'trap_bar',
+    'trx',
+    'weight_machine',
+    'yoga',
+    'other'
+);
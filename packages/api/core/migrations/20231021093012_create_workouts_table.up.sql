@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS workouts (
+    id SERIAL PRIMARY KEY NOT NULL,
+    ulid VARCHAR UNIQUE DEFAULT generate_ulid() NOT NULL,
+    user_id SMALLINT REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    name VARCHAR,
+    notes TEXT,
+    started_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+    finished_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL
+);
+
+CREATE INDEX workouts_user_id_idx ON workouts (user_id);
@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS workout_exercises (
+    id SERIAL PRIMARY KEY NOT NULL,
+    ulid VARCHAR UNIQUE DEFAULT generate_ulid() NOT NULL,
+    workout_id INTEGER REFERENCES workouts(id) ON DELETE CASCADE NOT NULL,
+    exercise_id SMALLINT REFERENCES exercises(id) ON DELETE CASCADE NOT NULL,
+    sequence_number SMALLINT NOT NULL,
+    notes TEXT,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+
+    UNIQUE (workout_id, sequence_number)
+);
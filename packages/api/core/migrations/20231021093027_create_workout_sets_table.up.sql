@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS workout_sets (
+    id SERIAL PRIMARY KEY NOT NULL,
+    ulid VARCHAR UNIQUE DEFAULT generate_ulid() NOT NULL,
+    workout_exercise_id INTEGER REFERENCES workout_exercises(id) ON DELETE CASCADE NOT NULL,
+    sequence_number SMALLINT NOT NULL,
+    weight DOUBLE PRECISION,
+    repetitions SMALLINT,
+    duration INTEGER,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+
+    UNIQUE (workout_exercise_id, sequence_number)
+);
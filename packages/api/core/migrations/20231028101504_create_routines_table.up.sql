@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS routines (
+    id SERIAL PRIMARY KEY NOT NULL,
+    ulid VARCHAR UNIQUE DEFAULT generate_ulid() NOT NULL,
+    user_id SMALLINT REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    name VARCHAR NOT NULL,
+    description TEXT,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+
+    UNIQUE (user_id, name)
+);
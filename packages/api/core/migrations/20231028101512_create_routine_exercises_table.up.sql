@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS routine_exercises (
+    id SERIAL PRIMARY KEY NOT NULL,
+    ulid VARCHAR UNIQUE DEFAULT generate_ulid() NOT NULL,
+    routine_id INTEGER REFERENCES routines(id) ON DELETE CASCADE NOT NULL,
+    exercise_id SMALLINT REFERENCES exercises(id) ON DELETE CASCADE NOT NULL,
+    sequence_number SMALLINT NOT NULL,
+    target_sets SMALLINT NOT NULL CHECK (target_sets > 0),
+    target_repetitions SMALLINT CHECK (target_repetitions > 0),
+    target_weight DOUBLE PRECISION CHECK (target_weight >= 0),
+    -- Rest time between sets, in seconds
+    rest_seconds INTEGER CHECK (rest_seconds >= 0),
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+
+    UNIQUE (routine_id, sequence_number)
+);
@@ -0,0 +1,8 @@
+ALTER TABLE workout_exercises
+    DROP COLUMN IF EXISTS rest_seconds,
+    DROP COLUMN IF EXISTS target_weight,
+    DROP COLUMN IF EXISTS target_repetitions,
+    DROP COLUMN IF EXISTS target_sets;
+
+ALTER TABLE workouts
+    DROP COLUMN IF EXISTS routine_id;
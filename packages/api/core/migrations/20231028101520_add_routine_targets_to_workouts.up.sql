@@ -0,0 +1,8 @@
+ALTER TABLE workouts
+    ADD COLUMN IF NOT EXISTS routine_id INTEGER REFERENCES routines(id) ON DELETE SET NULL;
+
+ALTER TABLE workout_exercises
+    ADD COLUMN IF NOT EXISTS target_sets SMALLINT,
+    ADD COLUMN IF NOT EXISTS target_repetitions SMALLINT,
+    ADD COLUMN IF NOT EXISTS target_weight DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS rest_seconds INTEGER;
@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS programs (
+    id SERIAL PRIMARY KEY NOT NULL,
+    ulid VARCHAR UNIQUE DEFAULT generate_ulid() NOT NULL,
+    user_id SMALLINT REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    name VARCHAR NOT NULL,
+    description TEXT,
+    weeks SMALLINT NOT NULL CHECK (weeks > 0),
+    -- Load added each week to compound lifts, in kilograms
+    weekly_increment DOUBLE PRECISION DEFAULT 2.5 NOT NULL CHECK (weekly_increment >= 0),
+    -- Every Nth week is a deload week; NULL disables deloads
+    deload_every SMALLINT CHECK (deload_every > 1),
+    -- Fraction of the working load used during a deload week
+    deload_factor DOUBLE PRECISION DEFAULT 0.6 NOT NULL CHECK (deload_factor > 0 AND deload_factor <= 1),
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL
+);
@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS program_days (
+    id SERIAL PRIMARY KEY NOT NULL,
+    ulid VARCHAR UNIQUE DEFAULT generate_ulid() NOT NULL,
+    program_id INTEGER REFERENCES programs(id) ON DELETE CASCADE NOT NULL,
+    routine_id INTEGER REFERENCES routines(id) ON DELETE CASCADE NOT NULL,
+    week_number SMALLINT NOT NULL CHECK (week_number > 0),
+    day_number SMALLINT NOT NULL CHECK (day_number BETWEEN 1 AND 7),
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+
+    UNIQUE (program_id, week_number, day_number)
+);
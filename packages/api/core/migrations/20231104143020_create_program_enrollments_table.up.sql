@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS program_enrollments (
+    id SERIAL PRIMARY KEY NOT NULL,
+    ulid VARCHAR UNIQUE DEFAULT generate_ulid() NOT NULL,
+    program_id INTEGER REFERENCES programs(id) ON DELETE CASCADE NOT NULL,
+    user_id SMALLINT REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    started_on DATE NOT NULL,
+    ended_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL
+);
+
+-- A user may only have one active enrollment per program
+CREATE UNIQUE INDEX IF NOT EXISTS program_enrollments_active_idx
+    ON program_enrollments (program_id, user_id)
+    WHERE ended_at IS NULL;
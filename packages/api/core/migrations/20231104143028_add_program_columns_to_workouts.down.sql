@@ -0,0 +1,3 @@
+ALTER TABLE workouts
+    DROP COLUMN IF EXISTS program_day_id,
+    DROP COLUMN IF EXISTS program_enrollment_id;
@@ -0,0 +1,3 @@
+ALTER TABLE workouts
+    ADD COLUMN IF NOT EXISTS program_enrollment_id INTEGER REFERENCES program_enrollments(id) ON DELETE SET NULL,
+    ADD COLUMN IF NOT EXISTS program_day_id INTEGER REFERENCES program_days(id) ON DELETE SET NULL;
@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS personal_records (
+    id SERIAL PRIMARY KEY NOT NULL,
+    ulid VARCHAR UNIQUE DEFAULT generate_ulid() NOT NULL,
+    user_id SMALLINT REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    exercise_id SMALLINT REFERENCES exercises(id) ON DELETE CASCADE NOT NULL,
+    workout_set_id INTEGER REFERENCES workout_sets(id) ON DELETE CASCADE NOT NULL,
+    type VARCHAR NOT NULL,
+    value DOUBLE PRECISION NOT NULL,
+    weight DOUBLE PRECISION,
+    achieved_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL
+);
+
+CREATE INDEX personal_records_user_id_exercise_id_type_idx ON personal_records (user_id, exercise_id, type);
@@ -0,0 +1 @@
+ALTER TABLE user_profiles ADD COLUMN bodyweight DOUBLE PRECISION CHECK (bodyweight > 0);
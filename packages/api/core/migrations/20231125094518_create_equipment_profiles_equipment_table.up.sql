@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS equipment_profiles_equipment (
+    equipment_profile_id INTEGER REFERENCES equipment_profiles(id) ON DELETE CASCADE NOT NULL,
+    equipment_id SMALLINT REFERENCES exercise_equipment(id) ON DELETE CASCADE NOT NULL,
+
+    PRIMARY KEY (equipment_profile_id, equipment_id)
+);
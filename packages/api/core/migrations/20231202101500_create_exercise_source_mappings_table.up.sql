@@ -0,0 +1,103 @@
+CREATE TABLE IF NOT EXISTS exercise_source_mappings (
+    id SERIAL PRIMARY KEY NOT NULL,
+    source VARCHAR NOT NULL,
+    model_name VARCHAR NOT NULL,
+    model_id SMALLINT NOT NULL,
+    external_id SMALLINT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+
+    UNIQUE (source, model_name, external_id)
+);
+
+INSERT INTO exercise_source_mappings (source, model_name, model_id, external_id)
+SELECT 'musclewiki', 'exercise_equipment', exercise_equipment.id, mappings.external_id
+FROM (VALUES
+    (10, 'resistance_band'),
+    (1, 'barbell'),
+    (3, 'bodyweight'),
+    (24, 'bosu_ball'),
+    (9, 'cable_machine'),
+    (27, 'cardio_machine'),
+    (2, 'dumbbell'),
+    (7, 'kettlebell'),
+    (4, 'weight_machine'),
+    (6, 'medicine_ball'),
+    (11, 'plate'),
+    (85, 'smith_machine'),
+    (13, 'yoga'),
+    (12, 'trx'),
+    (26, 'other')
+) AS mappings (external_id, name)
+JOIN exercise_equipment ON exercise_equipment.name = mappings.name;
+
+INSERT INTO exercise_source_mappings (source, model_name, model_id, external_id)
+SELECT 'musclewiki', 'muscle_groups', muscle_groups.id, mappings.external_id
+FROM (VALUES
+    (1, 'Arms'),
+    (2, 'Upper Back'),
+    (3, 'Lower Back'),
+    (4, 'Core'),
+    (5, 'Calves'),
+    (6, 'Forearms'),
+    (7, 'Glutes'),
+    (8, 'Hamstrings'),
+    (9, 'Lats'),
+    (10, 'Shoulders'),
+    (11, 'Arms'),
+    (12, 'Upper Back'),
+    (13, 'Quadriceps'),
+    (14, 'Chest'),
+    (15, 'Core'),
+    (16, 'Hands')
+) AS mappings (external_id, name)
+JOIN muscle_groups ON muscle_groups.name = mappings.name;
+
+INSERT INTO exercise_source_mappings (source, model_name, model_id, external_id)
+SELECT 'musclewiki', 'muscles', muscles.id, mappings.external_id
+FROM (VALUES
+    (1, 'Biceps Brachii'),
+    (16, 'Biceps Brachii, Long Head'),
+    (17, 'Biceps Brachii, Short Head'),
+    (14, 'Trapezius'),
+    (13, 'Lower Back'),
+    (12, 'Abdominals'),
+    (21, 'Transversus Abdominis'),
+    (22, 'Rectus Abdominis'),
+    (11, 'Calves'),
+    (31, 'Tibialis'),
+    (32, 'Soleus'),
+    (33, 'Gastrocnemius'),
+    (10, 'Forearms'),
+    (25, 'Wrist Extensors'),
+    (26, 'Wrist Flexors'),
+    (9, 'Glutes'),
+    (37, 'Gluteus Medius'),
+    (38, 'Gluteus Maximus'),
+    (8, 'Hamstrings'),
+    (39, 'Medial Hamstrings'),
+    (40, 'Lateral Hamstrings'),
+    (7, 'Latissimus Dorsi'),
+    (6, 'Shoulders'),
+    (18, 'Lateral Deltoid'),
+    (19, 'Anterior Deltoid'),
+    (20, 'Posterior Deltoid'),
+    (5, 'Triceps Brachii'),
+    (34, 'Triceps Brachii, Long Head'),
+    (35, 'Triceps Brachii, Lateral Head'),
+    (36, 'Triceps Brachii, Medial Head'),
+    (4, 'Trapezius'),
+    (41, 'Trapezius, Superior'),
+    (42, 'Trapezius, Inferior'),
+    (3, 'Quadriceps'),
+    (27, 'Hip Adductors'),
+    (28, 'Vastus Medialis'),
+    (29, 'Vastus Lateralis'),
+    (30, 'Rectus Femoris'),
+    (2, 'Pectoralis'),
+    (23, 'Pectoralis Major, Clavicular Head'),
+    (24, 'Pectoralis Major, Sternocostal Head'),
+    (15, 'Obliques'),
+    (43, 'Palmar Fascia')
+) AS mappings (external_id, name)
+JOIN muscles ON muscles.name = mappings.name;
@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS sync_runs (
+    id SERIAL PRIMARY KEY NOT NULL,
+    source VARCHAR NOT NULL,
+    status VARCHAR NOT NULL,
+    dry_run BOOLEAN DEFAULT false NOT NULL,
+    next_offset INTEGER DEFAULT 0 NOT NULL,
+    created_count INTEGER DEFAULT 0 NOT NULL,
+    updated_count INTEGER DEFAULT 0 NOT NULL,
+    unchanged_count INTEGER DEFAULT 0 NOT NULL,
+    failed_count INTEGER DEFAULT 0 NOT NULL,
+    finished_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL
+);
+
+CREATE INDEX sync_runs_source_status_idx ON sync_runs (source, status);
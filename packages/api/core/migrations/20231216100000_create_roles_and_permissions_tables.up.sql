@@ -0,0 +1,21 @@
+CREATE TABLE IF NOT EXISTS roles (
+    id SMALLSERIAL PRIMARY KEY NOT NULL,
+    name VARCHAR UNIQUE NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS permissions (
+    id SMALLSERIAL PRIMARY KEY NOT NULL,
+    name VARCHAR UNIQUE NOT NULL,
+    description VARCHAR,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS roles_permissions (
+    role_id SMALLINT REFERENCES roles(id) ON DELETE CASCADE NOT NULL,
+    permission_id SMALLINT REFERENCES permissions(id) ON DELETE CASCADE NOT NULL,
+
+    PRIMARY KEY (role_id, permission_id)
+);
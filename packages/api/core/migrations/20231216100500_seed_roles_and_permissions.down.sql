@@ -0,0 +1,5 @@
+ALTER TABLE users DROP CONSTRAINT IF EXISTS users_role_fkey;
+
+DELETE FROM roles_permissions;
+DELETE FROM permissions;
+DELETE FROM roles;
@@ -0,0 +1,38 @@
+INSERT INTO roles (name)
+VALUES ('admin'), ('coach'), ('moderator'), ('user');
+
+INSERT INTO permissions (name, description)
+VALUES
+    ('admin.access', 'Sign in through the admin login'),
+    ('equipment.manage', 'Create, update and delete exercise equipment'),
+    ('exercise.create', 'Create exercises'),
+    ('exercise.delete', 'Delete exercises'),
+    ('exercise.update', 'Update exercises'),
+    ('link.manage', 'Create, update and delete links'),
+    ('muscle.manage', 'Create, update and delete muscles and muscle groups'),
+    ('user.manage', 'Manage other users and their roles'),
+    ('workout.view_others', 'View the workouts of other users');
+
+INSERT INTO roles_permissions (role_id, permission_id)
+SELECT roles.id, permissions.id
+FROM (VALUES
+    ('admin', 'admin.access'),
+    ('admin', 'equipment.manage'),
+    ('admin', 'exercise.create'),
+    ('admin', 'exercise.delete'),
+    ('admin', 'exercise.update'),
+    ('admin', 'link.manage'),
+    ('admin', 'muscle.manage'),
+    ('admin', 'user.manage'),
+    ('admin', 'workout.view_others'),
+    ('moderator', 'admin.access'),
+    ('moderator', 'equipment.manage'),
+    ('moderator', 'exercise.create'),
+    ('moderator', 'exercise.update'),
+    ('moderator', 'link.manage')
+) AS grants (role, permission)
+JOIN roles ON roles.name = grants.role
+JOIN permissions ON permissions.name = grants.permission;
+
+ALTER TABLE users
+    ADD CONSTRAINT users_role_fkey FOREIGN KEY (role) REFERENCES roles(name) ON UPDATE CASCADE;
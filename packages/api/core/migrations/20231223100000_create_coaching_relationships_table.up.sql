@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS coaching_relationships (
+    id SERIAL PRIMARY KEY NOT NULL,
+    ulid VARCHAR UNIQUE DEFAULT generate_ulid() NOT NULL,
+    coach_id SMALLINT REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    client_id SMALLINT REFERENCES users(id) ON DELETE CASCADE NOT NULL,
+    status VARCHAR DEFAULT 'pending' NOT NULL,
+    responded_at TIMESTAMP WITH TIME ZONE,
+    ended_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT now() NOT NULL,
+    CHECK (coach_id <> client_id)
+);
+
+-- A coach may only have one open invitation or relationship per client
+CREATE UNIQUE INDEX IF NOT EXISTS coaching_relationships_open_idx
+    ON coaching_relationships (coach_id, client_id)
+    WHERE status IN ('pending', 'accepted');
+
+CREATE INDEX IF NOT EXISTS coaching_relationships_client_id_idx ON coaching_relationships (client_id);
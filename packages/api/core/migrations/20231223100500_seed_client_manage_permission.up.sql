@@ -0,0 +1,7 @@
+INSERT INTO permissions (name, description)
+VALUES ('client.manage', 'Invite clients and program for them');
+
+INSERT INTO roles_permissions (role_id, permission_id)
+SELECT roles.id, permissions.id
+FROM roles, permissions
+WHERE roles.name IN ('admin', 'coach') AND permissions.name = 'client.manage';
@@ -5,14 +5,44 @@ use axum::http::StatusCode;
 
 #[derive(Debug, strum_macros::Display, thiserror::Error)]
 pub enum Error {
+    AlreadyCoachingClient,
+    AlreadyEnrolledInProgram,
+    CannotCoachSelf,
+    ExerciseNotWeightedRepetitions,
+    InvalidEquipment(Vec<String>),
     InvalidPasswordFormat(Vec<String>),
+    InvalidProgramSchedule(Vec<String>),
+    InvalidVolumeRange,
+    InvalidWorkoutSet(Vec<String>),
+    NoScheduledProgramDay,
     PasswordMismatch,
     UserWithEmailAlreadyExists,
+    WorkoutAlreadyFinished,
 }
 
 impl From<Error> for crate::http::Error {
     fn from(error: Error) -> Self {
         match &error {
+            Error::AlreadyCoachingClient => Self::conflict(ClientError::Conflict, Domain::Coaching)
+                .with_message(__("errors.coaching.alreadyInvited")),
+            Error::AlreadyEnrolledInProgram => Self::conflict(ClientError::Conflict, Domain::Program)
+                .with_message(__("errors.program.alreadyEnrolled")),
+            Error::CannotCoachSelf => {
+                let mut map = ErrorMap::new();
+                map.insert("email".to_string(), vec![__("errors.coaching.cannotCoachSelf")]);
+
+                Self::unprocessable(ClientError::Validation, Domain::Coaching)
+                    .with_messages(map)
+            },
+            Error::ExerciseNotWeightedRepetitions => Self::unprocessable(ClientError::Validation, Domain::Analytics)
+                .with_message(__("errors.analytics.notWeightedRepetitions")),
+            Error::InvalidEquipment(messages) => {
+                let mut map = ErrorMap::new();
+                map.insert("equipment_ids".to_string(), messages.clone());
+
+                Self::unprocessable(ClientError::Validation, Domain::Equipment)
+                    .with_messages(map)
+            },
             Error::InvalidPasswordFormat(messages) => {
                 let mut map = ErrorMap::new();
                 map.insert("password".to_string(), messages.clone());
@@ -20,9 +50,34 @@ impl From<Error> for crate::http::Error {
                 Self::unprocessable(ClientError::Validation, Domain::UserRegistration)
                     .with_messages(map)
             },
+            Error::InvalidProgramSchedule(messages) => {
+                let mut map = ErrorMap::new();
+                map.insert("days".to_string(), messages.clone());
+
+                Self::unprocessable(ClientError::Validation, Domain::Program)
+                    .with_messages(map)
+            },
+            Error::InvalidVolumeRange => {
+                let mut map = ErrorMap::new();
+                map.insert("from".to_string(), vec![__("errors.analytics.invalidVolumeRange")]);
+
+                Self::unprocessable(ClientError::Validation, Domain::Analytics)
+                    .with_messages(map)
+            },
+            Error::InvalidWorkoutSet(messages) => {
+                let mut map = ErrorMap::new();
+                map.insert("set".to_string(), messages.clone());
+
+                Self::unprocessable(ClientError::Validation, Domain::Workout)
+                    .with_messages(map)
+            },
+            Error::NoScheduledProgramDay => Self::not_found(ClientError::ResourceNotFound, Domain::Program)
+                .with_message(__("errors.program.noScheduledDay")),
             Error::PasswordMismatch => Self::unprocessable(ClientError::Validation, Domain::UserRegistration)
                 .with_message(__("errors.auth.passwordMismatch")),
-            Error::UserWithEmailAlreadyExists => Self::unprocessable(ClientError::Validation, Domain::UserRegistration)
+            Error::UserWithEmailAlreadyExists => Self::unprocessable(ClientError::Validation, Domain::UserRegistration),
+            Error::WorkoutAlreadyFinished => Self::conflict(ClientError::Conflict, Domain::Workout)
+                .with_message(__("errors.workout.alreadyFinished")),
         }
     }
 }
@@ -1,19 +1,45 @@
 mod errors;
 pub mod services;
 
-use crate::data::{CreateUserData, CreateUserProfileData};
-use crate::enums::Role;
-use crate::models::{Exercise, Profile, User};
-use crate::utils::{crypt, validators};
-use database::DatabaseManager;
+use crate::data::{
+    CreateProgramData,
+    CreateRoutineData,
+    CreateUserData,
+    CreateUserProfileData,
+    EquipmentProfileData,
+    LogWorkoutSetData,
+};
+use crate::enums::{Measurement, OneRepMaxFormula, Role, VolumeGranularity};
+use crate::models::{
+    CoachingRelationship,
+    EquipmentProfile,
+    Exercise,
+    ExerciseEquipment,
+    MuscleGroupVolume,
+    MuscleVolume,
+    PersonalRecord,
+    Profile,
+    Program,
+    ProgramDay,
+    ProgramEnrollment,
+    Routine,
+    RoutineExercise,
+    User,
+    Workout,
+    WorkoutExercise,
+    WorkoutSet,
+};
+use chrono::NaiveDate;
+use crate::utils::{crypt, strength, validators};
+use database::{Connection, DatabaseManager, Model};
 
 pub(self) use errors::Error;
 pub(self) type Result<TValue> = core::result::Result<TValue, crate::http::Error>;
 
-pub async fn create_user(data: CreateUserData<'_>, database: &DatabaseManager) -> Result<User> {
+pub async fn create_user(data: CreateUserData<'_>, mut database: impl Connection) -> Result<User> {
     use validators::ValidatorResult;
 
-    if User::exists_with_email(data.email, database).await? {
+    if User::exists_with_email(data.email, &mut database).await? {
         return Err(Error::UserWithEmailAlreadyExists)?;
     }
 
@@ -32,19 +58,386 @@ pub async fn create_user(data: CreateUserData<'_>, database: &DatabaseManager) -
         .email(data.email)
         .role(data.role.unwrap_or_default())
         .password(hash)
-        .create(&database)
+        .create(database)
         .await?;
 
     Ok(user)
 }
 
-pub async fn create_user_profile(data: CreateUserProfileData, database: &DatabaseManager) -> Result<Profile> {
+pub async fn create_user_profile(data: CreateUserProfileData, database: impl Connection) -> Result<Profile> {
     let profile = Profile::new()
         .user_id(data.user_id)
         .birthday(data.birthday)
         .gender(data.gender)
-        .create(&database)
+        .bodyweight(data.bodyweight)
+        .create(database)
         .await?;
 
     Ok(profile)
 }
+
+pub async fn add_workout_exercise(
+    workout: &Workout,
+    exercise: &Exercise,
+    notes: Option<String>,
+    database: &DatabaseManager,
+) -> Result<WorkoutExercise> {
+    if workout.is_finished() {
+        return Err(Error::WorkoutAlreadyFinished)?;
+    }
+
+    let workout_exercise = WorkoutExercise::new()
+        .workout(workout)
+        .exercise(exercise)
+        .sequence_number(workout.next_exercise_sequence_number(database).await?)
+        .notes(notes)
+        .create(database)
+        .await?;
+
+    Ok(workout_exercise)
+}
+
+pub async fn log_workout_set(
+    workout: &Workout,
+    workout_exercise: &WorkoutExercise,
+    data: LogWorkoutSetData,
+    mut database: impl Connection,
+) -> Result<WorkoutSet> {
+    use validators::ValidatorResult;
+
+    if workout.is_finished() {
+        return Err(Error::WorkoutAlreadyFinished)?;
+    }
+
+    let exercise = workout_exercise.exercise(&mut database).await?;
+
+    if let ValidatorResult::Invalid(messages) = validators::workout_set(
+        exercise.measurement.as_ref(),
+        data.weight,
+        data.repetitions,
+        data.duration,
+    ) {
+        return Err(Error::InvalidWorkoutSet(messages))?;
+    }
+
+    // Holding the workout exercise keeps concurrent sets from taking the same
+    // sequence number, or from both being counted as a new record.
+    workout_exercise.lock(&mut database).await?;
+
+    let set = WorkoutSet::new()
+        .workout_exercise(workout_exercise)
+        .sequence_number(workout_exercise.next_set_sequence_number(&mut database).await?)
+        .weight(data.weight)
+        .repetitions(data.repetitions)
+        .duration(data.duration)
+        .create(&mut database)
+        .await?;
+
+    detect_personal_records(workout.user_id, &exercise, &set, &mut database).await?;
+
+    Ok(set)
+}
+
+/// Stores every record the set beats for the user and exercise,
+/// returning the new records.
+pub async fn detect_personal_records(
+    user_id: i16,
+    exercise: &Exercise,
+    set: &WorkoutSet,
+    mut database: impl Connection,
+) -> Result<Vec<PersonalRecord>> {
+    let mut records = Vec::new();
+
+    for (record_type, value, weight) in PersonalRecord::candidates(exercise.measurement.as_ref(), set) {
+        let best = PersonalRecord::best(user_id, exercise.id, &record_type, weight, &mut database).await?;
+
+        if best.is_some_and(|best| best.value >= value) {
+            continue;
+        }
+
+        records.push(PersonalRecord::new()
+            .user_id(user_id)
+            .exercise(exercise)
+            .workout_set(set)
+            .record(record_type, value)
+            .weight(weight)
+            .create(&mut database)
+            .await?
+        );
+    }
+
+    Ok(records)
+}
+
+/// Returns the best estimated one rep max of each day the user
+/// logged a set of the exercise, oldest first.
+pub async fn one_rep_max_trend(
+    user: &User,
+    exercise: &Exercise,
+    formula: &OneRepMaxFormula,
+    database: &DatabaseManager,
+) -> Result<Vec<(NaiveDate, f64)>> {
+    if exercise.measurement != Some(Measurement::WeightedRepetitions) {
+        return Err(Error::ExerciseNotWeightedRepetitions)?;
+    }
+
+    let mut trend: Vec<(NaiveDate, f64)> = Vec::new();
+
+    for set in WorkoutSet::for_user_exercise(user.id, exercise.id, database).await? {
+        let estimate = match (set.weight, set.repetitions) {
+            (Some(weight), Some(repetitions)) => strength::one_rep_max(weight, repetitions, formula),
+            _ => None,
+        };
+        let date = set.created_at.date_naive();
+
+        match (trend.last_mut(), estimate) {
+            (Some((last_date, best)), Some(estimate)) if *last_date == date => *best = best.max(estimate),
+            (_, Some(estimate)) => trend.push((date, estimate)),
+            (_, None) => continue,
+        }
+    }
+
+    Ok(trend)
+}
+
+/// Returns the user's training volume per muscle and per muscle group
+/// for each period between two dates, inclusive.
+pub async fn volume_report(
+    user: &User,
+    from: NaiveDate,
+    to: NaiveDate,
+    granularity: &VolumeGranularity,
+    database: &DatabaseManager,
+) -> Result<(Vec<MuscleVolume>, Vec<MuscleGroupVolume>)> {
+    if from > to {
+        return Err(Error::InvalidVolumeRange)?;
+    }
+
+    let muscles = WorkoutSet::volume_by_muscle(user.id, from, to, granularity, database).await?;
+    let muscle_groups = WorkoutSet::volume_by_muscle_group(user.id, from, to, granularity, database).await?;
+
+    Ok((muscles, muscle_groups))
+}
+
+pub async fn finish_workout(workout: &mut Workout, database: &DatabaseManager) -> Result<()> {
+    if workout.is_finished() {
+        return Err(Error::WorkoutAlreadyFinished)?;
+    }
+
+    workout.finish(database).await?;
+
+    Ok(())
+}
+
+pub async fn create_routine(user: &User, data: CreateRoutineData, mut database: impl Connection) -> Result<Routine> {
+    let routine = Routine::new()
+        .user(user)
+        .name(data.name)
+        .description(data.description)
+        .create(&mut database)
+        .await?;
+
+    for (index, exercise) in data.exercises.into_iter().enumerate() {
+        RoutineExercise::new()
+            .routine(&routine)
+            .exercise_id(exercise.exercise_id)
+            .sequence_number(index as i16 + 1)
+            .target_sets(exercise.target_sets)
+            .target_repetitions(exercise.target_repetitions)
+            .target_weight(exercise.target_weight)
+            .rest_seconds(exercise.rest_seconds)
+            .create(&mut database)
+            .await?;
+    }
+
+    Ok(routine)
+}
+
+pub async fn start_workout_from_routine(user: &User, routine: &Routine, mut database: impl Connection) -> Result<Workout> {
+    let workout = Workout::new()
+        .user(user)
+        .routine(routine)
+        .name(Some(routine.name.clone()))
+        .create(&mut database)
+        .await?;
+
+    for routine_exercise in routine.exercises(&mut database).await? {
+        WorkoutExercise::new()
+            .workout(&workout)
+            .exercise_id(routine_exercise.exercise_id)
+            .sequence_number(routine_exercise.sequence_number)
+            .target_sets(Some(routine_exercise.target_sets))
+            .target_repetitions(routine_exercise.target_repetitions)
+            .target_weight(routine_exercise.target_weight)
+            .rest_seconds(routine_exercise.rest_seconds)
+            .create(&mut database)
+            .await?;
+    }
+
+    Ok(workout)
+}
+
+pub async fn create_program(user: &User, data: CreateProgramData, mut database: impl Connection) -> Result<Program> {
+    let invalid_days = data.days.iter()
+        .filter(|day| day.week_number < 1 || day.week_number > data.weeks)
+        .map(|day| format!("Week {} is outside of the program's {} weeks", day.week_number, data.weeks))
+        .collect::<Vec<String>>();
+
+    if !invalid_days.is_empty() {
+        return Err(Error::InvalidProgramSchedule(invalid_days))?;
+    }
+
+    let program = Program::new()
+        .user(user)
+        .name(data.name)
+        .weeks(data.weeks)
+        .description(data.description)
+        .weekly_increment(data.weekly_increment)
+        .deload_every(data.deload_every)
+        .deload_factor(data.deload_factor)
+        .create(&mut database)
+        .await?;
+
+    for day in data.days {
+        ProgramDay::new()
+            .program(&program)
+            .routine_id(day.routine_id)
+            .schedule(day.week_number, day.day_number)
+            .create(&mut database)
+            .await?;
+    }
+
+    Ok(program)
+}
+
+pub async fn enroll_in_program(
+    user: &User,
+    program: &Program,
+    started_on: NaiveDate,
+    database: &DatabaseManager,
+) -> Result<ProgramEnrollment> {
+    if ProgramEnrollment::find_active(program.id, user.id, database).await?.is_some() {
+        return Err(Error::AlreadyEnrolledInProgram)?;
+    }
+
+    let enrollment = ProgramEnrollment::new()
+        .program(program)
+        .user(user)
+        .started_on(started_on)
+        .create(database)
+        .await?;
+
+    Ok(enrollment)
+}
+
+pub async fn start_program_day(
+    user: &User,
+    enrollment: &ProgramEnrollment,
+    today: NaiveDate,
+    mut database: impl Connection,
+) -> Result<Workout> {
+    let program = enrollment.program(&mut database).await?;
+    let day = enrollment.next_day(&program, today, &mut database)
+        .await?
+        .ok_or(Error::NoScheduledProgramDay)?;
+    let routine = day.routine(&mut database).await?;
+
+    let workout = Workout::new()
+        .user(user)
+        .routine(&routine)
+        .program_day(enrollment, &day)
+        .name(Some(routine.name.clone()))
+        .create(&mut database)
+        .await?;
+
+    for routine_exercise in routine.exercises(&mut database).await? {
+        let exercise = routine_exercise.exercise(&mut database).await?;
+
+        WorkoutExercise::new()
+            .workout(&workout)
+            .exercise(&exercise)
+            .sequence_number(routine_exercise.sequence_number)
+            .target_sets(Some(routine_exercise.target_sets))
+            .target_repetitions(routine_exercise.target_repetitions)
+            .target_weight(program.target_weight(
+                routine_exercise.target_weight,
+                exercise.mechanic.as_ref(),
+                day.week_number,
+            ))
+            .rest_seconds(routine_exercise.rest_seconds)
+            .create(&mut database)
+            .await?;
+    }
+
+    Ok(workout)
+}
+
+pub async fn create_equipment_profile(
+    user: &User,
+    data: EquipmentProfileData,
+    mut database: impl Connection,
+) -> Result<EquipmentProfile> {
+    validate_equipment_ids(&data.equipment_ids, &mut database).await?;
+
+    let profile = EquipmentProfile::new()
+        .user(user)
+        .name(data.name)
+        .create(&mut database)
+        .await?;
+
+    profile.sync_equipment(&data.equipment_ids, database).await?;
+
+    Ok(profile)
+}
+
+pub async fn update_equipment_profile(
+    profile: &mut EquipmentProfile,
+    data: EquipmentProfileData,
+    mut database: impl Connection,
+) -> Result<()> {
+    validate_equipment_ids(&data.equipment_ids, &mut database).await?;
+
+    profile.name = data.name;
+    profile.save(&mut database).await?;
+    profile.sync_equipment(&data.equipment_ids, database).await?;
+
+    Ok(())
+}
+
+pub async fn invite_client(coach: &User, email: &str, database: &DatabaseManager) -> Result<CoachingRelationship> {
+    let client = User::find_by_email(email, database).await?;
+
+    if client.id == coach.id {
+        return Err(Error::CannotCoachSelf)?;
+    }
+
+    if CoachingRelationship::find_open(coach.id, client.id, database).await?.is_some() {
+        return Err(Error::AlreadyCoachingClient)?;
+    }
+
+    let relationship = CoachingRelationship::new()
+        .coach(coach)
+        .client(&client)
+        .create(database)
+        .await?;
+
+    Ok(relationship)
+}
+
+async fn validate_equipment_ids(equipment_ids: &[i16], database: impl Connection) -> Result<()> {
+    let known = ExerciseEquipment::all(database)
+        .await?
+        .into_iter()
+        .map(|equipment| equipment.id)
+        .collect::<Vec<i16>>();
+    let unknown = equipment_ids.iter()
+        .filter(|id| !known.contains(id))
+        .map(|id| format!("No equipment exists with id {}", id))
+        .collect::<Vec<String>>();
+
+    if !unknown.is_empty() {
+        return Err(Error::InvalidEquipment(unknown))?;
+    }
+
+    Ok(())
+}
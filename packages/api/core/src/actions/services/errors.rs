@@ -0,0 +1,32 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Could not read {path}: {source}")]
+    ReadFailure {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("Could not fetch {url}: {source}")]
+    RequestFailure {
+        url: String,
+        source: reqwest::Error,
+    },
+    #[error("Could not parse exercises from {origin}: {message}")]
+    ParseFailure {
+        origin: String,
+        message: String,
+    },
+    #[error(transparent)]
+    Database(#[from] crate::models::Error),
+}
+
+impl From<sqlx::Error> for Error {
+    fn from(error: sqlx::Error) -> Self {
+        Self::Database(error.into())
+    }
+}
+
+impl From<database::Error> for Error {
+    fn from(error: database::Error) -> Self {
+        Self::Database(error.into())
+    }
+}
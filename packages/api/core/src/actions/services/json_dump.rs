@@ -0,0 +1,57 @@
+use super::musclewiki::{MuscleWiki, MWExercise, MWExerciseListResponse};
+use super::{Error, ExerciseSource, Result, SourceExercise};
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::path::Path;
+
+/// Loads exercises from a local dump of the MuscleWiki API, either a list of
+/// exercises or a single response from its exercise list endpoint. Dumps use
+/// MuscleWiki's ids, so they are imported with MuscleWiki's mappings.
+pub struct JsonDump {
+    exercises: Vec<MWExercise>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Dump {
+    Exercises(Vec<MWExercise>),
+    Response(MWExerciseListResponse),
+}
+
+impl JsonDump {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|source| Error::ReadFailure { path: path.display().to_string(), source })?;
+
+        Self::parse(&contents, &path.display().to_string())
+    }
+
+    pub fn parse(contents: &str, origin: &str) -> Result<Self> {
+        let dump = serde_json::from_str::<Dump>(contents)
+            .map_err(|error| Error::ParseFailure { origin: origin.to_string(), message: error.to_string() })?;
+
+        let exercises = match dump {
+            Dump::Exercises(exercises) => exercises,
+            Dump::Response(response) => response.results,
+        };
+
+        Ok(Self { exercises })
+    }
+}
+
+#[async_trait]
+impl ExerciseSource for JsonDump {
+    fn name(&self) -> &'static str {
+        MuscleWiki::NAME
+    }
+
+    async fn exercises(&self, limit: u16, offset: u32) -> Result<Vec<SourceExercise>> {
+        Ok(self.exercises.iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .cloned()
+            .map(SourceExercise::from)
+            .collect())
+    }
+}
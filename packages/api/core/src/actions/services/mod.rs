@@ -0,0 +1,608 @@
+mod errors;
+mod json_dump;
+mod musclewiki;
+mod source;
+
+use crate::enums::{ExerciseMuscleTarget, ExerciseType, SyncRunStatus, Table};
+use crate::models::{
+    Exercise,
+    ExerciseInstruction,
+    ExerciseMuscleMap,
+    ExerciseSourceMapping,
+    SyncRun,
+};
+use database::{Connection, DatabaseManager, Model, SqlxAction, TransactionManager};
+use std::collections::HashMap;
+
+pub use errors::Error;
+pub use json_dump::JsonDump;
+pub use musclewiki::MuscleWiki;
+pub use source::{ExerciseSource, SourceExercise};
+
+type Result<TValue> = core::result::Result<TValue, Error>;
+
+/// What a sync did, along with the run its counts were recorded in. The
+/// changes, failures and warnings only cover this invocation, while the run
+/// also counts whatever an interrupted run it resumed had done.
+#[derive(Debug)]
+pub struct SyncReport {
+    pub run: SyncRun,
+    pub resumed_from: Option<i32>,
+    pub changes: Vec<String>,
+    pub failures: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+enum Outcome {
+    Created,
+    Updated(Vec<&'static str>),
+    Unchanged,
+    Failed(String),
+}
+
+/// The local ids of a source's muscles, muscle groups and equipment, keyed
+/// by the source's own ids.
+#[derive(Debug, Default)]
+struct SourceMappings {
+    equipment: HashMap<i16, i16>,
+    muscle_groups: HashMap<i16, i16>,
+    muscles: HashMap<i16, i16>,
+}
+
+impl SourceMappings {
+    async fn load(source: &str, database: &DatabaseManager) -> Result<Self> {
+        let mut mappings = Self::default();
+
+        for mapping in ExerciseSourceMapping::for_source(source, database).await? {
+            let map = match mapping.model_name {
+                Table::ExerciseEquipment => &mut mappings.equipment,
+                Table::MuscleGroups => &mut mappings.muscle_groups,
+                Table::Muscles => &mut mappings.muscles,
+                _ => continue,
+            };
+
+            map.insert(mapping.external_id, mapping.model_id);
+        }
+
+        Ok(mappings)
+    }
+}
+
+/// Brings the exercise catalog in line with `source`, matching exercises on
+/// their external id. New exercises are created, and changed exercises have
+/// their columns, muscles and instructions updated. Picks up the last run
+/// that didn't complete from where it stopped. A dry run works out what
+/// would change without keeping any of it, its own run included.
+pub async fn sync_exercises(
+    source: &dyn ExerciseSource,
+    dry_run: bool,
+    database: &DatabaseManager,
+) -> Result<SyncReport> {
+    let mappings = SourceMappings::load(source.name(), database).await?;
+
+    // A dry run keeps its run and every page in one transaction that is never
+    // committed, so later exercises still see what earlier ones would have changed.
+    let mut dry_run = match dry_run {
+        true => Some(database.transaction().await?),
+        false => None,
+    };
+
+    let resumable = match dry_run {
+        Some(_) => None,
+        None => SyncRun::resumable(source.name(), database).await?,
+    };
+
+    let mut report = SyncReport {
+        resumed_from: resumable.as_ref().map(|run| run.next_offset),
+        run: match resumable {
+            Some(mut run) => {
+                run.status = SyncRunStatus::Running;
+                run.finished_at = None;
+                run.save(database).await?;
+                run
+            },
+            None => {
+                let run = SyncRun::new()
+                    .source(source.name())
+                    .dry_run(dry_run.is_some());
+
+                match &mut dry_run {
+                    Some(transaction) => run.create(transaction).await?,
+                    None => run.create(database).await?,
+                }
+            },
+        },
+        changes: Vec::new(),
+        failures: Vec::new(),
+        warnings: Vec::new(),
+    };
+
+    let result = sync_pages(source, &mappings, &mut report, &mut dry_run, database).await;
+
+    let status = match &result {
+        Ok(()) => SyncRunStatus::Completed,
+        Err(_) => SyncRunStatus::Failed,
+    };
+    match &mut dry_run {
+        Some(transaction) => report.run.finish(status, transaction).await?,
+        None => report.run.finish(status, database).await?,
+    }
+
+    result.map(|()| report)
+}
+
+async fn sync_pages(
+    source: &dyn ExerciseSource,
+    mappings: &SourceMappings,
+    report: &mut SyncReport,
+    dry_run: &mut Option<TransactionManager<'_>>,
+    database: &DatabaseManager,
+) -> Result<()> {
+    loop {
+        let offset = u32::try_from(report.run.next_offset).unwrap_or_default();
+        let exercises = source.exercises(source.page_size(), offset).await?;
+        let loaded = exercises.len() as i32;
+
+        if exercises.is_empty() {
+            return Ok(());
+        }
+
+        let mut page = match dry_run {
+            Some(transaction) => transaction.savepoint().await?,
+            None => database.transaction().await?,
+        };
+
+        // The page's progress only reaches the report once the page is committed,
+        // so a page that fails partway isn't counted by the run or skipped on resume.
+        let mut run = report.run.clone();
+        let mut changes = Vec::new();
+        let mut failures = Vec::new();
+        let mut warnings = Vec::new();
+
+        for exercise in exercises {
+            let name = exercise.name.clone();
+
+            match sync_exercise(exercise, mappings, &mut warnings, &mut page).await? {
+                Outcome::Created => {
+                    run.created_count += 1;
+                    changes.push(format!("{}: created", name));
+                },
+                Outcome::Updated(fields) => {
+                    run.updated_count += 1;
+                    changes.push(format!("{}: updated {}", name, fields.join(", ")));
+                },
+                Outcome::Unchanged => run.unchanged_count += 1,
+                Outcome::Failed(message) => {
+                    run.failed_count += 1;
+                    failures.push(format!("{}: {}", name, message));
+                },
+            }
+        }
+
+        // The page and the run's progress through the source are saved together.
+        run.next_offset += loaded;
+        run.save(&mut page).await?;
+
+        page.commit().await?;
+
+        report.run = run;
+        report.changes.extend(changes);
+        report.failures.extend(failures);
+        report.warnings.extend(warnings);
+    }
+}
+
+async fn sync_exercise(
+    exercise: SourceExercise,
+    mappings: &SourceMappings,
+    warnings: &mut Vec<String>,
+    transaction: &mut TransactionManager<'_>,
+) -> Result<Outcome> {
+    let name = exercise.name.clone();
+    let mut warn = |message: String| warnings.push(format!("{}: {}", name, message));
+
+    let muscle_group_id = exercise.muscle_group_id.and_then(|id| {
+        let mapped = mappings.muscle_groups.get(&id).copied();
+        if mapped.is_none() {
+            warn(format!("unknown muscle group {}", id));
+        }
+        mapped
+    });
+
+    let equipment_id = exercise.equipment_id.and_then(|id| {
+        let mapped = mappings.equipment.get(&id).copied();
+        if mapped.is_none() {
+            warn(format!("unknown equipment {}", id));
+        }
+        mapped
+    });
+
+    let mut muscles = exercise.muscles.iter()
+        .filter_map(|(id, target)| match mappings.muscles.get(id) {
+            Some(muscle_id) => Some((*muscle_id, target.clone())),
+            None => {
+                warn(format!("unknown muscle {}", id));
+                None
+            },
+        })
+        .collect::<Vec<(i16, ExerciseMuscleTarget)>>();
+    muscles.sort_by_key(|(id, target)| (*id, target.to_string()));
+
+    let mut instructions = exercise.instructions;
+    instructions.sort();
+
+    let exercise_type = match &equipment_id {
+        Some(_) => ExerciseType::Strength,
+        None => ExerciseType::Stretch,
+    };
+
+    let existing = Exercise::query()
+        .select(&["*"])
+        .and_where("external_id", "=", exercise.external_id)
+        .all::<_, Exercise>(transaction.executor())
+        .await?
+        .pop();
+
+    // Each exercise gets a savepoint, so one that fails doesn't undo the page.
+    let mut savepoint = transaction.savepoint().await?;
+
+    let result: core::result::Result<Outcome, crate::models::Error> = async {
+        let Some(mut model) = existing else {
+            let model = Exercise::new()
+                .external_id(Some(exercise.external_id))
+                .exercise_type(exercise_type)
+                .target_muscle_group_id(muscle_group_id)
+                .name(exercise.name)
+                .name_alternative(exercise.name_alternative)
+                .description(None)
+                .equipment_id(equipment_id)
+                .mechanic(exercise.mechanic)
+                .force(exercise.force)
+                .measurement(exercise.measurement)
+                .create(&mut savepoint)
+                .await?;
+
+            model.replace_muscles(muscles, &mut savepoint).await?;
+            model.replace_instructions(instructions, &mut savepoint).await?;
+
+            return Ok(Outcome::Created);
+        };
+
+        let mut changed = Vec::new();
+        update(&mut model.name, exercise.name, "name", &mut changed);
+        update(&mut model.name_alternative, exercise.name_alternative, "name_alternative", &mut changed);
+        update(&mut model.exercise_type, exercise_type, "type", &mut changed);
+        update(&mut model.target_muscle_group_id, muscle_group_id, "target_muscle_group", &mut changed);
+        update(&mut model.equipment_id, equipment_id, "equipment", &mut changed);
+        update(&mut model.mechanic, exercise.mechanic, "mechanic", &mut changed);
+        update(&mut model.force, exercise.force, "force", &mut changed);
+        update(&mut model.measurement, exercise.measurement, "measurement", &mut changed);
+
+        if !changed.is_empty() {
+            model.save(&mut savepoint).await?;
+        }
+
+        let mut current_muscles = ExerciseMuscleMap::query()
+            .select(&["*"])
+            .and_where("exercise_id", "=", model.id)
+            .all::<_, ExerciseMuscleMap>(savepoint.executor())
+            .await?
+            .into_iter()
+            .map(|map| (map.muscle_id, map.target))
+            .collect::<Vec<(i16, ExerciseMuscleTarget)>>();
+        current_muscles.sort_by_key(|(id, target)| (*id, target.to_string()));
+
+        if current_muscles != muscles {
+            model.replace_muscles(muscles, &mut savepoint).await?;
+            changed.push("muscles");
+        }
+
+        let current_instructions = ExerciseInstruction::query()
+            .select(&["*"])
+            .and_where("exercise_id", "=", model.id)
+            .order_by("sequence_number", true)
+            .all::<_, ExerciseInstruction>(savepoint.executor())
+            .await?
+            .into_iter()
+            .map(|instruction| (instruction.sequence_number, instruction.content))
+            .collect::<Vec<(i16, String)>>();
+
+        if current_instructions != instructions {
+            model.replace_instructions(instructions, &mut savepoint).await?;
+            changed.push("instructions");
+        }
+
+        match changed.is_empty() {
+            true => Ok(Outcome::Unchanged),
+            false => Ok(Outcome::Updated(changed)),
+        }
+    }.await;
+
+    match result {
+        Ok(outcome) => {
+            savepoint.commit().await?;
+
+            Ok(outcome)
+        },
+        Err(crate::models::Error::Conflict(message) | crate::models::Error::InvalidData(message)) => {
+            savepoint.rollback().await?;
+
+            Ok(Outcome::Failed(message))
+        },
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn update<T: PartialEq>(field: &mut T, value: T, name: &'static str, changed: &mut Vec<&'static str>) {
+    if *field != value {
+        *field = value;
+        changed.push(name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sync_exercises, Error, ExerciseSource, JsonDump, SourceExercise};
+    use crate::enums::{ExerciseType, SyncRunStatus};
+    use crate::models::{Exercise, ExerciseInstruction, SyncRun};
+    use async_trait::async_trait;
+    use crate::prelude::*;
+    use std::sync::Mutex;
+
+    const FIXTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/musclewiki.json");
+
+    /// Serves the fixture one exercise at a time, failing once when asked
+    /// for the page at `fail_at`.
+    struct InterruptedSource {
+        dump: JsonDump,
+        fail_at: Mutex<Option<u32>>,
+    }
+
+    #[async_trait]
+    impl ExerciseSource for InterruptedSource {
+        fn name(&self) -> &'static str {
+            self.dump.name()
+        }
+
+        fn page_size(&self) -> u16 {
+            1
+        }
+
+        async fn exercises(&self, limit: u16, offset: u32) -> super::Result<Vec<SourceExercise>> {
+            if *self.fail_at.lock().unwrap() == Some(offset) {
+                self.fail_at.lock().unwrap().take();
+                return Err(Error::ParseFailure { origin: "test".to_string(), message: "interrupted".to_string() });
+            }
+
+            self.dump.exercises(limit, offset).await
+        }
+    }
+
+    /// Serves the fixture in a single page, once with a name Postgres refuses
+    /// to store on its last exercise, so that page fails partway through.
+    struct CorruptedSource {
+        dump: JsonDump,
+        corrupt: Mutex<bool>,
+    }
+
+    #[async_trait]
+    impl ExerciseSource for CorruptedSource {
+        fn name(&self) -> &'static str {
+            self.dump.name()
+        }
+
+        fn page_size(&self) -> u16 {
+            10
+        }
+
+        async fn exercises(&self, limit: u16, offset: u32) -> super::Result<Vec<SourceExercise>> {
+            let mut exercises = self.dump.exercises(limit, offset).await?;
+
+            if std::mem::take(&mut *self.corrupt.lock().unwrap()) {
+                if let Some(exercise) = exercises.last_mut() {
+                    exercise.name.push('\0');
+                }
+            }
+
+            Ok(exercises)
+        }
+    }
+
+    async fn find_by_external_id(id: i16, database: &DatabaseManager) -> Result<Exercise> {
+        Ok(Exercise::query()
+            .select(&["*"])
+            .and_where("external_id", "=", id)
+            .one::<_, Exercise>(database.connection())
+            .await?)
+    }
+
+    #[sqlx::test]
+    async fn syncs_exercises_from_a_json_dump(pool: PgPool) -> Result<()> {
+        let database = DatabaseManager::from_pool(pool);
+        let source = JsonDump::open(FIXTURE)?;
+
+        let report = sync_exercises(&source, false, &database).await?;
+
+        assert_eq!(vec!["Barbell Curl: created", "Doorway Stretch: created"], report.changes);
+        assert_eq!(1, report.failures.len());
+        assert!(report.failures[0].starts_with("Barbell Curl:"));
+        assert_eq!(vec![
+            "Doorway Stretch: unknown equipment 500".to_string(),
+            "Doorway Stretch: unknown muscle 999".to_string(),
+        ], report.warnings);
+        assert_eq!(SyncRunStatus::Completed, report.run.status);
+        assert_eq!((2, 0, 0, 1), (
+            report.run.created_count,
+            report.run.updated_count,
+            report.run.unchanged_count,
+            report.run.failed_count,
+        ));
+        assert_eq!(3, report.run.next_offset);
+
+        let curl = find_by_external_id(1, &database).await?;
+        assert_eq!("Barbell Curl", curl.name);
+        assert_eq!(ExerciseType::Strength, curl.exercise_type);
+        assert!(curl.equipment_id.is_some());
+        assert_eq!(
+            vec!["Biceps Brachii, Long Head", "Biceps Brachii, Short Head"],
+            curl.primary_muscles(&database).await?.into_iter().map(|muscle| muscle.name).collect::<Vec<String>>(),
+        );
+        assert_eq!(1, curl.secondary_muscles(&database).await?.len());
+        assert_eq!(2, curl.instructions(&database).await?.len());
+
+        let stretch = find_by_external_id(2, &database).await?;
+        assert_eq!(ExerciseType::Stretch, stretch.exercise_type);
+        assert_eq!(1, stretch.primary_muscles(&database).await?.len());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn syncing_twice_leaves_exercises_unchanged(pool: PgPool) -> Result<()> {
+        let database = DatabaseManager::from_pool(pool);
+        let source = JsonDump::open(FIXTURE)?;
+        sync_exercises(&source, false, &database).await?;
+
+        let report = sync_exercises(&source, false, &database).await?;
+
+        assert!(report.changes.is_empty());
+        assert_eq!((0, 0, 2, 1), (
+            report.run.created_count,
+            report.run.updated_count,
+            report.run.unchanged_count,
+            report.run.failed_count,
+        ));
+        assert_eq!(2, Exercise::query().select(&[]).where_not_null("external_id").count(database.connection()).await?);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn sync_updates_exercises_that_changed(pool: PgPool) -> Result<()> {
+        let database = DatabaseManager::from_pool(pool);
+        let source = JsonDump::open(FIXTURE)?;
+        sync_exercises(&source, false, &database).await?;
+        let mut curl = find_by_external_id(1, &database).await?;
+        curl.name_alternative = Some("Curl".to_string());
+        curl.save(&database).await?;
+        ExerciseInstruction::query()
+            .delete()
+            .and_where("exercise_id", "=", curl.id)
+            .and_where("sequence_number", "=", 2i16)
+            .execute(database.connection())
+            .await?;
+
+        let report = sync_exercises(&source, false, &database).await?;
+
+        assert_eq!(vec!["Barbell Curl: updated name_alternative, instructions"], report.changes);
+        assert_eq!((0, 1, 1, 1), (
+            report.run.created_count,
+            report.run.updated_count,
+            report.run.unchanged_count,
+            report.run.failed_count,
+        ));
+        let curl = find_by_external_id(1, &database).await?;
+        assert_eq!(Some("Standing Barbell Curl".to_string()), curl.name_alternative);
+        assert_eq!(2, curl.instructions(&database).await?.len());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn dry_run_reports_changes_without_saving_them(pool: PgPool) -> Result<()> {
+        let database = DatabaseManager::from_pool(pool);
+        let source = JsonDump::open(FIXTURE)?;
+
+        let report = sync_exercises(&source, true, &database).await?;
+
+        assert_eq!(vec!["Barbell Curl: created", "Doorway Stretch: created"], report.changes);
+        assert_eq!(1, report.failures.len());
+        assert!(report.run.dry_run);
+        assert_eq!(2, report.run.created_count);
+        assert_eq!(0, Exercise::query().select(&[]).where_not_null("external_id").count(database.connection()).await?);
+        assert_eq!(0, SyncRun::count(&database).await?);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn sync_failing_partway_through_a_page_keeps_none_of_its_progress(pool: PgPool) -> Result<()> {
+        let database = DatabaseManager::from_pool(pool);
+        let source = CorruptedSource {
+            dump: JsonDump::open(FIXTURE)?,
+            corrupt: Mutex::new(true),
+        };
+
+        assert!(sync_exercises(&source, false, &database).await.is_err());
+
+        let interrupted = SyncRun::resumable(source.name(), &database).await?.unwrap();
+        assert_eq!(SyncRunStatus::Failed, interrupted.status);
+        assert_eq!(0, interrupted.next_offset);
+        assert_eq!((0, 0, 0, 0), (
+            interrupted.created_count,
+            interrupted.updated_count,
+            interrupted.unchanged_count,
+            interrupted.failed_count,
+        ));
+        assert_eq!(0, Exercise::query().select(&[]).where_not_null("external_id").count(database.connection()).await?);
+
+        let report = sync_exercises(&source, false, &database).await?;
+
+        assert_eq!(Some(0), report.resumed_from);
+        assert_eq!(interrupted.id, report.run.id);
+        assert_eq!((2, 0, 0, 1), (
+            report.run.created_count,
+            report.run.updated_count,
+            report.run.unchanged_count,
+            report.run.failed_count,
+        ));
+        assert_eq!(3, report.run.next_offset);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn interrupted_sync_resumes_from_its_last_offset(pool: PgPool) -> Result<()> {
+        let database = DatabaseManager::from_pool(pool);
+        let source = InterruptedSource {
+            dump: JsonDump::open(FIXTURE)?,
+            fail_at: Mutex::new(Some(1)),
+        };
+
+        assert!(sync_exercises(&source, false, &database).await.is_err());
+
+        let interrupted = SyncRun::resumable(source.name(), &database).await?.unwrap();
+        assert_eq!(SyncRunStatus::Failed, interrupted.status);
+        assert_eq!(1, interrupted.next_offset);
+        assert_eq!(1, interrupted.created_count);
+
+        let report = sync_exercises(&source, false, &database).await?;
+
+        assert_eq!(Some(1), report.resumed_from);
+        assert_eq!(vec!["Doorway Stretch: created"], report.changes);
+        assert_eq!(interrupted.id, report.run.id);
+        assert_eq!(SyncRunStatus::Completed, report.run.status);
+        assert_eq!((2, 0, 0, 1), (
+            report.run.created_count,
+            report.run.updated_count,
+            report.run.unchanged_count,
+            report.run.failed_count,
+        ));
+        assert_eq!(3, report.run.next_offset);
+
+        Ok(())
+    }
+
+    #[test]
+    fn json_dump_reads_exercise_list_responses() {
+        let dump = JsonDump::parse(r#"{
+            "count": 1,
+            "next": null,
+            "previous": null,
+            "results": [{"id": 1, "name": "Plank", "category": {"id": 3, "name": "Bodyweight"}}]
+        }"#, "test");
+
+        assert!(dump.is_ok());
+        assert!(JsonDump::parse("{}", "test").is_err());
+    }
+}
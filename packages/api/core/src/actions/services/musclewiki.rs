@@ -0,0 +1,190 @@
+use super::{Error, ExerciseSource, Result, SourceExercise};
+use crate::enums::{ExerciseForce, ExerciseMechanic, ExerciseMuscleTarget, Measurement};
+use async_trait::async_trait;
+use serde::Deserialize;
+
+const API_URL: &str = "https://musclewiki.com/newapi";
+
+/// Loads exercises from the MuscleWiki API.
+pub struct MuscleWiki {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl MuscleWiki {
+    pub const NAME: &'static str = "musclewiki";
+
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: API_URL.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl ExerciseSource for MuscleWiki {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    async fn exercises(&self, limit: u16, offset: u32) -> Result<Vec<SourceExercise>> {
+        let endpoint = format!("{}/exercise/exercises/?limit={}&offset={}", self.url, limit, offset);
+
+        tracing::debug!("Request made to: {}", endpoint);
+        let response = self.client.get(&endpoint)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|source| Error::RequestFailure { url: endpoint.clone(), source })?;
+
+        let page = response.json::<MWExerciseListResponse>()
+            .await
+            .map_err(|error| Error::ParseFailure { origin: endpoint, message: error.to_string() })?;
+
+        Ok(page.results.into_iter().map(SourceExercise::from).collect())
+    }
+}
+
+impl From<MWExercise> for SourceExercise {
+    fn from(exercise: MWExercise) -> Self {
+        let muscles = [
+            (exercise.muscles_primary, ExerciseMuscleTarget::Primary),
+            (exercise.muscles_secondary, ExerciseMuscleTarget::Secondary),
+            (exercise.muscles_tertiary, ExerciseMuscleTarget::Tertiary),
+        ];
+
+        Self {
+            external_id: exercise.id,
+            name: exercise.name,
+            name_alternative: exercise.name_alternative,
+            muscle_group_id: muscles[0].0.first().map(|muscle| muscle.tree_id),
+            equipment_id: Some(exercise.category.id),
+            mechanic: mw_mechanic_to_exercise_mechanic(exercise.mechanic),
+            force: mw_force_to_exercise_force(exercise.force),
+            measurement: mw_full_measure_to_exercise_measurement(exercise.full_measure),
+            muscles: muscles.iter()
+                .flat_map(|(muscles, target)| muscles.iter().map(|muscle| (muscle.id, target.clone())))
+                .collect(),
+            instructions: exercise.correct_steps.into_iter()
+                .map(|step| (step.order, step.text))
+                .collect(),
+        }
+    }
+}
+
+fn mw_force_to_exercise_force(force: Option<MWForce>) -> Option<ExerciseForce> {
+    let force = force?;
+
+    match force.name.to_lowercase().as_str() {
+        "hold" => Some(ExerciseForce::Hold),
+        "pull" => Some(ExerciseForce::Pull),
+        "push" => Some(ExerciseForce::Push),
+        _ => None
+    }
+}
+
+fn mw_mechanic_to_exercise_mechanic(mechanic: Option<MWMechanic>) -> Option<ExerciseMechanic> {
+    let mechanic = mechanic?;
+
+    match mechanic.name.to_lowercase().as_str() {
+        "compound" => Some(ExerciseMechanic::Compound),
+        "isolation" => Some(ExerciseMechanic::Isolation),
+        _ => None
+    }
+}
+
+fn mw_full_measure_to_exercise_measurement(measurement: Option<MWFullMeasure>) -> Option<Measurement> {
+    let id = measurement?.id;
+
+    match id {
+        4 => Some(Measurement::WeightedRepetitions),
+        11 => Some(Measurement::WeightedDuration),
+        18 => Some(Measurement::Duration),
+        19 => Some(Measurement::Repetitions),
+        _ => None
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MWExerciseListResponse {
+    pub count: i16,
+    pub next: Option<String>,
+    pub previous: Option<String>,
+    pub results: Vec<MWExercise>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MWExercise {
+    pub id: i16,
+    pub name: String,
+    pub name_alternative: Option<String>,
+    // pub description: Option<String>,
+    #[serde(default)]
+    pub muscles: Vec<MWMuscle>,
+    #[serde(default)]
+    pub muscles_primary: Vec<MWMuscle>,
+    #[serde(default)]
+    pub muscles_secondary: Vec<MWMuscle>,
+    #[serde(default)]
+    pub muscles_tertiary: Vec<MWMuscle>,
+    pub category: MWCategory,
+    pub force: Option<MWForce>,
+    pub mechanic: Option<MWMechanic>,
+    #[serde(default)]
+    pub correct_steps: Vec<MWStep>,
+    pub full_measure: Option<MWFullMeasure>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MWMuscle {
+    pub id: i16,
+    pub name: String,
+    pub tree_id: i16,
+    // pub description: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MWCategory {
+    pub id: i16,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MWForce {
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MWMechanic {
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MWStep {
+    pub order: i16,
+    pub text: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MWFullMeasure {
+    pub id: i16,
+    pub measure: MWMeasure,
+    pub denominator: Option<MWDenominator>,
+    pub calculation_mode: MWCalculationMode,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MWMeasure {
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MWDenominator {
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MWCalculationMode {
+    pub name: String,
+}
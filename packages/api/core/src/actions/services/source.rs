@@ -0,0 +1,37 @@
+use super::Result;
+use crate::enums::{ExerciseForce, ExerciseMechanic, ExerciseMuscleTarget, Measurement};
+use async_trait::async_trait;
+
+/// Somewhere exercises can be imported from. Sources describe muscles,
+/// muscle groups and equipment by their own ids, which are translated to
+/// local ids through the `exercise_source_mappings` stored under `name()`.
+#[async_trait]
+pub trait ExerciseSource: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// How many exercises to load at a time. A sync records its progress
+    /// after every page, so an interrupted sync repeats at most one page.
+    fn page_size(&self) -> u16 {
+        50
+    }
+
+    /// Loads up to `limit` exercises, skipping the first `offset`. An empty
+    /// list means every exercise has been loaded.
+    async fn exercises(&self, limit: u16, offset: u32) -> Result<Vec<SourceExercise>>;
+}
+
+/// An exercise as a source describes it, with every relation given by the
+/// source's own ids.
+#[derive(Clone, Debug)]
+pub struct SourceExercise {
+    pub external_id: i16,
+    pub name: String,
+    pub name_alternative: Option<String>,
+    pub muscle_group_id: Option<i16>,
+    pub equipment_id: Option<i16>,
+    pub mechanic: Option<ExerciseMechanic>,
+    pub force: Option<ExerciseForce>,
+    pub measurement: Option<Measurement>,
+    pub muscles: Vec<(i16, ExerciseMuscleTarget)>,
+    pub instructions: Vec<(i16, String)>,
+}
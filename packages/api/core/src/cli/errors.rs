@@ -0,0 +1,11 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Unknown command `{0}`")]
+    UnknownCommand(String),
+    #[error("Unexpected argument `{0}`")]
+    UnexpectedArgument(String),
+    #[error("Missing a value for `{0}`")]
+    MissingValue(&'static str),
+    #[error("Missing migrate command, expected one of `up`, `down` or `status`")]
+    MissingMigrateCommand,
+}
@@ -0,0 +1,104 @@
+use database::DatabaseManager;
+use sqlx::migrate::{Migrate, Migrator};
+use std::collections::HashSet;
+
+/// Every migration in `core/migrations`, compiled into the binary so that a
+/// deployed server can migrate its database without the sqlx CLI.
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+/// Applies every pending migration.
+pub async fn up(database: &DatabaseManager) -> super::Result<()> {
+    let pending = pending(database).await?;
+
+    MIGRATOR.run(database.connection()).await?;
+
+    match pending.is_empty() {
+        true => println!("Nothing to migrate"),
+        false => pending.iter().for_each(|migration| println!("Applied {}", migration)),
+    }
+
+    Ok(())
+}
+
+/// Reverts the most recently applied migration.
+pub async fn down(database: &DatabaseManager) -> super::Result<()> {
+    let mut applied = applied(database).await?.into_iter().collect::<Vec<i64>>();
+    applied.sort_unstable();
+
+    let Some(latest) = applied.pop() else {
+        println!("Nothing to revert");
+        return Ok(());
+    };
+
+    MIGRATOR.undo(database.connection(), applied.last().copied().unwrap_or(0)).await?;
+
+    println!("Reverted {}", describe(latest));
+
+    Ok(())
+}
+
+/// Lists every migration along with whether it has been applied.
+pub async fn status(database: &DatabaseManager) -> super::Result<()> {
+    let applied = applied(database).await?;
+
+    for migration in MIGRATOR.iter().filter(|migration| !migration.migration_type.is_down_migration()) {
+        let state = match applied.contains(&migration.version) {
+            true => "applied",
+            false => "pending",
+        };
+
+        println!("{:<8} {} {}", state, migration.version, migration.description);
+    }
+
+    Ok(())
+}
+
+async fn applied(database: &DatabaseManager) -> super::Result<HashSet<i64>> {
+    let mut connection = database.connection().acquire().await?;
+
+    connection.ensure_migrations_table().await?;
+
+    Ok(connection.list_applied_migrations().await?
+        .into_iter()
+        .map(|migration| migration.version)
+        .collect())
+}
+
+async fn pending(database: &DatabaseManager) -> super::Result<Vec<String>> {
+    let applied = applied(database).await?;
+
+    Ok(MIGRATOR.iter()
+        .filter(|migration| !migration.migration_type.is_down_migration())
+        .filter(|migration| !applied.contains(&migration.version))
+        .map(|migration| describe(migration.version))
+        .collect())
+}
+
+fn describe(version: i64) -> String {
+    MIGRATOR.iter()
+        .find(|migration| migration.version == version)
+        .map(|migration| format!("{} {}", migration.version, migration.description))
+        .unwrap_or_else(|| version.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MIGRATOR;
+    use sqlx::PgPool;
+
+    #[sqlx::test(migrations = false)]
+    async fn every_migration_can_be_reverted_and_reapplied(pool: PgPool) {
+        MIGRATOR.run(&pool).await.unwrap();
+        MIGRATOR.undo(&pool, 0).await.unwrap();
+
+        let (tables,) = sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*) FROM information_schema.tables WHERE table_schema = 'public' AND table_name <> '_sqlx_migrations'"
+        )
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(tables, 0);
+
+        MIGRATOR.run(&pool).await.unwrap();
+    }
+}
@@ -0,0 +1,222 @@
+mod errors;
+mod migrate;
+mod seed;
+
+use crate::actions::{self, services::{self, JsonDump, MuscleWiki}};
+use crate::data::CreateUserData;
+use crate::enums::Role;
+use crate::models::User;
+use crate::sys::config;
+use database::DatabaseManager;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+pub use errors::Error;
+type Result<TValue> = ::core::result::Result<TValue, Box<dyn std::error::Error + Send + Sync + 'static>>;
+
+pub const USAGE: &str = "\
+Usage: core [COMMAND]
+
+Commands:
+  serve             Start the HTTP server (default)
+  migrate up        Apply every pending migration
+  migrate down      Revert the most recently applied migration
+  migrate status    List migrations and whether they have been applied
+  seed              Create a demo user with a profile and a routine
+  create-admin      Create the admin user from ADMIN_USERNAME and ADMIN_PASSWORD
+  sync-exercises    Sync the exercise catalog with MuscleWiki, or with a
+                    MuscleWiki JSON dump with `--file <PATH>`. Resumes an
+                    interrupted sync. `--dry-run` reports the changes
+                    without making them
+  help              Print this message";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Serve,
+    Migrate(MigrateCommand),
+    Seed,
+    CreateAdmin,
+    SyncExercises {
+        file: Option<PathBuf>,
+        dry_run: bool,
+    },
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MigrateCommand {
+    Up,
+    Down,
+    Status,
+}
+
+impl Command {
+    /// Parses the arguments following the binary name. Running the binary
+    /// without a command starts the server.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> ::core::result::Result<Self, Error> {
+        let mut args = args.into_iter();
+
+        let command = match args.next().as_deref() {
+            None | Some("serve") => Self::Serve,
+            Some("migrate") => Self::Migrate(match args.next().as_deref() {
+                Some("up") => MigrateCommand::Up,
+                Some("down") => MigrateCommand::Down,
+                Some("status") => MigrateCommand::Status,
+                Some(other) => return Err(Error::UnknownCommand(format!("migrate {}", other))),
+                None => return Err(Error::MissingMigrateCommand),
+            }),
+            Some("seed") => Self::Seed,
+            Some("create-admin") => Self::CreateAdmin,
+            Some("sync-exercises") => {
+                let (mut file, mut dry_run) = (None, false);
+
+                while let Some(argument) = args.next() {
+                    match argument.as_str() {
+                        "--file" => file = Some(args.next().ok_or(Error::MissingValue("--file"))?.into()),
+                        "--dry-run" => dry_run = true,
+                        _ => return Err(Error::UnexpectedArgument(argument)),
+                    }
+                }
+
+                Self::SyncExercises { file, dry_run }
+            },
+            Some("help" | "--help" | "-h") => Self::Help,
+            Some(other) => return Err(Error::UnknownCommand(other.to_string())),
+        };
+
+        match args.next() {
+            Some(argument) => Err(Error::UnexpectedArgument(argument)),
+            None => Ok(command),
+        }
+    }
+
+    pub async fn run(self) -> Result<()> {
+        match self {
+            Self::Serve => serve().await,
+            Self::Migrate(MigrateCommand::Up) => migrate::up(&connect().await?).await,
+            Self::Migrate(MigrateCommand::Down) => migrate::down(&connect().await?).await,
+            Self::Migrate(MigrateCommand::Status) => migrate::status(&connect().await?).await,
+            Self::Seed => seed::run(&connect().await?).await,
+            Self::CreateAdmin => create_admin(&connect().await?).await,
+            Self::SyncExercises { file, dry_run } => sync_exercises(file, dry_run, &connect().await?).await,
+            Self::Help => {
+                println!("{}", USAGE);
+                Ok(())
+            },
+        }
+    }
+}
+
+async fn connect() -> Result<DatabaseManager> {
+    let database = config().database();
+
+    Ok(DatabaseManager::builder()
+        .url(database.url())
+        .max_connections(database.max_connections())
+        .min_connections(database.min_connections())
+        .build()
+        .await?)
+}
+
+async fn serve() -> Result<()> {
+    let router = crate::http::router(connect().await?).await;
+    let server_address = SocketAddr::from(([127, 0, 0, 1], config().server().port()));
+    let server = axum::Server::bind(&server_address)
+        .serve(router.into_make_service());
+
+    tracing::debug!("Server listening at http://{}", &server_address);
+
+    server.await?;
+
+    Ok(())
+}
+
+async fn create_admin(database: &DatabaseManager) -> Result<()> {
+    let auth = config().auth();
+    let username = auth.admin_username();
+    let password = auth.admin_password();
+
+    if User::exists_with_email(&username, database).await? {
+        println!("Admin user {} already exists", username);
+        return Ok(());
+    }
+
+    let admin_data = CreateUserData {
+        email: username.as_str(),
+        first_name: "Admin",
+        last_name: "User",
+        role: Some(Role::Admin),
+        password: password.as_str(),
+        password_confirm: password.as_str(),
+    };
+
+    actions::create_user(admin_data, database).await?;
+
+    println!("Created admin user {}", username);
+
+    Ok(())
+}
+
+async fn sync_exercises(file: Option<PathBuf>, dry_run: bool, database: &DatabaseManager) -> Result<()> {
+    let report = match file {
+        Some(file) => services::sync_exercises(&JsonDump::open(file)?, dry_run, database).await?,
+        None => services::sync_exercises(&MuscleWiki::new(), dry_run, database).await?,
+    };
+
+    if let Some(offset) = report.resumed_from {
+        println!("Resumed sync run {} from offset {}", report.run.id, offset);
+    }
+
+    report.warnings.iter().for_each(|warning| println!("Warning: {}", warning));
+    report.changes.iter().for_each(|change| println!("{}", change));
+    report.failures.iter().for_each(|failure| println!("Failed {}", failure));
+
+    let run = &report.run;
+    println!(
+        "{}: {} created, {} updated, {} unchanged, {} failed",
+        match run.dry_run {
+            true => "Dry run, nothing was saved",
+            false => "Synced exercises",
+        },
+        run.created_count,
+        run.updated_count,
+        run.unchanged_count,
+        run.failed_count,
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Command, Error, MigrateCommand};
+
+    fn parse(args: &[&str]) -> Result<Command, Error> {
+        Command::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!(parse(&[]).unwrap(), Command::Serve);
+        assert_eq!(parse(&["serve"]).unwrap(), Command::Serve);
+        assert_eq!(parse(&["migrate", "up"]).unwrap(), Command::Migrate(MigrateCommand::Up));
+        assert_eq!(parse(&["migrate", "down"]).unwrap(), Command::Migrate(MigrateCommand::Down));
+        assert_eq!(parse(&["migrate", "status"]).unwrap(), Command::Migrate(MigrateCommand::Status));
+        assert_eq!(parse(&["seed"]).unwrap(), Command::Seed);
+        assert_eq!(parse(&["create-admin"]).unwrap(), Command::CreateAdmin);
+        assert_eq!(parse(&["sync-exercises"]).unwrap(), Command::SyncExercises { file: None, dry_run: false });
+        assert_eq!(
+            parse(&["sync-exercises", "--dry-run", "--file", "exercises.json"]).unwrap(),
+            Command::SyncExercises { file: Some("exercises.json".into()), dry_run: true },
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_commands_and_arguments() {
+        assert!(matches!(parse(&["deploy"]), Err(Error::UnknownCommand(_))));
+        assert!(matches!(parse(&["migrate"]), Err(Error::MissingMigrateCommand)));
+        assert!(matches!(parse(&["migrate", "sideways"]), Err(Error::UnknownCommand(_))));
+        assert!(matches!(parse(&["seed", "--force"]), Err(Error::UnexpectedArgument(_))));
+        assert!(matches!(parse(&["sync-exercises", "--file"]), Err(Error::MissingValue("--file"))));
+    }
+}
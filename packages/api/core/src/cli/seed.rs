@@ -0,0 +1,84 @@
+use crate::actions;
+use crate::data::{
+    CreateRoutineData,
+    CreateRoutineExerciseData,
+    CreateUserData,
+    CreateUserProfileData,
+    EquipmentProfileData,
+};
+use crate::enums::Gender;
+use crate::models::{Exercise, ExerciseEquipment, User};
+use chrono::NaiveDate;
+use database::{DatabaseManager, Model, SqlxAction};
+
+pub const DEMO_EMAIL: &str = "demo@example.com";
+pub const DEMO_PASSWORD: &str = "#DemoPassword1234";
+
+/// Creates a demo user with a profile, an equipment profile and a routine
+/// built from the exercise catalog. Does nothing if the demo user exists.
+pub async fn run(database: &DatabaseManager) -> super::Result<()> {
+    if User::exists_with_email(DEMO_EMAIL, database).await? {
+        println!("Demo user {} already exists", DEMO_EMAIL);
+        return Ok(());
+    }
+
+    let mut transaction = database.transaction().await?;
+
+    let user = actions::create_user(CreateUserData {
+        email: DEMO_EMAIL,
+        first_name: "Demo",
+        last_name: "User",
+        role: None,
+        password: DEMO_PASSWORD,
+        password_confirm: DEMO_PASSWORD,
+    }, &mut transaction).await?;
+
+    actions::create_user_profile(CreateUserProfileData {
+        user_id: user.id,
+        birthday: NaiveDate::from_ymd_opt(1990, 1, 1).unwrap(),
+        gender: Gender::Other,
+        bodyweight: Some(75.0),
+    }, &mut transaction).await?;
+
+    let equipment = ExerciseEquipment::query()
+        .select(&["*"])
+        .where_in("name", vec!["barbell", "bodyweight", "dumbbell"])
+        .all::<_, ExerciseEquipment>(transaction.executor())
+        .await?;
+
+    actions::create_equipment_profile(&user, EquipmentProfileData {
+        name: "Home Gym".to_string(),
+        equipment_ids: equipment.iter().map(|equipment| equipment.id).collect(),
+    }, &mut transaction).await?;
+
+    let exercises = Exercise::query()
+        .select(&["*"])
+        .order_by("name", true)
+        .limit(5)
+        .all::<_, Exercise>(transaction.executor())
+        .await?;
+
+    if exercises.is_empty() {
+        println!("The exercise catalog is empty, run `sync-exercises` to seed a routine");
+    } else {
+        actions::create_routine(&user, CreateRoutineData {
+            name: "Full Body".to_string(),
+            description: Some("A demo routine built from the exercise catalog".to_string()),
+            exercises: exercises.iter()
+                .map(|exercise| CreateRoutineExerciseData {
+                    exercise_id: exercise.id,
+                    target_sets: 3,
+                    target_repetitions: Some(10),
+                    target_weight: None,
+                    rest_seconds: Some(90),
+                })
+                .collect(),
+        }, &mut transaction).await?;
+    }
+
+    transaction.commit().await?;
+
+    println!("Created demo user {} with password {}", DEMO_EMAIL, DEMO_PASSWORD);
+
+    Ok(())
+}
@@ -24,4 +24,52 @@ pub struct CreateUserProfileData {
     pub user_id: i16,
     pub birthday: chrono::NaiveDate,
     pub gender: Gender,
+    pub bodyweight: Option<f64>,
+}
+
+#[derive(Debug)]
+pub struct LogWorkoutSetData {
+    pub weight: Option<f64>,
+    pub repetitions: Option<i16>,
+    pub duration: Option<i32>,
+}
+
+#[derive(Debug)]
+pub struct CreateRoutineData {
+    pub name: String,
+    pub description: Option<String>,
+    pub exercises: Vec<CreateRoutineExerciseData>,
+}
+
+#[derive(Debug)]
+pub struct CreateRoutineExerciseData {
+    pub exercise_id: i16,
+    pub target_sets: i16,
+    pub target_repetitions: Option<i16>,
+    pub target_weight: Option<f64>,
+    pub rest_seconds: Option<i32>,
+}
+
+#[derive(Debug)]
+pub struct CreateProgramData {
+    pub name: String,
+    pub description: Option<String>,
+    pub weeks: i16,
+    pub weekly_increment: Option<f64>,
+    pub deload_every: Option<i16>,
+    pub deload_factor: Option<f64>,
+    pub days: Vec<CreateProgramDayData>,
+}
+
+#[derive(Debug)]
+pub struct CreateProgramDayData {
+    pub routine_id: i32,
+    pub week_number: i16,
+    pub day_number: i16,
+}
+
+#[derive(Debug)]
+pub struct EquipmentProfileData {
+    pub name: String,
+    pub equipment_ids: Vec<i16>,
 }
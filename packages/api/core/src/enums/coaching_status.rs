@@ -0,0 +1,19 @@
+use database::impl_bindable;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use strum_macros::Display;
+
+#[derive(Clone, Debug, Deserialize, Display, PartialEq, Serialize, Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case", type_name = "varchar")]
+#[strum(serialize_all = "snake_case")]
+pub enum CoachingStatus {
+    /// The coach has invited the client, who hasn't answered yet.
+    Pending,
+    Accepted,
+    Declined,
+    /// Either side ended the relationship.
+    Revoked,
+}
+
+impl_bindable!(CoachingStatus);
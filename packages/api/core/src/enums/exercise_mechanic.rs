@@ -1,9 +1,9 @@
 use database::impl_bindable;
 use serde::{Deserialize, Serialize};
 use sqlx::Type;
-use strum_macros::Display;
+use strum_macros::{Display, EnumString};
 
-#[derive(Clone, Debug, Deserialize, Display, PartialEq, Serialize, Type)]
+#[derive(Clone, Debug, Deserialize, Display, EnumString, PartialEq, Serialize, Type)]
 #[serde(rename_all = "snake_case")]
 #[sqlx(rename_all = "snake_case", type_name = "varchar")]
 #[strum(serialize_all = "snake_case")]
@@ -15,6 +15,17 @@ pub enum ExerciseMuscleTarget {
     Tertiary,
 }
 
+impl ExerciseMuscleTarget {
+    /// The share of a set's volume that counts towards a muscle with this target.
+    pub fn volume_factor(&self) -> f64 {
+        match self {
+            Self::Primary => 1.0,
+            Self::Secondary => 0.5,
+            Self::Tertiary => 0.25,
+        }
+    }
+}
+
 impl_bindable!(ExerciseMuscleTarget);
 
 impl Default for ExerciseMuscleTarget {
@@ -0,0 +1,29 @@
+use serde::Deserialize;
+
+/// The orders exercises can be listed in. A leading `-`
+/// in the query parameter sorts in descending order.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub enum ExerciseSort {
+    #[default]
+    #[serde(rename = "name")]
+    Name,
+    #[serde(rename = "-name")]
+    NameDescending,
+    #[serde(rename = "created_at")]
+    CreatedAt,
+    #[serde(rename = "-created_at")]
+    CreatedAtDescending,
+}
+
+impl ExerciseSort {
+    pub fn column(&self) -> &'static str {
+        match self {
+            Self::Name | Self::NameDescending => "name",
+            Self::CreatedAt | Self::CreatedAtDescending => "created_at",
+        }
+    }
+
+    pub fn is_ascending(&self) -> bool {
+        matches!(self, Self::Name | Self::CreatedAt)
+    }
+}
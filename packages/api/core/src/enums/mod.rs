@@ -1,21 +1,37 @@
+mod coaching_status;
 mod exercise_force;
 mod exercise_mechanic;
 mod exercise_muscle_target;
+mod exercise_sort;
 mod exercise_type;
 mod gender;
 mod link_format;
 mod link_type;
 mod measurement;
+mod one_rep_max_formula;
+mod permission;
+mod personal_record_type;
 mod role;
+mod strength_level;
+mod sync_run_status;
 mod table;
+mod volume_granularity;
 
+pub use coaching_status::CoachingStatus;
 pub use exercise_force::ExerciseForce;
 pub use exercise_mechanic::ExerciseMechanic;
 pub use exercise_muscle_target::ExerciseMuscleTarget;
+pub use exercise_sort::ExerciseSort;
 pub use exercise_type::ExerciseType;
 pub use gender::Gender;
 pub use link_format::LinkFormat;
 pub use link_type::LinkType;
 pub use measurement::{Measurement, MeasurementDenominator, MeasurementOperation, MeasurementUnit};
+pub use one_rep_max_formula::OneRepMaxFormula;
+pub use permission::Permission;
+pub use personal_record_type::PersonalRecordType;
 pub use role::Role;
+pub use strength_level::StrengthLevel;
+pub use sync_run_status::SyncRunStatus;
 pub use table::Table;
+pub use volume_granularity::VolumeGranularity;
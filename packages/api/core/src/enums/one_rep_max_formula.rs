@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+#[derive(Clone, Debug, Default, Deserialize, Display, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OneRepMaxFormula {
+    Brzycki,
+    #[default]
+    Epley,
+    Lombardi,
+}
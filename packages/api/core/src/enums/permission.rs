@@ -0,0 +1,56 @@
+use database::impl_bindable;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use strum_macros::{Display, EnumString};
+
+/// Something a role allows its users to do. Which roles have which
+/// permissions is stored in the `roles_permissions` table.
+#[derive(Clone, Copy, Debug, Deserialize, Display, EnumString, Eq, Hash, PartialEq, Serialize, Type)]
+#[sqlx(type_name = "varchar")]
+pub enum Permission {
+    /// Sign in through the admin login.
+    #[serde(rename = "admin.access")]
+    #[sqlx(rename = "admin.access")]
+    #[strum(serialize = "admin.access")]
+    AdminAccess,
+    /// Invite clients and program for them.
+    #[serde(rename = "client.manage")]
+    #[sqlx(rename = "client.manage")]
+    #[strum(serialize = "client.manage")]
+    ClientManage,
+    #[serde(rename = "equipment.manage")]
+    #[sqlx(rename = "equipment.manage")]
+    #[strum(serialize = "equipment.manage")]
+    EquipmentManage,
+    #[serde(rename = "exercise.create")]
+    #[sqlx(rename = "exercise.create")]
+    #[strum(serialize = "exercise.create")]
+    ExerciseCreate,
+    #[serde(rename = "exercise.delete")]
+    #[sqlx(rename = "exercise.delete")]
+    #[strum(serialize = "exercise.delete")]
+    ExerciseDelete,
+    #[serde(rename = "exercise.update")]
+    #[sqlx(rename = "exercise.update")]
+    #[strum(serialize = "exercise.update")]
+    ExerciseUpdate,
+    #[serde(rename = "link.manage")]
+    #[sqlx(rename = "link.manage")]
+    #[strum(serialize = "link.manage")]
+    LinkManage,
+    /// Create, update and delete muscles and muscle groups.
+    #[serde(rename = "muscle.manage")]
+    #[sqlx(rename = "muscle.manage")]
+    #[strum(serialize = "muscle.manage")]
+    MuscleManage,
+    #[serde(rename = "user.manage")]
+    #[sqlx(rename = "user.manage")]
+    #[strum(serialize = "user.manage")]
+    UserManage,
+    #[serde(rename = "workout.view_others")]
+    #[sqlx(rename = "workout.view_others")]
+    #[strum(serialize = "workout.view_others")]
+    WorkoutViewOthers,
+}
+
+impl_bindable!(Permission);
@@ -0,0 +1,27 @@
+use database::impl_bindable;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use strum_macros::Display;
+
+#[derive(Clone, Debug, Deserialize, Display, PartialEq, Serialize, Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case", type_name = "varchar")]
+#[strum(serialize_all = "snake_case")]
+pub enum PersonalRecordType {
+    HeaviestWeight,
+    MostRepetitions,
+    LongestDuration,
+    EstimatedOneRepMax,
+}
+
+impl PersonalRecordType {
+    pub fn unit(&self) -> &'static str {
+        match self {
+            Self::HeaviestWeight | Self::EstimatedOneRepMax => "kilograms",
+            Self::MostRepetitions => "repetitions",
+            Self::LongestDuration => "seconds",
+        }
+    }
+}
+
+impl_bindable!(PersonalRecordType);
@@ -9,6 +9,10 @@ use strum_macros::Display;
 #[strum(serialize_all = "snake_case")]
 pub enum Role {
     Admin,
+    /// Programs training for their clients.
+    Coach,
+    /// Looks after the exercise catalog.
+    Moderator,
     User,
 }
 
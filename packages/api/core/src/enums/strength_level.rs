@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+#[derive(Clone, Debug, Deserialize, Display, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum StrengthLevel {
+    Beginner,
+    Novice,
+    Intermediate,
+    Advanced,
+    Elite,
+}
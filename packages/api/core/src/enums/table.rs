@@ -8,7 +8,9 @@ use strum_macros::Display;
 #[sqlx(rename_all = "snake_case", type_name = "varchar")]
 #[strum(serialize_all = "snake_case")]
 pub enum Table {
+    ExerciseEquipment,
     Exercises,
+    MuscleGroups,
     Muscles,
     Users,
     UserProfiles,
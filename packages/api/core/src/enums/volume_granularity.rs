@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+/// The period training volume is grouped by. Its string form
+/// matches the field names accepted by Postgres' `date_trunc`.
+#[derive(Clone, Debug, Default, Deserialize, Display, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VolumeGranularity {
+    #[default]
+    Week,
+    Month,
+}
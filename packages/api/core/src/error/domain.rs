@@ -1,7 +1,15 @@
 #[derive(Clone, Debug, strum_macros::Display)]
 pub enum Domain {
+    Analytics,
+    Coaching,
     Database,
+    Equipment,
+    Exercise,
+    Muscle,
+    Pagination,
+    Program,
     SystemUtilities,
     UserAuthentication,
     UserRegistration,
+    Workout,
 }
@@ -1,7 +1,9 @@
-use crate::models::User;
+use crate::enums::Permission;
+use crate::models::{CoachingRelationship, User};
 use async_trait::async_trait;
 use axum::extract::FromRequestParts;
 use axum::http::request::Parts;
+use database::DatabaseManager;
 
 pub(self) use crate::http::errors::Error;
 pub(self) type Result<TValue> = ::core::result::Result<TValue, Error>;
@@ -9,16 +11,55 @@ pub(self) type Result<TValue> = ::core::result::Result<TValue, Error>;
 #[derive(Clone, Debug)]
 pub struct Context {
     user: User,
+    permissions: Vec<Permission>,
+    coach: Option<User>,
 }
 
 impl Context {
-    pub fn new(user: User) -> Self {
-        Self { user }
+    pub fn new(user: User, permissions: Vec<Permission>) -> Self {
+        Self { user, permissions, coach: None }
     }
 
     pub fn user(&self) -> &User {
         &self.user
     }
+
+    /// The coach acting on the user's behalf, if the context was scoped
+    /// with [`Context::for_client`].
+    pub fn coach(&self) -> Option<&User> {
+        self.coach.as_ref()
+    }
+
+    pub fn can(&self, permission: Permission) -> bool {
+        self.permissions.contains(&permission)
+    }
+
+    /// Fails with a forbidden error unless the user has `permission`.
+    pub fn authorize(&self, permission: Permission) -> Result<()> {
+        match self.can(permission) {
+            true => Ok(()),
+            false => Err(Error::MissingPermission),
+        }
+    }
+
+    /// Scopes the context to the client of one of the user's accepted coaching
+    /// relationships. The scoped context acts as the client, but carries none
+    /// of the coach's permissions.
+    pub async fn for_client(&self, relationship: String, database: &DatabaseManager) -> ::core::result::Result<Self, crate::http::Error> {
+        self.authorize(Permission::ClientManage)?;
+
+        let relationship = CoachingRelationship::find_accepted_for_coach(
+            relationship,
+            self.user.id,
+            database,
+        ).await?;
+
+        Ok(Self {
+            user: relationship.client(database).await?,
+            permissions: Vec::new(),
+            coach: Some(self.user.clone()),
+        })
+    }
 }
 
 #[async_trait]
@@ -0,0 +1,79 @@
+use super::{Controller, Result};
+use crate::actions;
+use crate::enums::{OneRepMaxFormula, VolumeGranularity};
+use crate::http::resources::{OneRepMaxTrendResource, VolumeReportResource};
+use crate::http::{Context, JsonResponse};
+use crate::models::Exercise;
+use axum::extract::{Path, Query, State};
+use axum::routing::{get, Router};
+use chrono::{Duration, NaiveDate, Utc};
+use database::{DatabaseManager, HasRouteKey};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct OneRepMaxParams {
+    formula: Option<OneRepMaxFormula>,
+}
+
+#[derive(Deserialize)]
+pub struct VolumeParams {
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+    granularity: Option<VolumeGranularity>,
+}
+
+pub struct AnalyticsController;
+
+impl Controller for AnalyticsController {
+    fn router(state: DatabaseManager) -> Router {
+        Router::new()
+            .route("/one-rep-max/:ulid", get(Self::one_rep_max))
+            .route("/volume", get(Self::volume))
+            .with_state(state)
+    }
+}
+
+impl AnalyticsController {
+    pub async fn one_rep_max(
+        context: Context,
+        Query(params): Query<OneRepMaxParams>,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let exercise = Exercise::find_by_route_key(ulid, &database).await?;
+        let formula = params.formula.unwrap_or_default();
+
+        let trend = actions::one_rep_max_trend(
+            context.user(),
+            &exercise,
+            &formula,
+            &database,
+        ).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(OneRepMaxTrendResource::new(context.user(), exercise, formula, trend, &database).await?)
+        )
+    }
+
+    pub async fn volume(
+        context: Context,
+        Query(params): Query<VolumeParams>,
+        State(database): State<DatabaseManager>,
+    ) -> Result<JsonResponse> {
+        let to = params.to.unwrap_or_else(|| Utc::now().date_naive());
+        let from = params.from.unwrap_or(to - Duration::days(28));
+        let granularity = params.granularity.unwrap_or_default();
+
+        let (muscles, muscle_groups) = actions::volume_report(
+            context.user(),
+            from,
+            to,
+            &granularity,
+            &database,
+        ).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(VolumeReportResource::new(from, to, granularity, muscles, muscle_groups))
+        )
+    }
+}
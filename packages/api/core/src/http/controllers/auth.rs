@@ -1,7 +1,7 @@
 use super::{Controller, Error, Result};
 use crate::actions;
 use crate::data;
-use crate::enums::{Gender, Role};
+use crate::enums::{Gender, Permission, Role};
 use crate::http::resources::{ModelResource, UserResource};
 use crate::http::{Context, JsonResponse};
 use crate::models::{User, Profile};
@@ -20,6 +20,7 @@ use std::collections::HashMap;
 #[derive(Deserialize)]
 pub struct RegisterPayload {
     pub birthday: NaiveDate,
+    pub bodyweight: Option<f64>,
     pub email: String,
     pub first_name: String,
     pub last_name: String,
@@ -82,15 +83,9 @@ impl AuthController {
         State(database): State<DatabaseManager>,
         Json(payload): Json<LoginPayload>,
     ) -> Result<JsonResponse> {
-        let mut user = User::find_by_email(payload.email, &database)
-            .await
-            .map_err(|_| Error::NoMatchingCredentialsFound)?;
+        let (mut user, permissions) = Self::authenticate(payload, &database).await?;
 
-        if !crypt::decrypt_and_verify(payload.password.as_str(), user.password.as_str())? {
-            return Err(Error::NoMatchingCredentialsFound)?;
-        }
-
-        if user.role == Role::Admin {
+        if permissions.contains(&Permission::AdminAccess) {
             return Err(Error::UnauthorizedUserRole)?;
         }
 
@@ -107,15 +102,9 @@ impl AuthController {
         State(database): State<DatabaseManager>,
         Json(payload): Json<LoginPayload>,
     ) -> Result<JsonResponse> {
-        let mut user = User::find_by_email(&payload.email, &database)
-            .await
-            .map_err(|_| Error::NoMatchingCredentialsFound)?;
+        let (mut user, permissions) = Self::authenticate(payload, &database).await?;
 
-        if !crypt::decrypt_and_verify(payload.password.as_str(), user.password.as_str())? {
-            return Err(Error::NoMatchingCredentialsFound)?;
-        }
-
-        if user.role != Role::Admin {
+        if !permissions.contains(&Permission::AdminAccess) {
             return Err(Error::UnauthorizedUserRole)?;
         }
 
@@ -127,6 +116,22 @@ impl AuthController {
         )
     }
 
+    /// Finds the user matching the credentials, along with the permissions
+    /// the user's role grants.
+    async fn authenticate(payload: LoginPayload, database: &DatabaseManager) -> Result<(User, Vec<Permission>)> {
+        let user = User::find_by_email(payload.email, database)
+            .await
+            .map_err(|_| Error::NoMatchingCredentialsFound)?;
+
+        if !crypt::decrypt_and_verify(payload.password.as_str(), user.password.as_str())? {
+            return Err(Error::NoMatchingCredentialsFound)?;
+        }
+
+        let permissions = user.permissions(database).await?;
+
+        Ok((user, permissions))
+    }
+
     pub async fn logout(session: Session, context: Option<Context>) -> Result<JsonResponse> {
         context.ok_or(Error::RequestExtensionMissingContext)?;
 
@@ -138,6 +143,12 @@ impl AuthController {
         State(database): State<DatabaseManager>,
         Json(payload): Json<RegisterPayload>,
     ) -> Result<JsonResponse> {
+        if !matches!(payload.role, None | Some(Role::User)) {
+            return Err(Error::UnauthorizedUserRole)?;
+        }
+
+        let mut transaction = database.transaction().await?;
+
         let user = actions::create_user(
             data::CreateUserData {
                 email: payload.email.as_str(),
@@ -147,7 +158,7 @@ impl AuthController {
                 password: payload.password.as_str(),
                 password_confirm: payload.password_confirm.as_str(),
             },
-            &database,
+            &mut transaction,
         ).await?;
 
         let _profile = actions::create_user_profile(
@@ -155,10 +166,13 @@ impl AuthController {
                 user_id: user.id,
                 birthday: payload.birthday,
                 gender: payload.gender,
+                bodyweight: payload.bodyweight,
             },
-            &database,
+            &mut transaction,
         ).await?;
 
+        transaction.commit().await?;
+
         Ok(JsonResponse::created()
             .with_data(UserResource::default(user, &database).await?)
         )
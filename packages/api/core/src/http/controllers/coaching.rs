@@ -0,0 +1,162 @@
+use super::routine::CreateRoutinePayload;
+use super::{Controller, Result, RoutineController, WorkoutController};
+use crate::actions;
+use crate::enums::Permission;
+use crate::http::extractors::Pagination;
+use crate::http::resources::{CoachingRelationshipResource, ModelResource};
+use crate::http::{Context, JsonResponse};
+use crate::models::CoachingRelationship;
+use axum::extract::{Path, State};
+use axum::response::Json;
+use axum::routing::{get, post, Router};
+use database::DatabaseManager;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct InviteClientPayload {
+    email: String,
+}
+
+pub struct CoachingController;
+
+impl Controller for CoachingController {
+    fn router(state: DatabaseManager) -> Router {
+        Router::new()
+            .route("/", get(Self::list).post(Self::invite))
+            .route("/:ulid", get(Self::read).delete(Self::revoke))
+            .route("/:ulid/accept", post(Self::accept))
+            .route("/:ulid/decline", post(Self::decline))
+            .route("/:ulid/routines", get(Self::list_routines).post(Self::create_routine))
+            .route("/:ulid/workouts", get(Self::list_workouts))
+            .route("/:ulid/workouts/:workout_ulid", get(Self::read_workout))
+            .with_state(state)
+    }
+}
+
+impl CoachingController {
+    pub async fn invite(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Json(payload): Json<InviteClientPayload>,
+    ) -> Result<JsonResponse> {
+        context.authorize(Permission::ClientManage)?;
+
+        let relationship = actions::invite_client(
+            context.user(),
+            payload.email.as_str(),
+            &database,
+        ).await?;
+
+        Ok(JsonResponse::created()
+            .with_data(CoachingRelationshipResource::default(relationship, &database).await?)
+        )
+    }
+
+    pub async fn list(
+        context: Context,
+        State(database): State<DatabaseManager>,
+    ) -> Result<JsonResponse> {
+        let relationships = CoachingRelationship::all_open_for_user(context.user().id, &database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(CoachingRelationshipResource::list(relationships, &database).await?)
+        )
+    }
+
+    pub async fn read(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let relationship = CoachingRelationship::find_open_for_user(ulid, context.user().id, &database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(CoachingRelationshipResource::default(relationship, &database).await?)
+        )
+    }
+
+    pub async fn accept(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let mut relationship = CoachingRelationship::find_pending_for_client(ulid, context.user().id, &database).await?;
+
+        relationship.accept(&database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(CoachingRelationshipResource::default(relationship, &database).await?)
+        )
+    }
+
+    pub async fn decline(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let mut relationship = CoachingRelationship::find_pending_for_client(ulid, context.user().id, &database).await?;
+
+        relationship.decline(&database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(CoachingRelationshipResource::default(relationship, &database).await?)
+        )
+    }
+
+    /// Either side can end the relationship at any time, and a coach can
+    /// withdraw an invitation that hasn't been answered yet.
+    pub async fn revoke(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let mut relationship = CoachingRelationship::find_open_for_user(ulid, context.user().id, &database).await?;
+
+        relationship.revoke(&database).await?;
+
+        Ok(JsonResponse::no_content())
+    }
+
+    pub async fn list_routines(
+        context: Context,
+        pagination: Pagination,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let context = context.for_client(ulid, &database).await?;
+
+        RoutineController::list(context, pagination, State(database)).await
+    }
+
+    pub async fn create_routine(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+        payload: Json<CreateRoutinePayload>,
+    ) -> Result<JsonResponse> {
+        let context = context.for_client(ulid, &database).await?;
+
+        RoutineController::create(context, State(database), payload).await
+    }
+
+    pub async fn list_workouts(
+        context: Context,
+        pagination: Pagination,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let context = context.for_client(ulid, &database).await?;
+
+        WorkoutController::list(context, pagination, State(database)).await
+    }
+
+    pub async fn read_workout(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path((ulid, workout_ulid)): Path<(String, String)>,
+    ) -> Result<JsonResponse> {
+        let context = context.for_client(ulid, &database).await?;
+
+        WorkoutController::read(context, State(database), Path(workout_ulid)).await
+    }
+}
@@ -0,0 +1,122 @@
+use super::{Controller, Result};
+use crate::actions;
+use crate::data::EquipmentProfileData;
+use crate::http::extractors::Pagination;
+use crate::http::resources::{EquipmentProfileResource, ModelResource};
+use crate::http::{Context, JsonResponse};
+use crate::models::EquipmentProfile;
+use axum::extract::{Path, State};
+use axum::response::Json;
+use axum::routing::{get, Router};
+use database::{DatabaseManager, Model};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct EquipmentProfilePayload {
+    name: String,
+    equipment_ids: Vec<i16>,
+}
+
+pub struct EquipmentProfileController;
+
+impl Controller for EquipmentProfileController {
+    fn router(state: DatabaseManager) -> Router {
+        Router::new()
+            .route("/", get(Self::list).post(Self::create))
+            .route("/:ulid", get(Self::read).put(Self::update).delete(Self::delete))
+            .with_state(state)
+    }
+}
+
+impl EquipmentProfileController {
+    pub async fn create(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Json(payload): Json<EquipmentProfilePayload>,
+    ) -> Result<JsonResponse> {
+        let mut transaction = database.transaction().await?;
+
+        let profile = actions::create_equipment_profile(
+            context.user(),
+            EquipmentProfileData {
+                name: payload.name,
+                equipment_ids: payload.equipment_ids,
+            },
+            &mut transaction,
+        ).await?;
+
+        transaction.commit().await?;
+
+        Ok(JsonResponse::created()
+            .with_data(EquipmentProfileResource::default(profile, &database).await?)
+        )
+    }
+
+    pub async fn read(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let profile = EquipmentProfile::find_for_user(ulid, context.user().id, &database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(EquipmentProfileResource::default(profile, &database).await?)
+        )
+    }
+
+    pub async fn list(
+        context: Context,
+        pagination: Pagination,
+        State(database): State<DatabaseManager>,
+    ) -> Result<JsonResponse> {
+        let query = EquipmentProfile::query()
+            .select(&["*"])
+            .and_where("user_id", "=", context.user().id)
+            .order_by("name", true)
+            .order_by("id", true);
+        let (profiles, page) = pagination.paginate(query, &database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(EquipmentProfileResource::list(profiles, &database).await?)
+            .with_pagination(page)
+        )
+    }
+
+    pub async fn update(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+        Json(payload): Json<EquipmentProfilePayload>,
+    ) -> Result<JsonResponse> {
+        let mut profile = EquipmentProfile::find_for_user(ulid, context.user().id, &database).await?;
+
+        let mut transaction = database.transaction().await?;
+
+        actions::update_equipment_profile(
+            &mut profile,
+            EquipmentProfileData {
+                name: payload.name,
+                equipment_ids: payload.equipment_ids,
+            },
+            &mut transaction,
+        ).await?;
+
+        transaction.commit().await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(EquipmentProfileResource::default(profile, &database).await?)
+        )
+    }
+
+    pub async fn delete(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let profile = EquipmentProfile::find_for_user(ulid, context.user().id, &database).await?;
+
+        profile.delete(&database).await?;
+
+        Ok(JsonResponse::no_content())
+    }
+}
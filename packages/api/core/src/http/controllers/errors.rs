@@ -1,20 +1,62 @@
 use crate::error::{ClientError, Domain};
+use crate::prelude::__;
 use axum::http::StatusCode;
 
 #[derive(Debug, strum_macros::Display, thiserror::Error)]
 pub enum Error {
+    EquipmentInUse,
+    ExerciseInUse,
+    MuscleGroupInUse,
+    MuscleInUse,
     NoMatchingCredentialsFound,
+    NotEnrolledInProgram,
     RequestExtensionMissingContext,
     UnauthorizedUserRole,
 }
 
+impl Error {
+    /// Reports a conflict from deleting a model as this error, since rows still
+    /// reference the model.
+    pub fn on_conflict(self, error: database::Error) -> crate::http::Error {
+        match error {
+            database::Error::Conflict(_) => self.into(),
+            error => error.into(),
+        }
+    }
+}
+
 impl From<Error> for crate::http::Error {
     fn from(error: Error) -> Self {
         match error {
+            Error::EquipmentInUse => Self::conflict(
+                ClientError::Conflict,
+                Domain::Equipment,
+            )
+                .with_message(__("errors.equipment.inUse")),
+            Error::ExerciseInUse => Self::conflict(
+                ClientError::Conflict,
+                Domain::Exercise,
+            )
+                .with_message(__("errors.exercise.inUse")),
+            Error::MuscleGroupInUse => Self::conflict(
+                ClientError::Conflict,
+                Domain::Muscle,
+            )
+                .with_message(__("errors.muscleGroup.inUse")),
+            Error::MuscleInUse => Self::conflict(
+                ClientError::Conflict,
+                Domain::Muscle,
+            )
+                .with_message(__("errors.muscle.inUse")),
             Error::NoMatchingCredentialsFound => Self::bad_request(
                 ClientError::InvalidCredentials,
                 Domain::UserAuthentication,
             ),
+            Error::NotEnrolledInProgram => Self::not_found(
+                ClientError::ResourceNotFound,
+                Domain::Program,
+            )
+                .with_message(__("errors.program.notEnrolled")),
             Error::RequestExtensionMissingContext => Self::not_authenticated(
                 ClientError::NotAuthenticated,
                 Domain::UserAuthentication,
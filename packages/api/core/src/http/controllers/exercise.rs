@@ -1,13 +1,17 @@
-use super::{Controller, Result};
+use super::{Controller, Error, Result};
 use crate::prelude::*;
-use crate::enums::{ExerciseForce, ExerciseMechanic, ExerciseMuscleTarget, ExerciseType, Measurement};
+use crate::enums::{ExerciseForce, ExerciseMechanic, ExerciseMuscleTarget, ExerciseSort, ExerciseType, Measurement, Permission, Table};
 use crate::http::extractors::Pagination;
-use crate::http::resources::{ModelResource, ExerciseResource};
+use crate::http::resources::{ModelResource, ExerciseAlternativeResource, ExerciseResource};
+use crate::http::Context;
+use crate::http::middleware::require_permission;
 use crate::http::response::JsonResponse;
-use crate::models::{Exercise, ExerciseMuscleMap};
+use crate::models::{EquipmentProfile, Exercise, ExerciseFilter, ExerciseMuscleMap, Link};
+use crate::utils::deserializers;
 use axum::extract::{Path, State, Query};
+use axum::middleware;
 use axum::response::Json;
-use axum::routing::{get, post, Router};
+use axum::routing::{delete, get, patch, post, Router};
 use database::{DatabaseManager, HasRouteKey, Model};
 use serde::Deserialize;
 
@@ -32,10 +36,61 @@ pub struct CreateExercisePayload {
     muscles: Vec<MuscleData>
 }
 
+#[derive(Deserialize)]
+pub struct UpdateExercisePayload {
+    #[serde(rename = "type")]
+    exercise_type: Option<ExerciseType>,
+    #[serde(default, deserialize_with = "deserializers::nullable")]
+    target_muscle_group_id: Option<Option<i16>>,
+    name: Option<String>,
+    #[serde(default, deserialize_with = "deserializers::nullable")]
+    name_alternative: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserializers::nullable")]
+    description: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserializers::nullable")]
+    equipment_id: Option<Option<i16>>,
+    #[serde(default, deserialize_with = "deserializers::nullable")]
+    mechanic: Option<Option<ExerciseMechanic>>,
+    #[serde(default, deserialize_with = "deserializers::nullable")]
+    force: Option<Option<ExerciseForce>>,
+    #[serde(default, deserialize_with = "deserializers::nullable")]
+    measurement: Option<Option<Measurement>>,
+    /// Replaces every muscle the exercise targets.
+    muscles: Option<Vec<MuscleData>>,
+    /// Replaces every instruction, numbering them in the given order.
+    instructions: Option<Vec<String>>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ListExerciseParams {
-    muscle: Option<i16>,
-    muscle_group: Option<i16>,
+    #[serde(default, deserialize_with = "deserializers::comma_separated")]
+    muscle: Option<Vec<i16>>,
+    #[serde(default, deserialize_with = "deserializers::comma_separated")]
+    muscle_group: Option<Vec<i16>>,
+    #[serde(default, deserialize_with = "deserializers::comma_separated")]
+    equipment: Option<Vec<i16>>,
+    #[serde(default, deserialize_with = "deserializers::comma_separated", rename = "type")]
+    exercise_type: Option<Vec<ExerciseType>>,
+    #[serde(default, deserialize_with = "deserializers::comma_separated")]
+    mechanic: Option<Vec<ExerciseMechanic>>,
+    #[serde(default, deserialize_with = "deserializers::comma_separated")]
+    force: Option<Vec<ExerciseForce>>,
+    #[serde(default, deserialize_with = "deserializers::comma_separated")]
+    measurement: Option<Vec<Measurement>>,
+    search: Option<String>,
+    sort: Option<ExerciseSort>,
+    available_in: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExerciseAlternativesParams {
+    #[serde(default, deserialize_with = "deserializers::comma_separated")]
+    equipment: Option<Vec<i16>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearchExerciseParams {
+    q: String,
 }
 
 pub struct ExerciseController;
@@ -43,8 +98,19 @@ pub struct ExerciseController;
 impl Controller for ExerciseController {
     fn router(state: DatabaseManager) -> Router {
         Router::new()
-            .route("/", get(Self::list).post(Self::create))
+            .route("/", post(Self::create)
+                .route_layer(middleware::from_fn_with_state(Permission::ExerciseCreate, require_permission))
+            )
+            .route("/:ulid", patch(Self::update)
+                .route_layer(middleware::from_fn_with_state(Permission::ExerciseUpdate, require_permission))
+            )
+            .route("/:ulid", delete(Self::delete)
+                .route_layer(middleware::from_fn_with_state(Permission::ExerciseDelete, require_permission))
+            )
+            .route("/", get(Self::list))
+            .route("/search", get(Self::search))
             .route("/:ulid", get(Self::read))
+            .route("/:ulid/alternatives", get(Self::alternatives))
             .with_state(state)
     }
 }
@@ -54,6 +120,8 @@ impl ExerciseController {
         State(database): State<DatabaseManager>,
         Json(payload): Json<CreateExercisePayload>,
     ) -> Result<JsonResponse> {
+        let mut transaction = database.transaction().await?;
+
         let exercise = Exercise::new()
             .exercise_type(payload.exercise_type)
             .target_muscle_group_id(payload.target_muscle_group_id)
@@ -64,7 +132,7 @@ impl ExerciseController {
             .mechanic(payload.mechanic)
             .force(payload.force)
             .measurement(payload.measurement)
-            .create(&database)
+            .create(&mut transaction)
             .await?;
 
         for muscle in payload.muscles {
@@ -72,10 +140,12 @@ impl ExerciseController {
                 .exercise_id(exercise.id)
                 .muscle_id(muscle.muscle_id)
                 .target(muscle.target)
-                .create(&database)
+                .create(&mut transaction)
                 .await?;
         }
 
+        transaction.commit().await?;
+
         Ok(JsonResponse::created()
             .with_data(ExerciseResource::default(exercise, &database).await?)
         )
@@ -92,26 +162,176 @@ impl ExerciseController {
         )
     }
 
+    pub async fn update(
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+        Json(payload): Json<UpdateExercisePayload>,
+    ) -> Result<JsonResponse> {
+        let mut transaction = database.transaction().await?;
+
+        // Re-read once the row is locked so that the changes apply to its latest
+        // state rather than overwriting a concurrent update.
+        let exercise = Exercise::find_by_route_key(ulid, &mut transaction).await?;
+        exercise.lock(&mut transaction).await?;
+        let mut exercise = Exercise::find_by_pk(exercise.id, &mut transaction).await?;
+
+        if let Some(exercise_type) = payload.exercise_type {
+            exercise.exercise_type = exercise_type;
+        }
+
+        if let Some(target_muscle_group_id) = payload.target_muscle_group_id {
+            exercise.target_muscle_group_id = target_muscle_group_id;
+        }
+
+        if let Some(name) = payload.name {
+            exercise.name = name;
+        }
+
+        if let Some(name_alternative) = payload.name_alternative {
+            exercise.name_alternative = name_alternative;
+        }
+
+        if let Some(description) = payload.description {
+            exercise.description = description;
+        }
+
+        if let Some(equipment_id) = payload.equipment_id {
+            exercise.equipment_id = equipment_id;
+        }
+
+        if let Some(mechanic) = payload.mechanic {
+            exercise.mechanic = mechanic;
+        }
+
+        if let Some(force) = payload.force {
+            exercise.force = force;
+        }
+
+        if let Some(measurement) = payload.measurement {
+            exercise.measurement = measurement;
+        }
+
+        exercise.save(&mut transaction).await?;
+
+        if let Some(muscles) = payload.muscles {
+            let muscles = muscles.into_iter()
+                .map(|muscle| (muscle.muscle_id, muscle.target))
+                .collect();
+
+            exercise.replace_muscles(muscles, &mut transaction).await?;
+        }
+
+        if let Some(instructions) = payload.instructions {
+            let instructions = (1..)
+                .zip(instructions)
+                .collect();
+
+            exercise.replace_instructions(instructions, &mut transaction).await?;
+        }
+
+        transaction.commit().await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(ExerciseResource::default(exercise, &database).await?)
+        )
+    }
+
+    pub async fn delete(
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let exercise = Exercise::find_by_route_key(ulid, &database).await?;
+
+        let mut transaction = database.transaction().await?;
+
+        exercise.lock(&mut transaction).await?;
+
+        if exercise.is_in_use(&mut transaction).await? {
+            return Err(Error::ExerciseInUse)?;
+        }
+
+        Link::delete_for_model(Table::Exercises, exercise.id, &mut transaction).await?;
+        exercise.delete(&mut transaction).await.map_err(|error| Error::ExerciseInUse.on_conflict(error))?;
+
+        transaction.commit().await?;
+
+        Ok(JsonResponse::no_content())
+    }
+
     pub async fn list(
+        context: Context,
         pagination: Pagination,
         Query(params): Query<ListExerciseParams>,
         State(database): State<DatabaseManager>,
     ) -> Result<JsonResponse> {
-        let mut query = Exercise::query()
-            .select(&["*"])
-            .limit(pagination.limit())
-            .offset(pagination.offset());
+        let available_equipment_ids = match params.available_in {
+            Some(ulid) => Some(
+                EquipmentProfile::find_for_user(ulid, context.user().id, &database)
+                    .await?
+                    .equipment_ids(&database)
+                    .await?
+            ),
+            None => None,
+        };
 
-        if let Some(group) = params.muscle_group {
-            query = query.and_where("target_muscle_group_id", "=", group);
-        }
+        let filter = ExerciseFilter {
+            muscle_ids: params.muscle,
+            muscle_group_ids: params.muscle_group,
+            equipment_ids: params.equipment,
+            types: params.exercise_type,
+            mechanics: params.mechanic,
+            forces: params.force,
+            measurements: params.measurement,
+            search: params.search.filter(|search| !search.trim().is_empty()),
+            available_equipment_ids,
+            sort: params.sort.unwrap_or_default(),
+        };
 
-        let exercises = query
-            .all(database.connection())
-            .await?;
+        let (exercises, page) = pagination.paginate(Exercise::filtered(&filter), &database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(ExerciseResource::list(exercises, &database).await?)
+            .with_pagination(page)
+        )
+    }
+
+    pub async fn alternatives(
+        pagination: Pagination,
+        Query(params): Query<ExerciseAlternativesParams>,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let exercise = Exercise::find_by_route_key(ulid, &database).await?;
+
+        let alternatives = exercise.alternatives(
+            params.equipment.as_deref(),
+            pagination.limit(),
+            pagination.offset(),
+            &database,
+        ).await?;
+
+        let page = pagination.uncounted(alternatives.len());
+
+        Ok(JsonResponse::ok()
+            .with_data(ExerciseAlternativeResource::list(alternatives, &database).await?)
+            .with_pagination(page)
+        )
+    }
+
+    pub async fn search(
+        pagination: Pagination,
+        Query(params): Query<SearchExerciseParams>,
+        State(database): State<DatabaseManager>,
+    ) -> Result<JsonResponse> {
+        let exercises = match params.q.trim() {
+            "" => Vec::new(),
+            query => Exercise::search(query, pagination.limit(), pagination.offset(), &database).await?,
+        };
+        let page = pagination.uncounted(exercises.len());
 
         Ok(JsonResponse::ok()
             .with_data(ExerciseResource::list(exercises, &database).await?)
+            .with_pagination(page)
         )
     }
 }
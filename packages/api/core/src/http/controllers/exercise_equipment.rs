@@ -1,11 +1,14 @@
-use super::{Controller, Result};
+use super::{Controller, Error, Result};
 use crate::prelude::*;
+use crate::enums::{Permission, Table};
 use crate::http::resources::{ModelResource, ExerciseEquipmentResource};
+use crate::http::middleware::require_permission;
 use crate::http::response::JsonResponse;
-use crate::models::ExerciseEquipment;
+use crate::models::{ExerciseEquipment, ExerciseSourceMapping, Link};
 use axum::extract::{Path, State};
+use axum::middleware;
 use axum::response::Json;
-use axum::routing::{get, Router};
+use axum::routing::{get, patch, post, Router};
 use database::{DatabaseManager, HasRouteKey, Model};
 use serde::Deserialize;
 
@@ -14,12 +17,20 @@ pub struct CreateExerciseEquipmentPayload {
     name: String,
 }
 
+#[derive(Deserialize)]
+pub struct UpdateExerciseEquipmentPayload {
+    name: Option<String>,
+}
+
 pub struct ExerciseEquipmentController;
 
 impl Controller for ExerciseEquipmentController {
     fn router(state: DatabaseManager) -> Router {
         Router::new()
-            .route("/", get(Self::list).post(Self::create))
+            .route("/", post(Self::create))
+            .route("/:id", patch(Self::update).delete(Self::delete))
+            .route_layer(middleware::from_fn_with_state(Permission::EquipmentManage, require_permission))
+            .route("/", get(Self::list))
             .route("/:id", get(Self::read))
             .with_state(state)
     }
@@ -51,6 +62,47 @@ impl ExerciseEquipmentController {
         )
     }
 
+    pub async fn update(
+        State(database): State<DatabaseManager>,
+        Path(id): Path<i16>,
+        Json(payload): Json<UpdateExerciseEquipmentPayload>,
+    ) -> Result<JsonResponse> {
+        let mut equipment = ExerciseEquipment::find_by_route_key(id, &database).await?;
+
+        if let Some(name) = payload.name {
+            equipment.name = name;
+        }
+
+        equipment.save(&database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(ExerciseEquipmentResource::default(equipment, &database).await?)
+        )
+    }
+
+    pub async fn delete(
+        State(database): State<DatabaseManager>,
+        Path(id): Path<i16>,
+    ) -> Result<JsonResponse> {
+        let equipment = ExerciseEquipment::find_by_route_key(id, &database).await?;
+
+        let mut transaction = database.transaction().await?;
+
+        equipment.lock(&mut transaction).await?;
+
+        if equipment.is_in_use(&mut transaction).await? {
+            return Err(Error::EquipmentInUse)?;
+        }
+
+        Link::delete_for_model(Table::ExerciseEquipment, equipment.id, &mut transaction).await?;
+        ExerciseSourceMapping::delete_for_model(Table::ExerciseEquipment, equipment.id, &mut transaction).await?;
+        equipment.delete(&mut transaction).await.map_err(|error| Error::EquipmentInUse.on_conflict(error))?;
+
+        transaction.commit().await?;
+
+        Ok(JsonResponse::no_content())
+    }
+
     pub async fn list(State(database): State<DatabaseManager>) -> Result<JsonResponse> {
         let groups = ExerciseEquipment::all(&database).await?;
 
@@ -1,13 +1,16 @@
 use super::{Controller, Result};
 use crate::prelude::*;
-use crate::enums::{LinkFormat, LinkType, Table};
+use crate::enums::{LinkFormat, LinkType, Permission, Table};
 use crate::http::resources::{LinkResource, ModelResource};
+use crate::http::middleware::require_permission;
 use crate::http::response::JsonResponse;
 use crate::models::Link;
-use axum::extract::State;
+use crate::utils::deserializers;
+use axum::extract::{Path, State};
+use axum::middleware;
 use axum::response::Json;
-use axum::routing::{get, post, Router};
-use database::{DatabaseManager, Model};
+use axum::routing::{get, patch, post, Router};
+use database::{DatabaseManager, HasRouteKey, Model};
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -22,12 +25,27 @@ pub struct CreateLinkPayload {
     src: String,
 }
 
+#[derive(Deserialize)]
+pub struct UpdateLinkPayload {
+    #[serde(rename = "type")]
+    link_type: Option<LinkType>,
+    format: Option<LinkFormat>,
+    label: Option<String>,
+    #[serde(default, deserialize_with = "deserializers::nullable")]
+    description: Option<Option<String>>,
+    src: Option<String>,
+}
+
 pub struct LinkController;
 
 impl Controller for LinkController {
     fn router(state: DatabaseManager) -> Router {
         Router::new()
-            .route("/", get(Self::list).post(Self::create))
+            .route("/", post(Self::create))
+            .route("/:ulid", patch(Self::update).delete(Self::delete))
+            .route_layer(middleware::from_fn_with_state(Permission::LinkManage, require_permission))
+            .route("/", get(Self::list))
+            .route("/:ulid", get(Self::read))
             .with_state(state)
     }
 }
@@ -63,4 +81,60 @@ impl LinkController {
             .with_data(LinkResource::default(link, &database).await?)
         )
     }
+
+    pub async fn read(
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let link = Link::find_by_route_key(ulid, &database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(LinkResource::default(link, &database).await?)
+        )
+    }
+
+    pub async fn update(
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+        Json(payload): Json<UpdateLinkPayload>,
+    ) -> Result<JsonResponse> {
+        let mut link = Link::find_by_route_key(ulid, &database).await?;
+
+        if let Some(link_type) = payload.link_type {
+            link.link_type = link_type;
+        }
+
+        if let Some(format) = payload.format {
+            link.format = format;
+        }
+
+        if let Some(label) = payload.label {
+            link.label = label;
+        }
+
+        if let Some(description) = payload.description {
+            link.description = description;
+        }
+
+        if let Some(src) = payload.src {
+            link.src = src;
+        }
+
+        link.save(&database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(LinkResource::default(link, &database).await?)
+        )
+    }
+
+    pub async fn delete(
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let link = Link::find_by_route_key(ulid, &database).await?;
+
+        link.delete(&database).await?;
+
+        Ok(JsonResponse::no_content())
+    }
 }
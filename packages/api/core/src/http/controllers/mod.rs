@@ -1,4 +1,7 @@
+mod analytics;
 mod auth;
+mod coaching;
+mod equipment_profile;
 mod errors;
 mod exercise;
 mod exercise_equipment;
@@ -6,14 +9,27 @@ mod link;
 mod muscle;
 mod muscle_group;
 mod health;
+mod personal_record;
+mod program;
+mod routine;
+mod user;
+mod workout;
 
+pub use analytics::AnalyticsController;
 pub use auth::AuthController;
+pub use coaching::CoachingController;
+pub use equipment_profile::EquipmentProfileController;
 pub use exercise::ExerciseController;
 pub use exercise_equipment::ExerciseEquipmentController;
 pub use health::HealthController;
 pub use link::LinkController;
 pub use muscle::MuscleController;
 pub use muscle_group::MuscleGroupController;
+pub use personal_record::PersonalRecordController;
+pub use program::ProgramController;
+pub use routine::RoutineController;
+pub use user::UserController;
+pub use workout::WorkoutController;
 
 pub(self) use errors::Error;
 pub(self) type Result<TValue> = ::core::result::Result<TValue, crate::http::Error>;
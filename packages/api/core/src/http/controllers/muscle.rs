@@ -1,14 +1,18 @@
-use super::{Controller, Result};
+use super::{Controller, Error, Result};
 use crate::prelude::*;
+use crate::enums::{Permission, Table};
 use crate::http::Context;
 use crate::http::extractors::Pagination;
 use crate::http::resources::{ModelResource, MuscleResource};
+use crate::http::middleware::require_permission;
 use crate::http::response::JsonResponse;
-use crate::models::Muscle;
+use crate::models::{ExerciseSourceMapping, Link, Muscle};
+use crate::utils::deserializers;
 use axum::extract::{Path, State};
+use axum::middleware;
 use axum::response::Json;
-use axum::routing::{get, post, Router};
-use database::{DatabaseManager, HasRouteKey, Model};
+use axum::routing::{get, patch, post, Router};
+use database::{DatabaseManager, HasRouteKey, Model, SqlxAction};
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -21,13 +25,30 @@ pub struct CreateMusclePayload {
     image_source: Option<String>,
 }
 
+#[derive(Deserialize)]
+pub struct UpdateMusclePayload {
+    group_id: Option<i16>,
+    #[serde(default, deserialize_with = "deserializers::nullable")]
+    parent_id: Option<Option<i16>>,
+    name: Option<String>,
+    #[serde(default, deserialize_with = "deserializers::nullable")]
+    simple_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserializers::nullable")]
+    description: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserializers::nullable")]
+    image_source: Option<Option<String>>,
+}
+
 pub struct MuscleController;
 
 impl Controller for MuscleController {
     fn router(state: DatabaseManager) -> Router {
         Router::new()
-            .route("/", get(Self::list).post(Self::create))
-            .route("/:id", get(Self::read))
+            .route("/", post(Self::create))
+            .route("/:ulid", patch(Self::update).delete(Self::delete))
+            .route_layer(middleware::from_fn_with_state(Permission::MuscleManage, require_permission))
+            .route("/", get(Self::list))
+            .route("/:ulid", get(Self::read))
             .with_state(state)
     }
 }
@@ -66,6 +87,67 @@ impl MuscleController {
         )
     }
 
+    pub async fn update(
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+        Json(payload): Json<UpdateMusclePayload>,
+    ) -> Result<JsonResponse> {
+        let mut muscle = Muscle::find_by_route_key(ulid, &database).await?;
+
+        if let Some(group_id) = payload.group_id {
+            muscle.group_id = group_id;
+        }
+
+        if let Some(parent_id) = payload.parent_id {
+            muscle.parent_id = parent_id;
+        }
+
+        if let Some(name) = payload.name {
+            muscle.name = name;
+        }
+
+        if let Some(simple_name) = payload.simple_name {
+            muscle.simple_name = simple_name;
+        }
+
+        if let Some(description) = payload.description {
+            muscle.description = description;
+        }
+
+        if let Some(image_source) = payload.image_source {
+            muscle.image_source = image_source;
+        }
+
+        muscle.save(&database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(MuscleResource::default(muscle, &database).await?)
+        )
+    }
+
+    pub async fn delete(
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let muscle = Muscle::find_by_route_key(ulid, &database).await?;
+
+        let mut transaction = database.transaction().await?;
+
+        muscle.lock(&mut transaction).await?;
+
+        if muscle.is_in_use(&mut transaction).await? {
+            return Err(Error::MuscleInUse)?;
+        }
+
+        Link::delete_for_model(Table::Muscles, muscle.id, &mut transaction).await?;
+        ExerciseSourceMapping::delete_for_model(Table::Muscles, muscle.id, &mut transaction).await?;
+        muscle.delete(&mut transaction).await.map_err(|error| Error::MuscleInUse.on_conflict(error))?;
+
+        transaction.commit().await?;
+
+        Ok(JsonResponse::no_content())
+    }
+
     pub async fn list(
         pagination: Pagination,
         State(database): State<DatabaseManager>,
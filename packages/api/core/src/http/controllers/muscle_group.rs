@@ -1,13 +1,17 @@
-use super::{Controller, Result};
+use super::{Controller, Error, Result};
 use crate::prelude::*;
+use crate::enums::{Permission, Table};
 use crate::http::resources::{ModelResource, MuscleGroupResource};
+use crate::http::middleware::require_permission;
 use crate::http::response::JsonResponse;
-use crate::models::MuscleGroup;
+use crate::models::{ExerciseSourceMapping, Link, MuscleGroup};
+use crate::utils::deserializers;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
+use axum::middleware;
 use axum::response::Json;
-use axum::routing::{get, post, Router};
-use database::{DatabaseManager, Model};
+use axum::routing::{get, patch, post, Router};
+use database::{DatabaseManager, Model, SqlxAction};
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -16,12 +20,22 @@ pub struct CreateMuscleGroupPayload {
     image_source: Option<String>,
 }
 
+#[derive(Deserialize)]
+pub struct UpdateMuscleGroupPayload {
+    name: Option<String>,
+    #[serde(default, deserialize_with = "deserializers::nullable")]
+    image_source: Option<Option<String>>,
+}
+
 pub struct MuscleGroupController;
 
 impl Controller for MuscleGroupController {
     fn router(state: DatabaseManager) -> Router {
         Router::new()
-            .route("/", get(Self::list).post(Self::create))
+            .route("/", post(Self::create))
+            .route("/:id", patch(Self::update).delete(Self::delete))
+            .route_layer(middleware::from_fn_with_state(Permission::MuscleManage, require_permission))
+            .route("/", get(Self::list))
             .route("/:id", get(Self::read))
             .with_state(state)
     }
@@ -57,6 +71,51 @@ impl MuscleGroupController {
         )
     }
 
+    pub async fn update(
+        Path(id): Path<i16>,
+        State(database): State<DatabaseManager>,
+        Json(payload): Json<UpdateMuscleGroupPayload>,
+    ) -> Result<JsonResponse> {
+        let mut group = MuscleGroup::find_by_pk(id, &database).await?;
+
+        if let Some(name) = payload.name {
+            group.name = name;
+        }
+
+        if let Some(image_source) = payload.image_source {
+            group.image_source = image_source;
+        }
+
+        group.save(&database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(MuscleGroupResource::default(group, &database).await?)
+        )
+    }
+
+    pub async fn delete(
+        Path(id): Path<i16>,
+        State(database): State<DatabaseManager>,
+    ) -> Result<JsonResponse> {
+        let group = MuscleGroup::find_by_pk(id, &database).await?;
+
+        let mut transaction = database.transaction().await?;
+
+        group.lock(&mut transaction).await?;
+
+        if group.is_in_use(&mut transaction).await? {
+            return Err(Error::MuscleGroupInUse)?;
+        }
+
+        Link::delete_for_model(Table::MuscleGroups, group.id, &mut transaction).await?;
+        ExerciseSourceMapping::delete_for_model(Table::MuscleGroups, group.id, &mut transaction).await?;
+        group.delete(&mut transaction).await.map_err(|error| Error::MuscleGroupInUse.on_conflict(error))?;
+
+        transaction.commit().await?;
+
+        Ok(JsonResponse::no_content())
+    }
+
     pub async fn list(
         State(database): State<DatabaseManager>,
     ) -> Result<JsonResponse> {
@@ -0,0 +1,49 @@
+use super::{Controller, Result};
+use crate::http::resources::{ModelResource, PersonalRecordResource};
+use crate::http::{Context, JsonResponse};
+use crate::models::{Exercise, PersonalRecord};
+use axum::extract::{Path, State};
+use axum::routing::{get, Router};
+use database::{DatabaseManager, HasRouteKey};
+
+pub struct PersonalRecordController;
+
+impl Controller for PersonalRecordController {
+    fn router(state: DatabaseManager) -> Router {
+        Router::new()
+            .route("/", get(Self::list))
+            .route("/exercises/:ulid", get(Self::timeline))
+            .with_state(state)
+    }
+}
+
+impl PersonalRecordController {
+    pub async fn list(
+        context: Context,
+        State(database): State<DatabaseManager>,
+    ) -> Result<JsonResponse> {
+        let records = PersonalRecord::current_for_user(context.user().id, &database).await?;
+        let mut resources = Vec::with_capacity(records.len());
+
+        for record in records {
+            resources.push(PersonalRecordResource::default(record, &database).await?);
+        }
+
+        Ok(JsonResponse::ok()
+            .with_data(resources)
+        )
+    }
+
+    pub async fn timeline(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let exercise = Exercise::find_by_route_key(ulid, &database).await?;
+        let records = PersonalRecord::timeline(context.user().id, exercise.id, &database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(PersonalRecordResource::list(records, &database).await?)
+        )
+    }
+}
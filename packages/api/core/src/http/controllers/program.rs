@@ -0,0 +1,203 @@
+use super::{Controller, Error, Result};
+use crate::actions;
+use crate::data::{CreateProgramData, CreateProgramDayData};
+use crate::http::extractors::Pagination;
+use crate::http::resources::{
+    ModelResource,
+    ProgramEnrollmentResource,
+    ProgramResource,
+    ProgramSessionResource,
+    WorkoutResource,
+};
+use crate::http::{Context, JsonResponse};
+use crate::models::{Program, ProgramEnrollment, Routine};
+use axum::extract::{Path, State};
+use axum::response::Json;
+use axum::routing::{get, post, Router};
+use chrono::{NaiveDate, Utc};
+use database::{DatabaseManager, HasRouteKey, Model};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct ProgramDayPayload {
+    routine_id: String,
+    week_number: i16,
+    day_number: i16,
+}
+
+#[derive(Deserialize)]
+pub struct CreateProgramPayload {
+    name: String,
+    description: Option<String>,
+    weeks: i16,
+    weekly_increment: Option<f64>,
+    deload_every: Option<i16>,
+    deload_factor: Option<f64>,
+    days: Vec<ProgramDayPayload>,
+}
+
+#[derive(Deserialize)]
+pub struct EnrollPayload {
+    started_on: Option<NaiveDate>,
+}
+
+pub struct ProgramController;
+
+impl Controller for ProgramController {
+    fn router(state: DatabaseManager) -> Router {
+        Router::new()
+            .route("/", get(Self::list).post(Self::create))
+            .route("/:ulid", get(Self::read))
+            .route("/:ulid/enrollment", post(Self::enroll).delete(Self::unenroll))
+            .route("/:ulid/today", get(Self::today).post(Self::start_today))
+            .with_state(state)
+    }
+}
+
+impl ProgramController {
+    pub async fn create(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Json(payload): Json<CreateProgramPayload>,
+    ) -> Result<JsonResponse> {
+        let mut days = Vec::with_capacity(payload.days.len());
+
+        for day in payload.days {
+            days.push(CreateProgramDayData {
+                routine_id: Routine::find_for_user(day.routine_id, context.user().id, &database).await?.id,
+                week_number: day.week_number,
+                day_number: day.day_number,
+            });
+        }
+
+        let mut transaction = database.transaction().await?;
+
+        let program = actions::create_program(
+            context.user(),
+            CreateProgramData {
+                name: payload.name,
+                description: payload.description,
+                weeks: payload.weeks,
+                weekly_increment: payload.weekly_increment,
+                deload_every: payload.deload_every,
+                deload_factor: payload.deload_factor,
+                days,
+            },
+            &mut transaction,
+        ).await?;
+
+        transaction.commit().await?;
+
+        Ok(JsonResponse::created()
+            .with_data(ProgramResource::default(program, &database).await?)
+        )
+    }
+
+    pub async fn read(
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let program = Program::find_by_route_key(ulid, &database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(ProgramResource::default(program, &database).await?)
+        )
+    }
+
+    pub async fn list(
+        pagination: Pagination,
+        State(database): State<DatabaseManager>,
+    ) -> Result<JsonResponse> {
+        let query = Program::query()
+            .select(&["*"])
+            .order_by("name", true)
+            .order_by("id", true);
+        let (programs, page) = pagination.paginate(query, &database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(ProgramResource::list(programs, &database).await?)
+            .with_pagination(page)
+        )
+    }
+
+    pub async fn enroll(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+        Json(payload): Json<EnrollPayload>,
+    ) -> Result<JsonResponse> {
+        let program = Program::find_by_route_key(ulid, &database).await?;
+
+        let enrollment = actions::enroll_in_program(
+            context.user(),
+            &program,
+            payload.started_on.unwrap_or_else(|| Utc::now().date_naive()),
+            &database,
+        ).await?;
+
+        Ok(JsonResponse::created()
+            .with_data(ProgramEnrollmentResource::default(enrollment, &database).await?)
+        )
+    }
+
+    pub async fn unenroll(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let program = Program::find_by_route_key(ulid, &database).await?;
+        let mut enrollment = ProgramEnrollment::find_active(program.id, context.user().id, &database)
+            .await?
+            .ok_or(Error::NotEnrolledInProgram)?;
+
+        enrollment.end(&database).await?;
+
+        Ok(JsonResponse::no_content())
+    }
+
+    pub async fn today(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let program = Program::find_by_route_key(ulid, &database).await?;
+        let enrollment = ProgramEnrollment::find_active(program.id, context.user().id, &database)
+            .await?
+            .ok_or(Error::NotEnrolledInProgram)?;
+
+        let session = match enrollment.next_day(&program, Utc::now().date_naive(), &database).await? {
+            Some(day) => Some(ProgramSessionResource::new(&program, day, &database).await?),
+            None => None,
+        };
+
+        Ok(JsonResponse::ok()
+            .with_data(session)
+        )
+    }
+
+    pub async fn start_today(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let program = Program::find_by_route_key(ulid, &database).await?;
+        let enrollment = ProgramEnrollment::find_active(program.id, context.user().id, &database)
+            .await?
+            .ok_or(Error::NotEnrolledInProgram)?;
+
+        let mut transaction = database.transaction().await?;
+
+        let workout = actions::start_program_day(
+            context.user(),
+            &enrollment,
+            Utc::now().date_naive(),
+            &mut transaction,
+        ).await?;
+
+        transaction.commit().await?;
+
+        Ok(JsonResponse::created()
+            .with_data(WorkoutResource::default(workout, &database).await?)
+        )
+    }
+}
@@ -0,0 +1,142 @@
+use super::{Controller, Result};
+use crate::actions;
+use crate::data::{CreateRoutineData, CreateRoutineExerciseData};
+use crate::http::extractors::Pagination;
+use crate::http::resources::{ModelResource, RoutineResource, WorkoutResource};
+use crate::http::{Context, JsonResponse};
+use crate::models::{Exercise, Routine};
+use axum::extract::{Path, State};
+use axum::response::Json;
+use axum::routing::{get, post, Router};
+use database::{DatabaseManager, HasRouteKey, Model};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct RoutineExercisePayload {
+    exercise_id: String,
+    target_sets: i16,
+    target_repetitions: Option<i16>,
+    target_weight: Option<f64>,
+    rest_seconds: Option<i32>,
+}
+
+#[derive(Deserialize)]
+pub struct CreateRoutinePayload {
+    name: String,
+    description: Option<String>,
+    exercises: Vec<RoutineExercisePayload>,
+}
+
+pub struct RoutineController;
+
+impl Controller for RoutineController {
+    fn router(state: DatabaseManager) -> Router {
+        Router::new()
+            .route("/", get(Self::list).post(Self::create))
+            .route("/:ulid", get(Self::read).delete(Self::delete))
+            .route("/:ulid/start", post(Self::start))
+            .with_state(state)
+    }
+}
+
+impl RoutineController {
+    pub async fn create(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Json(payload): Json<CreateRoutinePayload>,
+    ) -> Result<JsonResponse> {
+        let mut exercises = Vec::with_capacity(payload.exercises.len());
+
+        for exercise in payload.exercises {
+            exercises.push(CreateRoutineExerciseData {
+                exercise_id: Exercise::find_by_route_key(exercise.exercise_id, &database).await?.id,
+                target_sets: exercise.target_sets,
+                target_repetitions: exercise.target_repetitions,
+                target_weight: exercise.target_weight,
+                rest_seconds: exercise.rest_seconds,
+            });
+        }
+
+        let mut transaction = database.transaction().await?;
+
+        let routine = actions::create_routine(
+            context.user(),
+            CreateRoutineData {
+                name: payload.name,
+                description: payload.description,
+                exercises,
+            },
+            &mut transaction,
+        ).await?;
+
+        transaction.commit().await?;
+
+        Ok(JsonResponse::created()
+            .with_data(RoutineResource::default(routine, &database).await?)
+        )
+    }
+
+    pub async fn read(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let routine = Routine::find_for_user(ulid, context.user().id, &database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(RoutineResource::default(routine, &database).await?)
+        )
+    }
+
+    pub async fn list(
+        context: Context,
+        pagination: Pagination,
+        State(database): State<DatabaseManager>,
+    ) -> Result<JsonResponse> {
+        let query = Routine::query()
+            .select(&["*"])
+            .and_where("user_id", "=", context.user().id)
+            .order_by("name", true)
+            .order_by("id", true);
+        let (routines, page) = pagination.paginate(query, &database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(RoutineResource::list(routines, &database).await?)
+            .with_pagination(page)
+        )
+    }
+
+    pub async fn delete(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let routine = Routine::find_for_user(ulid, context.user().id, &database).await?;
+
+        routine.delete(&database).await?;
+
+        Ok(JsonResponse::no_content())
+    }
+
+    pub async fn start(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let routine = Routine::find_for_user(ulid, context.user().id, &database).await?;
+
+        let mut transaction = database.transaction().await?;
+
+        let workout = actions::start_workout_from_routine(
+            context.user(),
+            &routine,
+            &mut transaction,
+        ).await?;
+
+        transaction.commit().await?;
+
+        Ok(JsonResponse::created()
+            .with_data(WorkoutResource::default(workout, &database).await?)
+        )
+    }
+}
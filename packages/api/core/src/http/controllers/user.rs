@@ -0,0 +1,80 @@
+use super::{Controller, Result, WorkoutController};
+use crate::enums::{Permission, Role};
+use crate::http::extractors::Pagination;
+use crate::http::resources::{ModelResource, UserResource};
+use crate::http::{Context, JsonResponse};
+use crate::models::User;
+use axum::extract::{Path, State};
+use axum::response::Json;
+use axum::routing::{get, put, Router};
+use database::{DatabaseManager, Model};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct UpdateUserRolePayload {
+    role: Role,
+}
+
+pub struct UserController;
+
+impl Controller for UserController {
+    fn router(state: DatabaseManager) -> Router {
+        Router::new()
+            .route("/:id/role", put(Self::update_role))
+            .route("/:id/workouts", get(Self::list_workouts))
+            .route("/:id/workouts/:workout_ulid", get(Self::read_workout))
+            .with_state(state)
+    }
+}
+
+impl UserController {
+    /// Moves the user to another role. Registration only creates regular
+    /// users, so this is how coaches and staff are made.
+    pub async fn update_role(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(id): Path<i16>,
+        Json(payload): Json<UpdateUserRolePayload>,
+    ) -> Result<JsonResponse> {
+        context.authorize(Permission::UserManage)?;
+
+        let mut user = User::find_by_pk(id, &database).await?;
+        user.role = payload.role;
+        user.save(&database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(UserResource::simple(user, &database).await?)
+        )
+    }
+
+    pub async fn list_workouts(
+        context: Context,
+        pagination: Pagination,
+        State(database): State<DatabaseManager>,
+        Path(id): Path<i16>,
+    ) -> Result<JsonResponse> {
+        let context = Self::viewing(context, id, &database).await?;
+
+        WorkoutController::list(context, pagination, State(database)).await
+    }
+
+    pub async fn read_workout(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path((id, workout_ulid)): Path<(i16, String)>,
+    ) -> Result<JsonResponse> {
+        let context = Self::viewing(context, id, &database).await?;
+
+        WorkoutController::read(context, State(database), Path(workout_ulid)).await
+    }
+
+    /// A context acting as the user with the given id, carrying none of the
+    /// viewer's permissions.
+    async fn viewing(context: Context, id: i16, database: &DatabaseManager) -> Result<Context> {
+        context.authorize(Permission::WorkoutViewOthers)?;
+
+        let user = User::find_by_pk(id, database).await?;
+
+        Ok(Context::new(user, Vec::new()))
+    }
+}
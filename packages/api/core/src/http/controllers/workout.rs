@@ -0,0 +1,164 @@
+use super::{Controller, Result};
+use crate::actions;
+use crate::data::LogWorkoutSetData;
+use crate::http::extractors::Pagination;
+use crate::http::resources::{ModelResource, WorkoutExerciseResource, WorkoutResource, WorkoutSetResource};
+use crate::http::{Context, JsonResponse};
+use crate::models::{Exercise, Workout, WorkoutExercise};
+use axum::extract::{Path, State};
+use axum::response::Json;
+use axum::routing::{get, post, Router};
+use database::{DatabaseManager, HasRouteKey, Model};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct StartWorkoutPayload {
+    name: Option<String>,
+    notes: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct AddWorkoutExercisePayload {
+    exercise_id: String,
+    notes: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct LogWorkoutSetPayload {
+    weight: Option<f64>,
+    repetitions: Option<i16>,
+    duration: Option<i32>,
+}
+
+pub struct WorkoutController;
+
+impl Controller for WorkoutController {
+    fn router(state: DatabaseManager) -> Router {
+        Router::new()
+            .route("/", get(Self::list).post(Self::create))
+            .route("/:ulid", get(Self::read))
+            .route("/:ulid/exercises", post(Self::add_exercise))
+            .route("/:ulid/exercises/:workout_exercise_ulid/sets", post(Self::log_set))
+            .route("/:ulid/finish", post(Self::finish))
+            .with_state(state)
+    }
+}
+
+impl WorkoutController {
+    pub async fn create(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Json(payload): Json<StartWorkoutPayload>,
+    ) -> Result<JsonResponse> {
+        let workout = Workout::new()
+            .user(context.user())
+            .name(payload.name)
+            .notes(payload.notes)
+            .create(&database)
+            .await?;
+
+        Ok(JsonResponse::created()
+            .with_data(WorkoutResource::default(workout, &database).await?)
+        )
+    }
+
+    pub async fn read(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let workout = Workout::find_for_user(ulid, context.user().id, &database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(WorkoutResource::default(workout, &database).await?)
+        )
+    }
+
+    pub async fn list(
+        context: Context,
+        pagination: Pagination,
+        State(database): State<DatabaseManager>,
+    ) -> Result<JsonResponse> {
+        let query = Workout::query()
+            .select(&["*"])
+            .and_where("user_id", "=", context.user().id);
+        let (workouts, page) = pagination.paginate_by_cursor(
+            query,
+            |workout: &Workout| (workout.created_at, workout.id),
+            &database,
+        ).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(WorkoutResource::list(workouts, &database).await?)
+            .with_pagination(page)
+        )
+    }
+
+    pub async fn add_exercise(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+        Json(payload): Json<AddWorkoutExercisePayload>,
+    ) -> Result<JsonResponse> {
+        let workout = Workout::find_for_user(ulid, context.user().id, &database).await?;
+        let exercise = Exercise::find_by_route_key(payload.exercise_id, &database).await?;
+
+        let workout_exercise = actions::add_workout_exercise(
+            &workout,
+            &exercise,
+            payload.notes,
+            &database,
+        ).await?;
+
+        Ok(JsonResponse::created()
+            .with_data(WorkoutExerciseResource::default(workout_exercise, &database).await?)
+        )
+    }
+
+    pub async fn log_set(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path((ulid, workout_exercise_ulid)): Path<(String, String)>,
+        Json(payload): Json<LogWorkoutSetPayload>,
+    ) -> Result<JsonResponse> {
+        let workout = Workout::find_for_user(ulid, context.user().id, &database).await?;
+        let workout_exercise = WorkoutExercise::find_for_workout(
+            workout_exercise_ulid,
+            workout.id,
+            &database,
+        ).await?;
+
+        let mut transaction = database.transaction().await?;
+
+        let set = actions::log_workout_set(
+            &workout,
+            &workout_exercise,
+            LogWorkoutSetData {
+                weight: payload.weight,
+                repetitions: payload.repetitions,
+                duration: payload.duration,
+            },
+            &mut transaction,
+        ).await?;
+
+        transaction.commit().await?;
+
+        Ok(JsonResponse::created()
+            .with_data(WorkoutSetResource::default(set, &database).await?)
+        )
+    }
+
+    pub async fn finish(
+        context: Context,
+        State(database): State<DatabaseManager>,
+        Path(ulid): Path<String>,
+    ) -> Result<JsonResponse> {
+        let mut workout = Workout::find_for_user(ulid, context.user().id, &database).await?;
+
+        actions::finish_workout(&mut workout, &database).await?;
+
+        Ok(JsonResponse::ok()
+            .with_data(WorkoutResource::default(workout, &database).await?)
+        )
+    }
+}
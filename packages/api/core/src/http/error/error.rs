@@ -70,7 +70,7 @@ impl Error {
             client,
             StatusCode::FORBIDDEN,
             domain,
-            __("errors.generic.http.401"),
+            __("errors.generic.http.403"),
             None,
             None,
         )
@@ -271,6 +271,21 @@ impl From<database::Error> for Error {
         let err_pointer = &error;
         let message = err_pointer.to_string();
 
+        if let database::Error::ModelNotFound { .. } = err_pointer {
+            return Self::not_found(ClientError::ResourceNotFound, Domain::Database)
+                .with_message(message);
+        }
+
+        if let database::Error::Conflict(_) = err_pointer {
+            return Self::conflict(ClientError::Conflict, Domain::Database)
+                .with_message(message);
+        }
+
+        if let database::Error::InvalidData(_) = err_pointer {
+            return Self::unprocessable(ClientError::Validation, Domain::Database)
+                .with_message(message);
+        }
+
         Self::internal_error(
             ClientError::Unknown,
             Domain::Database,
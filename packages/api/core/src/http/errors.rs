@@ -1,18 +1,42 @@
 use crate::prelude::__;
 use crate::error::{ClientError, Domain};
+use crate::types::ErrorMap;
 use axum::http::StatusCode;
 
 #[derive(Debug, strum_macros::Display, thiserror::Error)]
 pub enum Error {
+    InvalidCursor,
+    InvalidPage,
+    InvalidPerPage,
+    MissingPermission,
     NoMatchingSessionUserFound,
+    PageOutOfRange,
     RequestExtensionMissingContext,
 }
 
 impl From<Error> for crate::http::Error {
     fn from(error: Error) -> Self {
-        Self::not_authenticated(
-            ClientError::NotAuthenticated,
-            Domain::UserAuthentication,
-        )
+        let invalid_parameter = |parameter: &str, message: &'static str| {
+            let mut map = ErrorMap::new();
+            map.insert(parameter.to_string(), vec![__(message)]);
+
+            Self::bad_request(ClientError::InvalidRequest, Domain::Pagination)
+                .with_messages(map)
+        };
+
+        match error {
+            Error::InvalidCursor => invalid_parameter("cursor", "errors.pagination.invalidCursor"),
+            Error::InvalidPage => invalid_parameter("page", "errors.pagination.invalidPage"),
+            Error::InvalidPerPage => invalid_parameter("per_page", "errors.pagination.invalidPerPage"),
+            Error::PageOutOfRange => invalid_parameter("page", "errors.pagination.pageOutOfRange"),
+            Error::MissingPermission => Self::forbidden(
+                ClientError::UnauthorizedAction,
+                Domain::UserAuthentication,
+            ),
+            Error::NoMatchingSessionUserFound | Error::RequestExtensionMissingContext => Self::not_authenticated(
+                ClientError::NotAuthenticated,
+                Domain::UserAuthentication,
+            ),
+        }
     }
 }
@@ -1,14 +1,30 @@
 use async_trait::async_trait;
 use axum::{
-    extract::{FromRequestParts, Query},
+    extract::{FromRequestParts, OriginalUri},
     http::request::Parts,
 };
-use serde::Deserialize;
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{NaiveDateTime, TimeZone, Utc};
+use crate::http::errors::Error;
+use crate::http::response::PageMeta;
+use crate::types::ISO8601DateTimeUTC;
+use database::{DatabaseManager, SelectAction, SqlxAction};
+use sqlx::{FromRow, postgres::PgRow};
 
-#[derive(Clone, Debug, Deserialize)]
+type Result<TValue> = core::result::Result<TValue, crate::http::Error>;
+
+/// The most results a client can ask for in a single page.
+pub const MAX_PER_PAGE: i64 = 100;
+const DEFAULT_PER_PAGE: i64 = 10;
+
+#[derive(Clone, Debug)]
 pub struct Pagination {
     page: i64,
     per_page: i64,
+    cursor: Option<Cursor>,
+    path: String,
+    /// Every other query parameter, kept so that links stay on the same filters.
+    params: Vec<(String, String)>,
 }
 
 impl Pagination {
@@ -19,13 +35,135 @@ impl Pagination {
     pub fn offset(&self) -> i64 {
         self.per_page * (self.page - 1)
     }
+
+    /// Loads the requested page of `query` using LIMIT and OFFSET, along with
+    /// the total number of rows the query matches.
+    pub async fn paginate<M>(
+        &self,
+        query: SelectAction<'_>,
+        database: &DatabaseManager,
+    ) -> Result<(Vec<M>, PageMeta)>
+    where
+        M: for<'r> FromRow<'r, PgRow> + Send + Unpin,
+    {
+        let total = query.count(database.connection()).await?;
+        let models = query
+            .limit(self.limit())
+            .offset(self.offset())
+            .all::<_, M>(database.connection())
+            .await?;
+
+        let meta = PageMeta {
+            total: Some(total),
+            page: Some(self.page),
+            per_page: self.per_page,
+            next: (self.page * self.per_page < total).then(|| self.page_link(self.page + 1)),
+            prev: (self.page > 1).then(|| self.page_link(self.page - 1)),
+        };
+
+        Ok((models, meta))
+    }
+
+    /// Describes a page of results from a query that can't be counted, linking
+    /// to the next page whenever this one is full.
+    pub fn uncounted(&self, returned: usize) -> PageMeta {
+        PageMeta {
+            total: None,
+            page: Some(self.page),
+            per_page: self.per_page,
+            next: (returned as i64 >= self.per_page).then(|| self.page_link(self.page + 1)),
+            prev: (self.page > 1).then(|| self.page_link(self.page - 1)),
+        }
+    }
+
+    /// Loads the page of `query` that follows the requested cursor, newest
+    /// first by `(created_at, id)`. Unlike `paginate`, this doesn't count
+    /// or skip over rows, so it stays fast however far back a client pages.
+    pub async fn paginate_by_cursor<M, F>(
+        &self,
+        query: SelectAction<'_>,
+        key: F,
+        database: &DatabaseManager,
+    ) -> Result<(Vec<M>, PageMeta)>
+    where
+        M: for<'r> FromRow<'r, PgRow> + Send + Unpin,
+        F: Fn(&M) -> (ISO8601DateTimeUTC, i32),
+    {
+        let backwards = matches!(&self.cursor, Some(cursor) if cursor.direction == Direction::Before);
+
+        let query = match &self.cursor {
+            Some(cursor) => query.where_raw(
+                match backwards {
+                    true => "(created_at, id) > (?, ?)",
+                    false => "(created_at, id) < (?, ?)",
+                },
+                (cursor.created_at, cursor.id),
+            ),
+            None => query,
+        };
+
+        let mut models = query
+            .order_by("created_at", backwards)
+            .order_by("id", backwards)
+            .limit(self.per_page + 1)
+            .all::<_, M>(database.connection())
+            .await?;
+
+        let has_more = models.len() as i64 > self.per_page;
+        models.truncate(self.per_page as usize);
+
+        if backwards {
+            models.reverse();
+        }
+
+        let (has_next, has_prev) = match backwards {
+            true => (true, has_more),
+            false => (has_more, self.cursor.is_some()),
+        };
+
+        let link = |model: Option<&M>, direction: Direction| model.map(|model| {
+            let (created_at, id) = key(model);
+            self.cursor_link(&Cursor { created_at, id, direction })
+        });
+
+        let meta = PageMeta {
+            total: None,
+            page: None,
+            per_page: self.per_page,
+            next: has_next.then(|| link(models.last(), Direction::After)).flatten(),
+            prev: has_prev.then(|| link(models.first(), Direction::Before)).flatten(),
+        };
+
+        Ok((models, meta))
+    }
+
+    fn page_link(&self, page: i64) -> String {
+        self.link(("page", page.to_string()))
+    }
+
+    fn cursor_link(&self, cursor: &Cursor) -> String {
+        self.link(("cursor", cursor.encode()))
+    }
+
+    fn link(&self, position: (&str, String)) -> String {
+        let mut params = self.params.iter()
+            .map(|(key, value)| (key.as_str(), value.clone()))
+            .collect::<Vec<(&str, String)>>();
+        params.push(position);
+        params.push(("per_page", self.per_page.to_string()));
+
+        format!("{}?{}", self.path, serde_urlencoded::to_string(params).unwrap_or_default())
+    }
 }
 
 impl Default for Pagination {
     fn default() -> Self {
         Self {
             page: 1,
-            per_page: 10,
+            per_page: DEFAULT_PER_PAGE,
+            cursor: None,
+            path: String::new(),
+            params: Vec::new(),
         }
     }
 }
@@ -37,11 +175,109 @@ where
 {
     type Rejection = crate::http::Error;
 
-    async fn from_request_parts(parts: &mut Parts, state: &S) -> core::result::Result<Self, Self::Rejection> {
-        let Query(pagination) = Query::<Pagination>::from_request_parts(parts, state)
-            .await
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> core::result::Result<Self, Self::Rejection> {
+        // Nested routers only see the end of the path, so links are built from the original.
+        let uri = match parts.extensions.get::<OriginalUri>() {
+            Some(OriginalUri(uri)) => uri.clone(),
+            None => parts.uri.clone(),
+        };
+
+        let params = serde_urlencoded::from_str::<Vec<(String, String)>>(uri.query().unwrap_or_default())
             .unwrap_or_default();
 
+        let mut pagination = Self {
+            path: uri.path().to_string(),
+            ..Self::default()
+        };
+
+        for (key, value) in params {
+            match key.as_str() {
+                "page" => pagination.page = value.parse::<i64>()
+                    .ok()
+                    .filter(|page| *page >= 1)
+                    .ok_or(Error::InvalidPage)?,
+                "per_page" => pagination.per_page = value.parse::<i64>()
+                    .ok()
+                    .filter(|per_page| (1..=MAX_PER_PAGE).contains(per_page))
+                    .ok_or(Error::InvalidPerPage)?,
+                "cursor" => pagination.cursor = Some(Cursor::decode(&value).ok_or(Error::InvalidCursor)?),
+                _ => pagination.params.push((key, value)),
+            }
+        }
+
+        // Offsets and links go up to the start of the following page, which has to fit in an i64.
+        if pagination.page.checked_add(1).and_then(|next| next.checked_mul(pagination.per_page)).is_none() {
+            return Err(Error::PageOutOfRange)?;
+        }
+
         Ok(pagination)
     }
 }
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Direction {
+    After,
+    Before,
+}
+
+/// A position in a list ordered by `(created_at, id)`, handed to clients as
+/// an opaque string along with the direction to page in.
+#[derive(Clone, Debug, PartialEq)]
+struct Cursor {
+    created_at: ISO8601DateTimeUTC,
+    id: i32,
+    direction: Direction,
+}
+
+impl Cursor {
+    fn encode(&self) -> String {
+        let direction = match self.direction {
+            Direction::After => "a",
+            Direction::Before => "b",
+        };
+
+        URL_SAFE_NO_PAD.encode(format!("{}:{}:{}", direction, self.created_at.timestamp_micros(), self.id))
+    }
+
+    fn decode(value: &str) -> Option<Self> {
+        let decoded = String::from_utf8(URL_SAFE_NO_PAD.decode(value).ok()?).ok()?;
+        let mut parts = decoded.splitn(3, ':');
+
+        let direction = match parts.next()? {
+            "a" => Direction::After,
+            "b" => Direction::Before,
+            _ => return None,
+        };
+        let micros = parts.next()?.parse::<i64>().ok()?;
+        let id = parts.next()?.parse::<i32>().ok()?;
+
+        Some(Self {
+            created_at: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp_micros(micros)?),
+            id,
+            direction,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cursor, Direction};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn cursor_round_trips() {
+        let cursor = Cursor {
+            created_at: Utc.with_ymd_and_hms(2023, 11, 18, 10, 22, 30).unwrap() + chrono::Duration::microseconds(123456),
+            id: 42,
+            direction: Direction::Before,
+        };
+
+        assert_eq!(Cursor::decode(&cursor.encode()), Some(cursor));
+    }
+
+    #[test]
+    fn cursor_rejects_garbage() {
+        assert_eq!(Cursor::decode("not a cursor"), None);
+        assert_eq!(Cursor::decode(&base64::Engine::encode(&base64::engine::general_purpose::URL_SAFE_NO_PAD, "c:1:2")), None);
+    }
+}
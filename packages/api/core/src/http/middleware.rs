@@ -1,3 +1,4 @@
+use crate::enums::Permission;
 use crate::http::Context;
 use crate::models::User;
 use axum::extract::State;
@@ -48,7 +49,9 @@ pub async fn context_resolver<TBody>(
 
     match User::find_by_pk(user_id, &database).await {
         Ok(user) => {
-            request.extensions_mut().insert(Context::new(user));
+            let permissions = user.permissions(&database).await?;
+
+            request.extensions_mut().insert(Context::new(user, permissions));
             Ok(next.run(request).await)
         },
         Err(err) => {
@@ -90,3 +93,17 @@ pub async fn require_auth<TBody>(
 
     Ok(next.run(request).await)
 }
+
+/// Only lets users with the permission in the middleware state through,
+/// e.g. `middleware::from_fn_with_state(Permission::LinkManage, require_permission)`.
+/// Layered inside `require_auth`, so guests are still told to authenticate.
+pub async fn require_permission<TBody>(
+    State(permission): State<Permission>,
+    context: Result<Context>,
+    request: Request<TBody>,
+    next: Next<TBody>,
+) -> Result<Response> {
+    context?.authorize(permission)?;
+
+    Ok(next.run(request).await)
+}
@@ -8,10 +8,7 @@ pub mod middleware;
 pub mod resources;
 pub mod response;
 
-pub use self::router::init;
+pub use self::router::router;
 pub use context::Context;
 pub use error::Error as Error;
 pub use response::JsonResponse;
-
-#[cfg(test)]
-pub use self::router::router;
@@ -0,0 +1,42 @@
+use super::{ModelResource, ResourceResult, UserResource};
+use crate::enums::CoachingStatus;
+use crate::prelude::*;
+use crate::models::CoachingRelationship;
+use async_trait::async_trait;
+use database::{DatabaseManager, HasRouteKey};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct CoachingRelationshipResource {
+    id: String,
+    coach: UserResource,
+    client: UserResource,
+    status: CoachingStatus,
+    responded_at: Option<ISO8601DateTimeUTC>,
+    ended_at: Option<ISO8601DateTimeUTC>,
+    created_at: ISO8601DateTimeUTC,
+}
+
+#[async_trait]
+impl ModelResource for CoachingRelationshipResource {
+    type Model = CoachingRelationship;
+
+    async fn default(relationship: CoachingRelationship, database: &DatabaseManager) -> ResourceResult<Self> {
+        Self::simple(relationship, database).await
+    }
+
+    async fn simple(relationship: CoachingRelationship, database: &DatabaseManager) -> ResourceResult<Self> {
+        let coach = UserResource::simple(relationship.coach(database).await?, database).await?;
+        let client = UserResource::simple(relationship.client(database).await?, database).await?;
+
+        Ok(Self {
+            id: relationship.route_key(),
+            coach,
+            client,
+            status: relationship.status,
+            responded_at: relationship.responded_at,
+            ended_at: relationship.ended_at,
+            created_at: relationship.created_at,
+        })
+    }
+}
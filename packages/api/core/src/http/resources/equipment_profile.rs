@@ -0,0 +1,48 @@
+use super::{ExerciseEquipmentResource, ModelResource, ResourceResult};
+use crate::prelude::*;
+use crate::models::EquipmentProfile;
+use async_trait::async_trait;
+use database::{DatabaseManager, HasRouteKey};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct EquipmentProfileResource {
+    id: String,
+    name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    equipment: Option<Vec<ExerciseEquipmentResource>>,
+
+    created_at: ISO8601DateTimeUTC,
+    updated_at: ISO8601DateTimeUTC,
+}
+
+#[async_trait]
+impl ModelResource for EquipmentProfileResource {
+    type Model = EquipmentProfile;
+
+    async fn default(profile: EquipmentProfile, database: &DatabaseManager) -> ResourceResult<Self> {
+        let equipment = ExerciseEquipmentResource::list(
+            profile.equipment(database).await?,
+            database,
+        ).await?;
+
+        Ok(Self {
+            id: profile.route_key(),
+            name: profile.name,
+            equipment: Some(equipment),
+            created_at: profile.created_at,
+            updated_at: profile.updated_at,
+        })
+    }
+
+    async fn simple(profile: EquipmentProfile, database: &DatabaseManager) -> ResourceResult<Self> {
+        Ok(Self {
+            id: profile.route_key(),
+            name: profile.name,
+            equipment: None,
+            created_at: profile.created_at,
+            updated_at: profile.updated_at,
+        })
+    }
+}
@@ -7,10 +7,10 @@ use super::{
     MuscleGroupResource,
     ResourceResult,
 };
-use crate::enums::{ExerciseForce, ExerciseMechanic, ExerciseType};
-use crate::models::Exercise;
+use crate::enums::{ExerciseForce, ExerciseMechanic, ExerciseMuscleTarget, ExerciseType};
+use crate::models::{Exercise, ExerciseEquipment, ExerciseMuscleMap, Muscle, MuscleGroup};
 use async_trait::async_trait;
-use database::DatabaseManager;
+use database::{DatabaseManager, Model};
 use serde::Serialize;
 
 #[derive(Serialize)]
@@ -96,12 +96,73 @@ impl ModelResource for ExerciseResource {
             exercise.primary_muscles(database).await?,
             database).await?;
 
+        Ok(Self::simple_with(exercise, equipment, target_muscle_group, primary_muscles))
+    }
+
+    /// Loads the equipment, target muscle group and primary muscles of every
+    /// exercise up front, so a page of exercises costs the same few queries
+    /// regardless of its size.
+    async fn list(exercises: Vec<Exercise>, database: &DatabaseManager) -> ResourceResult<Vec<Self>> {
+        let exercise_ids = exercises.iter()
+            .map(|exercise| exercise.id)
+            .collect();
+        let equipment_ids = exercises.iter()
+            .filter_map(|exercise| exercise.equipment_id)
+            .collect();
+
+        let equipment = ExerciseEquipment::preload("id", equipment_ids, |equipment| equipment.id, database).await?;
+        let maps = ExerciseMuscleMap::preload("exercise_id", exercise_ids, |map| map.exercise_id, database).await?;
+
+        let muscle_ids = maps.iter()
+            .filter(|map| map.target == ExerciseMuscleTarget::Primary)
+            .map(|map| map.muscle_id)
+            .collect();
+        let muscles = Muscle::preload("id", muscle_ids, |muscle| muscle.id, database).await?;
+
+        let group_ids = exercises.iter()
+            .filter_map(|exercise| exercise.target_muscle_group_id)
+            .chain(muscles.iter().map(|muscle| muscle.group_id))
+            .collect();
+        let groups = MuscleGroup::preload("id", group_ids, |group| group.id, database).await?;
+
+        let mut results = Vec::with_capacity(exercises.len());
+
+        for exercise in exercises {
+            let exercise_equipment = match exercise.equipment_id.and_then(|id| equipment.first(&id)) {
+                Some(equipment) => Some(ExerciseEquipmentResource::simple(equipment.clone(), database).await?),
+                None => None,
+            };
+            let target_muscle_group = match exercise.target_muscle_group_id.and_then(|id| groups.first(&id)) {
+                Some(muscle_group) => Some(MuscleGroupResource::simple(muscle_group.clone(), database).await?),
+                None => None,
+            };
+            let primary_muscles = maps.get(&exercise.id)
+                .iter()
+                .filter(|map| map.target == ExerciseMuscleTarget::Primary)
+                .filter_map(|map| muscles.first(&map.muscle_id).cloned())
+                .collect();
+            let primary_muscles = MuscleResource::list_with_groups(primary_muscles, &groups)?;
+
+            results.push(Self::simple_with(exercise, exercise_equipment, target_muscle_group, primary_muscles));
+        }
+
+        Ok(results)
+    }
+}
+
+impl ExerciseResource {
+    fn simple_with(
+        exercise: Exercise,
+        equipment: Option<ExerciseEquipmentResource>,
+        target_muscle_group: Option<MuscleGroupResource>,
+        primary_muscles: Vec<MuscleResource>,
+    ) -> Self {
         let measurement = match exercise.measurement {
             Some(measurement) => Some(MeasurementResource::new(measurement)),
             None => None,
         };
 
-        Ok(Self {
+        Self {
             id: exercise.ulid,
             exercise_type: exercise.exercise_type,
             target_muscle_group,
@@ -116,6 +177,6 @@ impl ModelResource for ExerciseResource {
             secondary_muscles: None,
             tertiary_muscles: None,
             instructions: None,
-        })
+        }
     }
 }
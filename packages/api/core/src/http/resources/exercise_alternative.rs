@@ -0,0 +1,35 @@
+use super::{ExerciseResource, ModelResource, ResourceResult};
+use crate::models::ExerciseAlternative;
+use database::DatabaseManager;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct ExerciseAlternativeResource {
+    exercise: ExerciseResource,
+    score: f64,
+}
+
+impl ExerciseAlternativeResource {
+    pub async fn new(alternative: ExerciseAlternative, database: &DatabaseManager) -> ResourceResult<Self> {
+        Ok(Self {
+            exercise: ExerciseResource::simple(alternative.exercise, database).await?,
+            score: alternative.score,
+        })
+    }
+
+    pub async fn list(alternatives: Vec<ExerciseAlternative>, database: &DatabaseManager) -> ResourceResult<Vec<Self>> {
+        let scores = alternatives.iter()
+            .map(|alternative| alternative.score)
+            .collect::<Vec<f64>>();
+        let exercises = ExerciseResource::list(
+            alternatives.into_iter().map(|alternative| alternative.exercise).collect(),
+            database).await?;
+
+        let results = exercises.into_iter()
+            .zip(scores)
+            .map(|(exercise, score)| Self { exercise, score })
+            .collect();
+
+        Ok(results)
+    }
+}
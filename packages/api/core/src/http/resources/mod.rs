@@ -1,4 +1,7 @@
+mod coaching_relationship;
+mod equipment_profile;
 mod exercise;
+mod exercise_alternative;
 mod exercise_equipment;
 mod exercise_instruction;
 mod link;
@@ -6,10 +9,25 @@ mod measurement;
 mod muscle;
 mod muscle_group;
 mod name;
+mod one_rep_max_trend;
+mod personal_record;
 mod profile;
+mod program;
+mod program_day;
+mod program_enrollment;
+mod program_session;
+mod routine;
+mod routine_exercise;
 mod user;
+mod volume_report;
+mod workout;
+mod workout_exercise;
+mod workout_set;
 
+pub use coaching_relationship::CoachingRelationshipResource;
+pub use equipment_profile::EquipmentProfileResource;
 pub use exercise::ExerciseResource;
+pub use exercise_alternative::ExerciseAlternativeResource;
 pub use exercise_equipment::ExerciseEquipmentResource;
 pub use exercise_instruction::ExerciseInstructionResource;
 pub use link::LinkResource;
@@ -17,8 +35,20 @@ pub use measurement::MeasurementResource;
 pub use muscle::MuscleResource;
 pub use muscle_group::MuscleGroupResource;
 pub use name::NameResource;
+pub use one_rep_max_trend::OneRepMaxTrendResource;
+pub use personal_record::PersonalRecordResource;
 pub use profile::ProfileResource;
+pub use program::ProgramResource;
+pub use program_day::ProgramDayResource;
+pub use program_enrollment::ProgramEnrollmentResource;
+pub use program_session::ProgramSessionResource;
+pub use routine::RoutineResource;
+pub use routine_exercise::RoutineExerciseResource;
 pub use user::UserResource;
+pub use volume_report::VolumeReportResource;
+pub use workout::WorkoutResource;
+pub use workout_exercise::WorkoutExerciseResource;
+pub use workout_set::WorkoutSetResource;
 
 use async_trait::async_trait;
 use database::DatabaseManager;
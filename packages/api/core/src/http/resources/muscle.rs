@@ -1,7 +1,7 @@
 use super::{LinkResource, ModelResource, ResourceResult};
-use crate::models::{Link, Muscle};
+use crate::models::{Link, Muscle, MuscleGroup};
 use async_trait::async_trait;
-use database::{DatabaseManager, HasRouteKey, Model};
+use database::{DatabaseManager, HasRouteKey, Model, Preloaded};
 use serde::Serialize;
 
 #[derive(Serialize)]
@@ -47,15 +47,57 @@ impl ModelResource for MuscleResource {
     async fn simple(muscle: Muscle, database: &DatabaseManager) -> ResourceResult<Self> {
         let group = muscle.muscle_group(database).await?;
 
-        Ok(Self {
+        Ok(Self::with_group(muscle, group.name))
+    }
+
+    async fn list(muscles: Vec<Muscle>, database: &DatabaseManager) -> ResourceResult<Vec<Self>> {
+        let groups = Self::preload_groups(&muscles, database).await?;
+
+        Self::list_with_groups(muscles, &groups)
+    }
+}
+
+impl MuscleResource {
+    /// Loads the muscle groups of every given muscle in a single query.
+    pub async fn preload_groups(
+        muscles: &[Muscle],
+        database: &DatabaseManager,
+    ) -> ResourceResult<Preloaded<i16, MuscleGroup>> {
+        let ids = muscles.iter()
+            .map(|muscle| muscle.group_id)
+            .collect();
+
+        Ok(MuscleGroup::preload("id", ids, |group| group.id, database).await?)
+    }
+
+    /// Builds simple resources for muscles whose groups have already been loaded.
+    pub fn list_with_groups(
+        muscles: Vec<Muscle>,
+        groups: &Preloaded<i16, MuscleGroup>,
+    ) -> ResourceResult<Vec<Self>> {
+        muscles.into_iter()
+            .map(|muscle| {
+                let group = groups.first(&muscle.group_id)
+                    .ok_or_else(|| crate::models::Error::ModelNotFound(format!(
+                        "No {} found where id = {}.",
+                        MuscleGroup::MODEL_NAME, muscle.group_id,
+                    )))?;
+
+                Ok(Self::with_group(muscle, group.name.clone()))
+            })
+            .collect()
+    }
+
+    fn with_group(muscle: Muscle, muscle_group: String) -> Self {
+        Self {
             id: muscle.route_key(),
-            muscle_group: group.name,
+            muscle_group,
             name: muscle.name,
             simple_name: muscle.simple_name,
             description: muscle.description,
             image_source: muscle.image_source,
             parent: None,
             links: None,
-        })
+        }
     }
 }
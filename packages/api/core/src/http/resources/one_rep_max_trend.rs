@@ -0,0 +1,81 @@
+use super::{ExerciseResource, ModelResource, ResourceResult};
+use crate::enums::{OneRepMaxFormula, StrengthLevel};
+use crate::models::{Exercise, Profile, User};
+use crate::utils::strength;
+use chrono::{NaiveDate, Utc};
+use database::{DatabaseManager, Model, SqlxAction};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct OneRepMaxPointResource {
+    date: NaiveDate,
+    one_rep_max: f64,
+}
+
+#[derive(Serialize)]
+pub struct StrengthStandardResource {
+    level: StrengthLevel,
+    bodyweight_ratio: f64,
+    bodyweight_known: bool,
+}
+
+/// A user's estimated one rep max of an exercise over time, with their
+/// best estimate classified against strength standards when their
+/// profile is available.
+#[derive(Serialize)]
+pub struct OneRepMaxTrendResource {
+    exercise: ExerciseResource,
+    formula: OneRepMaxFormula,
+    unit: &'static str,
+    best: Option<f64>,
+    standard: Option<StrengthStandardResource>,
+    points: Vec<OneRepMaxPointResource>,
+}
+
+impl OneRepMaxTrendResource {
+    pub async fn new(
+        user: &User,
+        exercise: Exercise,
+        formula: OneRepMaxFormula,
+        trend: Vec<(NaiveDate, f64)>,
+        database: &DatabaseManager,
+    ) -> ResourceResult<Self> {
+        let best = trend.iter()
+            .map(|(_, one_rep_max)| *one_rep_max)
+            .reduce(f64::max);
+        let profile = Profile::query()
+            .select(&["*"])
+            .and_where("user_id", "=", user.id)
+            .optional::<_, Profile>(database.connection())
+            .await?;
+
+        let standard = match (best, profile) {
+            (Some(best), Some(profile)) => {
+                let ratio = strength::bodyweight_ratio(best, &profile.gender, profile.bodyweight);
+
+                Some(StrengthStandardResource {
+                    level: strength::classify(
+                        ratio,
+                        exercise.mechanic.as_ref(),
+                        &profile.gender,
+                        profile.age_on(Utc::now().date_naive()),
+                    ),
+                    bodyweight_ratio: ratio,
+                    bodyweight_known: profile.bodyweight.is_some(),
+                })
+            },
+            _ => None,
+        };
+
+        Ok(Self {
+            exercise: ExerciseResource::simple(exercise, database).await?,
+            formula,
+            unit: "kilograms",
+            best,
+            standard,
+            points: trend.into_iter()
+                .map(|(date, one_rep_max)| OneRepMaxPointResource { date, one_rep_max })
+                .collect(),
+        })
+    }
+}
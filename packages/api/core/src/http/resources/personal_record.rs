@@ -0,0 +1,55 @@
+use super::{ExerciseResource, ModelResource, ResourceResult};
+use crate::prelude::*;
+use crate::enums::PersonalRecordType;
+use crate::models::PersonalRecord;
+use async_trait::async_trait;
+use database::{DatabaseManager, HasRouteKey};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct PersonalRecordResource {
+    id: String,
+    #[serde(rename = "type")]
+    record_type: PersonalRecordType,
+    value: f64,
+    unit: &'static str,
+    weight: Option<f64>,
+    achieved_at: ISO8601DateTimeUTC,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exercise: Option<ExerciseResource>,
+}
+
+#[async_trait]
+impl ModelResource for PersonalRecordResource {
+    type Model = PersonalRecord;
+
+    async fn default(record: PersonalRecord, database: &DatabaseManager) -> ResourceResult<Self> {
+        let exercise = ExerciseResource::simple(
+            record.exercise(database).await?,
+            database,
+        ).await?;
+
+        Ok(Self {
+            id: record.route_key(),
+            unit: record.record_type.unit(),
+            record_type: record.record_type,
+            value: record.value,
+            weight: record.weight,
+            achieved_at: record.achieved_at,
+            exercise: Some(exercise),
+        })
+    }
+
+    async fn simple(record: PersonalRecord, database: &DatabaseManager) -> ResourceResult<Self> {
+        Ok(Self {
+            id: record.route_key(),
+            unit: record.record_type.unit(),
+            record_type: record.record_type,
+            value: record.value,
+            weight: record.weight,
+            achieved_at: record.achieved_at,
+            exercise: None,
+        })
+    }
+}
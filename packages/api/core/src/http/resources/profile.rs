@@ -10,6 +10,7 @@ pub struct ProfileResource {
     id: String,
     gender: Gender,
     birthday: chrono::NaiveDate,
+    bodyweight: Option<f64>,
 }
 
 #[async_trait]
@@ -21,6 +22,7 @@ impl ModelResource for ProfileResource {
             id: profile.route_key(),
             gender: profile.gender,
             birthday: profile.birthday,
+            bodyweight: profile.bodyweight,
         })
     }
 
@@ -29,6 +31,7 @@ impl ModelResource for ProfileResource {
             id: profile.route_key(),
             gender: profile.gender,
             birthday: profile.birthday,
+            bodyweight: profile.bodyweight,
         })
     }
 }
@@ -0,0 +1,63 @@
+use super::{ModelResource, ProgramDayResource, ResourceResult};
+use crate::prelude::*;
+use crate::models::Program;
+use async_trait::async_trait;
+use database::{DatabaseManager, HasRouteKey};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct ProgramResource {
+    id: String,
+    name: String,
+    description: Option<String>,
+    weeks: i16,
+    weekly_increment: f64,
+    deload_every: Option<i16>,
+    deload_factor: f64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    days: Option<Vec<ProgramDayResource>>,
+
+    created_at: ISO8601DateTimeUTC,
+    updated_at: ISO8601DateTimeUTC,
+}
+
+#[async_trait]
+impl ModelResource for ProgramResource {
+    type Model = Program;
+
+    async fn default(program: Program, database: &DatabaseManager) -> ResourceResult<Self> {
+        let days = ProgramDayResource::list(
+            program.days(database).await?,
+            database,
+        ).await?;
+
+        Ok(Self {
+            id: program.route_key(),
+            name: program.name,
+            description: program.description,
+            weeks: program.weeks,
+            weekly_increment: program.weekly_increment,
+            deload_every: program.deload_every,
+            deload_factor: program.deload_factor,
+            days: Some(days),
+            created_at: program.created_at,
+            updated_at: program.updated_at,
+        })
+    }
+
+    async fn simple(program: Program, database: &DatabaseManager) -> ResourceResult<Self> {
+        Ok(Self {
+            id: program.route_key(),
+            name: program.name,
+            description: program.description,
+            weeks: program.weeks,
+            weekly_increment: program.weekly_increment,
+            deload_every: program.deload_every,
+            deload_factor: program.deload_factor,
+            days: None,
+            created_at: program.created_at,
+            updated_at: program.updated_at,
+        })
+    }
+}
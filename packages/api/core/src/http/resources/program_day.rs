@@ -0,0 +1,36 @@
+use super::{ModelResource, ResourceResult, RoutineResource};
+use crate::models::ProgramDay;
+use async_trait::async_trait;
+use database::{DatabaseManager, HasRouteKey};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct ProgramDayResource {
+    id: String,
+    week_number: i16,
+    day_number: i16,
+    routine: RoutineResource,
+}
+
+#[async_trait]
+impl ModelResource for ProgramDayResource {
+    type Model = ProgramDay;
+
+    async fn default(day: ProgramDay, database: &DatabaseManager) -> ResourceResult<Self> {
+        Self::simple(day, database).await
+    }
+
+    async fn simple(day: ProgramDay, database: &DatabaseManager) -> ResourceResult<Self> {
+        let routine = RoutineResource::simple(
+            day.routine(database).await?,
+            database,
+        ).await?;
+
+        Ok(Self {
+            id: day.route_key(),
+            week_number: day.week_number,
+            day_number: day.day_number,
+            routine,
+        })
+    }
+}
@@ -0,0 +1,38 @@
+use super::{ModelResource, ProgramResource, ResourceResult};
+use crate::prelude::*;
+use crate::models::ProgramEnrollment;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use database::{DatabaseManager, HasRouteKey};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct ProgramEnrollmentResource {
+    id: String,
+    program: ProgramResource,
+    started_on: NaiveDate,
+    ended_at: Option<ISO8601DateTimeUTC>,
+}
+
+#[async_trait]
+impl ModelResource for ProgramEnrollmentResource {
+    type Model = ProgramEnrollment;
+
+    async fn default(enrollment: ProgramEnrollment, database: &DatabaseManager) -> ResourceResult<Self> {
+        Self::simple(enrollment, database).await
+    }
+
+    async fn simple(enrollment: ProgramEnrollment, database: &DatabaseManager) -> ResourceResult<Self> {
+        let program = ProgramResource::simple(
+            enrollment.program(database).await?,
+            database,
+        ).await?;
+
+        Ok(Self {
+            id: enrollment.route_key(),
+            program,
+            started_on: enrollment.started_on,
+            ended_at: enrollment.ended_at,
+        })
+    }
+}
@@ -0,0 +1,59 @@
+use super::{ExerciseResource, ModelResource, ResourceResult, RoutineResource};
+use crate::models::{Program, ProgramDay};
+use database::DatabaseManager;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct ProgramSessionExerciseResource {
+    sequence_number: i16,
+    exercise: ExerciseResource,
+    target_sets: i16,
+    target_repetitions: Option<i16>,
+    target_weight: Option<f64>,
+    rest_seconds: Option<i32>,
+}
+
+/// The workout a program prescribes for a single day, with the
+/// routine's target weights adjusted for that week of the program.
+#[derive(Serialize)]
+pub struct ProgramSessionResource {
+    week_number: i16,
+    day_number: i16,
+    deload: bool,
+    routine: RoutineResource,
+    exercises: Vec<ProgramSessionExerciseResource>,
+}
+
+impl ProgramSessionResource {
+    pub async fn new(program: &Program, day: ProgramDay, database: &DatabaseManager) -> ResourceResult<Self> {
+        let routine = day.routine(database).await?;
+        let routine_exercises = routine.exercises(database).await?;
+        let mut exercises = Vec::with_capacity(routine_exercises.len());
+
+        for routine_exercise in routine_exercises {
+            let exercise = routine_exercise.exercise(database).await?;
+            let target_weight = program.target_weight(
+                routine_exercise.target_weight,
+                exercise.mechanic.as_ref(),
+                day.week_number,
+            );
+
+            exercises.push(ProgramSessionExerciseResource {
+                sequence_number: routine_exercise.sequence_number,
+                exercise: ExerciseResource::simple(exercise, database).await?,
+                target_sets: routine_exercise.target_sets,
+                target_repetitions: routine_exercise.target_repetitions,
+                target_weight,
+                rest_seconds: routine_exercise.rest_seconds,
+            });
+        }
+
+        Ok(Self {
+            week_number: day.week_number,
+            day_number: day.day_number,
+            deload: program.is_deload_week(day.week_number),
+            routine: RoutineResource::simple(routine, database).await?,
+            exercises,
+        })
+    }
+}
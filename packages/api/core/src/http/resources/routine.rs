@@ -0,0 +1,51 @@
+use super::{ModelResource, ResourceResult, RoutineExerciseResource};
+use crate::prelude::*;
+use crate::models::Routine;
+use async_trait::async_trait;
+use database::{DatabaseManager, HasRouteKey};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct RoutineResource {
+    id: String,
+    name: String,
+    description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exercises: Option<Vec<RoutineExerciseResource>>,
+
+    created_at: ISO8601DateTimeUTC,
+    updated_at: ISO8601DateTimeUTC,
+}
+
+#[async_trait]
+impl ModelResource for RoutineResource {
+    type Model = Routine;
+
+    async fn default(routine: Routine, database: &DatabaseManager) -> ResourceResult<Self> {
+        let exercises = RoutineExerciseResource::list(
+            routine.exercises(database).await?,
+            database,
+        ).await?;
+
+        Ok(Self {
+            id: routine.route_key(),
+            name: routine.name,
+            description: routine.description,
+            exercises: Some(exercises),
+            created_at: routine.created_at,
+            updated_at: routine.updated_at,
+        })
+    }
+
+    async fn simple(routine: Routine, database: &DatabaseManager) -> ResourceResult<Self> {
+        Ok(Self {
+            id: routine.route_key(),
+            name: routine.name,
+            description: routine.description,
+            exercises: None,
+            created_at: routine.created_at,
+            updated_at: routine.updated_at,
+        })
+    }
+}
@@ -0,0 +1,42 @@
+use super::{ExerciseResource, ModelResource, ResourceResult};
+use crate::models::RoutineExercise;
+use async_trait::async_trait;
+use database::{DatabaseManager, HasRouteKey};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct RoutineExerciseResource {
+    id: String,
+    sequence_number: i16,
+    exercise: ExerciseResource,
+    target_sets: i16,
+    target_repetitions: Option<i16>,
+    target_weight: Option<f64>,
+    rest_seconds: Option<i32>,
+}
+
+#[async_trait]
+impl ModelResource for RoutineExerciseResource {
+    type Model = RoutineExercise;
+
+    async fn default(routine_exercise: RoutineExercise, database: &DatabaseManager) -> ResourceResult<Self> {
+        Self::simple(routine_exercise, database).await
+    }
+
+    async fn simple(routine_exercise: RoutineExercise, database: &DatabaseManager) -> ResourceResult<Self> {
+        let exercise = ExerciseResource::simple(
+            routine_exercise.exercise(database).await?,
+            database,
+        ).await?;
+
+        Ok(Self {
+            id: routine_exercise.route_key(),
+            sequence_number: routine_exercise.sequence_number,
+            exercise,
+            target_sets: routine_exercise.target_sets,
+            target_repetitions: routine_exercise.target_repetitions,
+            target_weight: routine_exercise.target_weight,
+            rest_seconds: routine_exercise.rest_seconds,
+        })
+    }
+}
@@ -26,7 +26,7 @@ impl ModelResource for UserResource {
 
     async fn default(user: User, database: &DatabaseManager) -> ResourceResult<Self> {
         match user.role {
-            Role::User => {
+            Role::User | Role::Coach => {
                 let profile = ProfileResource::default(
                     user.profile(database).await?,
                     database,
@@ -42,7 +42,7 @@ impl ModelResource for UserResource {
                     updated_at: user.updated_at,
                 })
             }
-            Role::Admin => {
+            Role::Admin | Role::Moderator => {
                 Self::simple(user, database).await
             }
         }
@@ -0,0 +1,101 @@
+use crate::enums::{ExerciseMuscleTarget, VolumeGranularity};
+use crate::models::{MuscleGroupVolume, MuscleVolume};
+use chrono::NaiveDate;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct VolumeWeightsResource {
+    primary: f64,
+    secondary: f64,
+    tertiary: f64,
+}
+
+#[derive(Serialize)]
+pub struct VolumeEntryResource<T> {
+    id: T,
+    name: String,
+    sets: f64,
+    repetitions: f64,
+    tonnage: f64,
+}
+
+#[derive(Serialize)]
+pub struct VolumePeriodResource {
+    period: NaiveDate,
+    muscles: Vec<VolumeEntryResource<String>>,
+    muscle_groups: Vec<VolumeEntryResource<i16>>,
+}
+
+/// A user's training volume between two dates, grouped into periods and
+/// broken down per muscle and muscle group. Tonnage is in kilograms.
+#[derive(Serialize)]
+pub struct VolumeReportResource {
+    from: NaiveDate,
+    to: NaiveDate,
+    granularity: VolumeGranularity,
+    weights: VolumeWeightsResource,
+    periods: Vec<VolumePeriodResource>,
+}
+
+impl VolumeReportResource {
+    pub fn new(
+        from: NaiveDate,
+        to: NaiveDate,
+        granularity: VolumeGranularity,
+        muscles: Vec<MuscleVolume>,
+        muscle_groups: Vec<MuscleGroupVolume>,
+    ) -> Self {
+        let mut periods: Vec<VolumePeriodResource> = Vec::new();
+
+        for volume in muscles {
+            Self::period(&mut periods, volume.period).muscles.push(VolumeEntryResource {
+                id: volume.muscle_id,
+                name: volume.name,
+                sets: volume.sets,
+                repetitions: volume.repetitions,
+                tonnage: volume.tonnage,
+            });
+        }
+
+        for volume in muscle_groups {
+            Self::period(&mut periods, volume.period).muscle_groups.push(VolumeEntryResource {
+                id: volume.muscle_group_id,
+                name: volume.name,
+                sets: volume.sets,
+                repetitions: volume.repetitions,
+                tonnage: volume.tonnage,
+            });
+        }
+
+        periods.sort_by_key(|period| period.period);
+
+        Self {
+            from,
+            to,
+            granularity,
+            weights: VolumeWeightsResource {
+                primary: ExerciseMuscleTarget::Primary.volume_factor(),
+                secondary: ExerciseMuscleTarget::Secondary.volume_factor(),
+                tertiary: ExerciseMuscleTarget::Tertiary.volume_factor(),
+            },
+            periods,
+        }
+    }
+
+    fn period(periods: &mut Vec<VolumePeriodResource>, period: NaiveDate) -> &mut VolumePeriodResource {
+        let index = match periods.iter().position(|existing| existing.period == period) {
+            Some(index) => index,
+            None => {
+                periods.push(VolumePeriodResource {
+                    period,
+                    muscles: Vec::new(),
+                    muscle_groups: Vec::new(),
+                });
+
+                periods.len() - 1
+            },
+        };
+
+        &mut periods[index]
+    }
+}
@@ -0,0 +1,50 @@
+use super::{ModelResource, ResourceResult, WorkoutExerciseResource};
+use crate::prelude::*;
+use crate::models::Workout;
+use async_trait::async_trait;
+use database::{DatabaseManager, HasRouteKey};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct WorkoutResource {
+    id: String,
+    name: Option<String>,
+    notes: Option<String>,
+    started_at: ISO8601DateTimeUTC,
+    finished_at: Option<ISO8601DateTimeUTC>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exercises: Option<Vec<WorkoutExerciseResource>>,
+}
+
+#[async_trait]
+impl ModelResource for WorkoutResource {
+    type Model = Workout;
+
+    async fn default(workout: Workout, database: &DatabaseManager) -> ResourceResult<Self> {
+        let exercises = WorkoutExerciseResource::list(
+            workout.exercises(database).await?,
+            database,
+        ).await?;
+
+        Ok(Self {
+            id: workout.route_key(),
+            name: workout.name,
+            notes: workout.notes,
+            started_at: workout.started_at,
+            finished_at: workout.finished_at,
+            exercises: Some(exercises),
+        })
+    }
+
+    async fn simple(workout: Workout, database: &DatabaseManager) -> ResourceResult<Self> {
+        Ok(Self {
+            id: workout.route_key(),
+            name: workout.name,
+            notes: workout.notes,
+            started_at: workout.started_at,
+            finished_at: workout.finished_at,
+            exercises: None,
+        })
+    }
+}
@@ -0,0 +1,50 @@
+use super::{ExerciseResource, ModelResource, ResourceResult, WorkoutSetResource};
+use crate::models::WorkoutExercise;
+use async_trait::async_trait;
+use database::{DatabaseManager, HasRouteKey};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct WorkoutExerciseResource {
+    id: String,
+    sequence_number: i16,
+    exercise: ExerciseResource,
+    notes: Option<String>,
+    target_sets: Option<i16>,
+    target_repetitions: Option<i16>,
+    target_weight: Option<f64>,
+    rest_seconds: Option<i32>,
+    sets: Vec<WorkoutSetResource>,
+}
+
+#[async_trait]
+impl ModelResource for WorkoutExerciseResource {
+    type Model = WorkoutExercise;
+
+    async fn default(workout_exercise: WorkoutExercise, database: &DatabaseManager) -> ResourceResult<Self> {
+        Self::simple(workout_exercise, database).await
+    }
+
+    async fn simple(workout_exercise: WorkoutExercise, database: &DatabaseManager) -> ResourceResult<Self> {
+        let exercise = ExerciseResource::simple(
+            workout_exercise.exercise(database).await?,
+            database,
+        ).await?;
+        let sets = WorkoutSetResource::list(
+            workout_exercise.sets(database).await?,
+            database,
+        ).await?;
+
+        Ok(Self {
+            id: workout_exercise.route_key(),
+            sequence_number: workout_exercise.sequence_number,
+            exercise,
+            notes: workout_exercise.notes,
+            target_sets: workout_exercise.target_sets,
+            target_repetitions: workout_exercise.target_repetitions,
+            target_weight: workout_exercise.target_weight,
+            rest_seconds: workout_exercise.rest_seconds,
+            sets,
+        })
+    }
+}
@@ -0,0 +1,53 @@
+use super::{ModelResource, PersonalRecordResource, ResourceResult};
+use crate::prelude::*;
+use crate::models::WorkoutSet;
+use async_trait::async_trait;
+use database::{DatabaseManager, HasRouteKey};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct WorkoutSetResource {
+    id: String,
+    sequence_number: i16,
+    weight: Option<f64>,
+    repetitions: Option<i16>,
+    duration: Option<i32>,
+    created_at: ISO8601DateTimeUTC,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    personal_records: Option<Vec<PersonalRecordResource>>,
+}
+
+#[async_trait]
+impl ModelResource for WorkoutSetResource {
+    type Model = WorkoutSet;
+
+    async fn default(set: WorkoutSet, database: &DatabaseManager) -> ResourceResult<Self> {
+        let personal_records = PersonalRecordResource::list(
+            set.personal_records(database).await?,
+            database,
+        ).await?;
+
+        Ok(Self {
+            id: set.route_key(),
+            sequence_number: set.sequence_number,
+            weight: set.weight,
+            repetitions: set.repetitions,
+            duration: set.duration,
+            created_at: set.created_at,
+            personal_records: Some(personal_records),
+        })
+    }
+
+    async fn simple(set: WorkoutSet, database: &DatabaseManager) -> ResourceResult<Self> {
+        Ok(Self {
+            id: set.route_key(),
+            sequence_number: set.sequence_number,
+            weight: set.weight,
+            repetitions: set.repetitions,
+            duration: set.duration,
+            created_at: set.created_at,
+            personal_records: None,
+        })
+    }
+}
@@ -7,6 +7,7 @@ use serde_json::{json, Value};
 pub struct JsonResponse {
     body: Value,
     code: StatusCode,
+    pagination: Option<PageMeta>,
     success: bool,
 }
 
@@ -16,6 +17,19 @@ pub struct ApiSuccessResponse<T: Serialize> {
     pub data: Option<T>,
 }
 
+/// Describes the page of results held in a list response. Lists paged with a
+/// cursor have no `total` or `page`.
+#[derive(Debug, Serialize)]
+pub struct PageMeta {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i64>,
+    pub per_page: i64,
+    pub next: Option<String>,
+    pub prev: Option<String>,
+}
+
 #[derive(Serialize)]
 pub struct ApiErrorContext {
     pub name: String,
@@ -41,6 +55,7 @@ impl JsonResponse {
         Self {
             body,
             code,
+            pagination: None,
             success,
         }
     }
@@ -94,6 +109,11 @@ impl JsonResponse {
         self
     }
 
+    pub fn with_pagination(mut self, pagination: PageMeta) -> Self {
+        self.pagination = Some(pagination);
+        self
+    }
+
     // endregion
 }
 
@@ -103,12 +123,17 @@ impl IntoResponse for JsonResponse {
             return self.code.into_response()
         }
 
-        let body = Json(match self.success {
-            true => json!({
+        let body = Json(match (self.success, self.pagination) {
+            (true, Some(pagination)) => json!({
+                "success": true,
+                "data": self.body,
+                "pagination": pagination,
+            }),
+            (true, None) => json!({
                 "success": true,
                 "data": self.body,
             }),
-            false => json!({
+            (false, _) => json!({
                 "success": false,
                 "error": self.body,
             }),
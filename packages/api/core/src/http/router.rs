@@ -1,19 +1,22 @@
 use super::controllers::{
+    AnalyticsController,
     AuthController,
+    CoachingController,
     Controller,
+    EquipmentProfileController,
     ExerciseController,
     ExerciseEquipmentController,
     HealthController,
     LinkController,
     MuscleController,
     MuscleGroupController,
+    PersonalRecordController,
+    ProgramController,
+    RoutineController,
+    UserController,
+    WorkoutController,
 };
-use crate::{
-    actions,
-    data::CreateUserData,
-    enums::Role,
-    sys::config,
-};
+use crate::sys::config;
 use axum::{
     http::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE},
     http::{HeaderValue, Method},
@@ -29,51 +32,6 @@ use tower_http::cors::{Any, CorsLayer};
 
 type Result<TValue> = ::core::result::Result<TValue, Box<dyn std::error::Error + Send + Sync + 'static>>;
 
-pub async fn create_admin_user(database: &DatabaseManager) -> () {
-    let auth = config().auth();
-    let username = auth.admin_username();
-    let password = auth.admin_password();
-
-    let admin_data = CreateUserData {
-        email: username.as_str(),
-        first_name: "Admin",
-        last_name: "User",
-        role: Some(Role::Admin),
-        password: password.as_str(),
-        password_confirm: password.as_str(),
-    };
-
-    match actions::create_user(admin_data, database).await {
-        Ok(_) => println!("Created admin user"),
-        Err(err) => {
-            println!("Admin user not created: {}", err);
-        }
-    }
-}
-
-pub async fn init() -> Result<Router> {
-    let server = config().server();
-    let database = config().database();
-
-    let database = DatabaseManager::new()
-        .url(database.url())
-        .max_connections(database.max_connections())
-        .min_connections(database.min_connections())
-        .build()
-        .await
-        .unwrap_or_else(|error| {
-            panic!("Could not initialize database manager: {}", error);
-        });
-
-    create_admin_user(&database).await;
-
-    if server.should_sync_exercises() {
-        actions::services::init(&database).await;
-    }
-
-    Ok(router(database).await)
-}
-
 pub async fn router(database: DatabaseManager) -> Router {
     let server = config().server();
 
@@ -85,6 +43,21 @@ pub async fn router(database: DatabaseManager) -> Router {
     // Note: `.layer()` calls are executed from bottom-to-top
     Router::new()
         .merge(HealthController::router(database.clone()))
+        .nest(
+            "/api/analytics",
+            AnalyticsController::router(database.clone())
+                .route_layer(middleware::from_fn(crate::http::middleware::require_auth)),
+        )
+        .nest(
+            "/api/coaching",
+            CoachingController::router(database.clone())
+                .route_layer(middleware::from_fn(crate::http::middleware::require_auth)),
+        )
+        .nest(
+            "/api/equipment-profiles",
+            EquipmentProfileController::router(database.clone())
+                .route_layer(middleware::from_fn(crate::http::middleware::require_auth)),
+        )
         .nest(
             "/api/exercises",
             ExerciseController::router(database.clone())
@@ -110,6 +83,31 @@ pub async fn router(database: DatabaseManager) -> Router {
             MuscleController::router(database.clone())
                 .route_layer(middleware::from_fn(crate::http::middleware::require_auth)),
         )
+        .nest(
+            "/api/personal-records",
+            PersonalRecordController::router(database.clone())
+                .route_layer(middleware::from_fn(crate::http::middleware::require_auth)),
+        )
+        .nest(
+            "/api/programs",
+            ProgramController::router(database.clone())
+                .route_layer(middleware::from_fn(crate::http::middleware::require_auth)),
+        )
+        .nest(
+            "/api/routines",
+            RoutineController::router(database.clone())
+                .route_layer(middleware::from_fn(crate::http::middleware::require_auth)),
+        )
+        .nest(
+            "/api/users",
+            UserController::router(database.clone())
+                .route_layer(middleware::from_fn(crate::http::middleware::require_auth)),
+        )
+        .nest(
+            "/api/workouts",
+            WorkoutController::router(database.clone())
+                .route_layer(middleware::from_fn(crate::http::middleware::require_auth)),
+        )
         .nest("/api/auth", AuthController::router(database.clone()))
         // .layer(middleware::map_response(
         //     crate::http::middleware::response_mapper,
@@ -8,6 +8,7 @@ extern crate rust_i18n;
 pub(crate) mod mocks;
 
 mod actions;
+mod cli;
 mod data;
 mod enums;
 mod error;
@@ -22,10 +23,6 @@ mod utils;
 #[cfg(test)]
 mod tests;
 
-use database::DatabaseManager;
-use sys::config;
-use log::Level;
-use std::net::SocketAddr;
 use tracing_subscriber::filter::EnvFilter;
 
 i18n!("lang", fallback = "en");
@@ -38,17 +35,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>
         .with_env_filter(EnvFilter::from_default_env())
         .init();
 
-    let router = crate::http::init().await?;
-    let server_config = config().server();
-    let server_address = SocketAddr::from(([127, 0, 0, 1], server_config.port()));
-    let server = axum::Server::bind(&server_address)
-        .serve(router.into_make_service());
-
-    tracing::debug!("Server listening at http://{}", &server_address);
-
-    if let Err(e) = server.await {
-        tracing::error!("Server error: {}", e);
-        std::process::abort();
+    let command = match cli::Command::parse(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, cli::USAGE);
+            std::process::exit(2);
+        },
+    };
+
+    if let Err(error) = command.run().await {
+        eprintln!("Error: {}", error);
+        std::process::exit(1);
     }
 
     Ok(())
@@ -227,6 +227,52 @@ impl ExerciseEquipmentBuilder<NoExerciseEquipmentName> {
 
 // endregion
 
+// region EquipmentProfile
+
+use crate::models::equipment_profile::{
+    EquipmentProfileBuilder,
+    Name as EquipmentProfileName,
+    NoName as NoEquipmentProfileName,
+    NoUserId as NoEquipmentProfileUserId,
+};
+
+impl EquipmentProfile {
+    pub fn fake() -> EquipmentProfileBuilder<NoEquipmentProfileUserId, EquipmentProfileName> {
+        EquipmentProfileBuilder::fake()
+    }
+
+    pub async fn mocked(database: &DatabaseManager) -> Result<EquipmentProfile> {
+        let profile = EquipmentProfileBuilder::fake()
+            .create(database)
+            .await?;
+
+        Ok(profile)
+    }
+}
+
+impl EquipmentProfileBuilder<NoEquipmentProfileUserId, NoEquipmentProfileName> {
+    pub fn fake() -> EquipmentProfileBuilder<NoEquipmentProfileUserId, EquipmentProfileName> {
+        let tokens: Vec<String> = Words(1..5).fake();
+        let name = tokens.join(" ");
+
+        EquipmentProfile::new().name(name)
+    }
+}
+
+impl EquipmentProfileBuilder<NoEquipmentProfileUserId, EquipmentProfileName> {
+    pub async fn create(self, database: &DatabaseManager) -> Result<EquipmentProfile> {
+        let user = User::mocked(database).await?;
+
+        let profile = self.user(&user)
+            .create(database)
+            .await?;
+
+        Ok(profile)
+    }
+}
+
+// endregion
+
 // region Exercise
 
 use crate::models::exercise::{
@@ -453,3 +499,567 @@ impl LinkBuilder<NoLinkModelData, LinkTypeEnum, LinkFormatEnum, LinkLabel, LinkS
 }
 
 // endregion
+
+// region Workout
+
+use crate::models::workout::{
+    WorkoutBuilder,
+    NoUserId as NoWorkoutUserId,
+};
+
+impl Workout {
+    pub fn fake() -> WorkoutBuilder<NoWorkoutUserId> {
+        WorkoutBuilder::fake()
+    }
+
+    pub async fn mocked(database: &DatabaseManager) -> Result<Workout> {
+        let workout = WorkoutBuilder::fake()
+            .create(database)
+            .await?;
+
+        Ok(workout)
+    }
+}
+
+impl WorkoutBuilder<NoWorkoutUserId> {
+    pub fn fake() -> WorkoutBuilder<NoWorkoutUserId> {
+        let tokens: Vec<String> = Words(1..3).fake();
+
+        Workout::new()
+            .name(Some(tokens.join(" ")))
+    }
+
+    pub async fn create(self, database: &DatabaseManager) -> Result<Workout> {
+        let user = User::mocked(database).await?;
+
+        let workout = self.user(&user)
+            .create(database)
+            .await?;
+
+        Ok(workout)
+    }
+}
+
+// endregion
+
+// region WorkoutExercise
+
+use crate::models::workout_exercise::{
+    WorkoutExerciseBuilder,
+    ExerciseId as WorkoutExerciseExerciseId,
+    SequenceNumber as WorkoutExerciseSequenceNumber,
+    WorkoutId as WorkoutExerciseWorkoutId,
+    NoExerciseId as NoWorkoutExerciseExerciseId,
+    NoSequenceNumber as NoWorkoutExerciseSequenceNumber,
+    NoWorkoutId as NoWorkoutExerciseWorkoutId,
+};
+
+impl WorkoutExercise {
+    pub fn fake() -> WorkoutExerciseBuilder<NoWorkoutExerciseWorkoutId, NoWorkoutExerciseExerciseId, WorkoutExerciseSequenceNumber> {
+        WorkoutExerciseBuilder::fake()
+    }
+
+    pub async fn mocked(database: &DatabaseManager) -> Result<WorkoutExercise> {
+        let workout_exercise = WorkoutExerciseBuilder::fake()
+            .create(database)
+            .await?;
+
+        Ok(workout_exercise)
+    }
+}
+
+impl WorkoutExerciseBuilder<NoWorkoutExerciseWorkoutId, NoWorkoutExerciseExerciseId, NoWorkoutExerciseSequenceNumber> {
+    pub fn fake() -> WorkoutExerciseBuilder<NoWorkoutExerciseWorkoutId, NoWorkoutExerciseExerciseId, WorkoutExerciseSequenceNumber> {
+        WorkoutExerciseBuilder::new()
+            .sequence_number(1)
+    }
+}
+
+impl WorkoutExerciseBuilder<NoWorkoutExerciseWorkoutId, NoWorkoutExerciseExerciseId, WorkoutExerciseSequenceNumber> {
+    pub async fn create(self, database: &DatabaseManager) -> Result<WorkoutExercise> {
+        let workout = Workout::mocked(database).await?;
+        let exercise = Exercise::mocked(database).await?;
+
+        let workout_exercise = self.workout(&workout)
+            .exercise(&exercise)
+            .create(database)
+            .await?;
+
+        Ok(workout_exercise)
+    }
+}
+
+impl WorkoutExerciseBuilder<WorkoutExerciseWorkoutId, NoWorkoutExerciseExerciseId, WorkoutExerciseSequenceNumber> {
+    pub async fn create(self, database: &DatabaseManager) -> Result<WorkoutExercise> {
+        let exercise = Exercise::mocked(database).await?;
+
+        let workout_exercise = self.exercise(&exercise)
+            .create(database)
+            .await?;
+
+        Ok(workout_exercise)
+    }
+}
+
+impl WorkoutExerciseBuilder<NoWorkoutExerciseWorkoutId, WorkoutExerciseExerciseId, WorkoutExerciseSequenceNumber> {
+    pub async fn create(self, database: &DatabaseManager) -> Result<WorkoutExercise> {
+        let workout = Workout::mocked(database).await?;
+
+        let workout_exercise = self.workout(&workout)
+            .create(database)
+            .await?;
+
+        Ok(workout_exercise)
+    }
+}
+
+// endregion
+
+// region WorkoutSet
+
+use crate::models::workout_set::{
+    WorkoutSetBuilder,
+    SequenceNumber as WorkoutSetSequenceNumber,
+    NoSequenceNumber as NoWorkoutSetSequenceNumber,
+    NoWorkoutExerciseId,
+};
+
+impl WorkoutSet {
+    pub fn fake() -> WorkoutSetBuilder<NoWorkoutExerciseId, WorkoutSetSequenceNumber> {
+        WorkoutSetBuilder::fake()
+    }
+
+    pub async fn mocked(database: &DatabaseManager) -> Result<WorkoutSet> {
+        let set = WorkoutSetBuilder::fake()
+            .create(database)
+            .await?;
+
+        Ok(set)
+    }
+}
+
+impl WorkoutSetBuilder<NoWorkoutExerciseId, NoWorkoutSetSequenceNumber> {
+    pub fn fake() -> WorkoutSetBuilder<NoWorkoutExerciseId, WorkoutSetSequenceNumber> {
+        WorkoutSetBuilder::new()
+            .sequence_number(1)
+            .weight(Some((20..200).fake::<i16>() as f64))
+            .repetitions(Some((1..20).fake::<i16>()))
+    }
+}
+
+impl WorkoutSetBuilder<NoWorkoutExerciseId, WorkoutSetSequenceNumber> {
+    pub async fn create(self, database: &DatabaseManager) -> Result<WorkoutSet> {
+        let workout_exercise = WorkoutExercise::mocked(database).await?;
+
+        let set = self.workout_exercise(&workout_exercise)
+            .create(database)
+            .await?;
+
+        Ok(set)
+    }
+}
+
+// endregion
+
+// region Routine
+
+use crate::models::routine::{
+    RoutineBuilder,
+    Name as RoutineName,
+    NoName as NoRoutineName,
+    NoUserId as NoRoutineUserId,
+};
+
+impl Routine {
+    pub fn fake() -> RoutineBuilder<NoRoutineUserId, RoutineName> {
+        RoutineBuilder::fake()
+    }
+
+    pub async fn mocked(database: &DatabaseManager) -> Result<Routine> {
+        let routine = RoutineBuilder::fake()
+            .create(database)
+            .await?;
+
+        Ok(routine)
+    }
+}
+
+impl RoutineBuilder<NoRoutineUserId, NoRoutineName> {
+    pub fn fake() -> RoutineBuilder<NoRoutineUserId, RoutineName> {
+        let tokens: Vec<String> = Words(1..5).fake();
+        let name = tokens.join(" ");
+
+        Routine::new().name(name)
+    }
+}
+
+impl RoutineBuilder<NoRoutineUserId, RoutineName> {
+    pub async fn create(self, database: &DatabaseManager) -> Result<Routine> {
+        let user = User::mocked(database).await?;
+
+        let routine = self.user(&user)
+            .create(database)
+            .await?;
+
+        Ok(routine)
+    }
+}
+
+// endregion
+
+// region RoutineExercise
+
+use crate::models::routine_exercise::{
+    RoutineExerciseBuilder,
+    ExerciseId as RoutineExerciseExerciseId,
+    RoutineId as RoutineExerciseRoutineId,
+    SequenceNumber as RoutineExerciseSequenceNumber,
+    TargetSets as RoutineExerciseTargetSets,
+    NoExerciseId as NoRoutineExerciseExerciseId,
+    NoRoutineId as NoRoutineExerciseRoutineId,
+    NoSequenceNumber as NoRoutineExerciseSequenceNumber,
+    NoTargetSets as NoRoutineExerciseTargetSets,
+};
+
+impl RoutineExercise {
+    pub fn fake() -> RoutineExerciseBuilder<NoRoutineExerciseRoutineId, NoRoutineExerciseExerciseId, RoutineExerciseSequenceNumber, RoutineExerciseTargetSets> {
+        RoutineExerciseBuilder::fake()
+    }
+
+    pub async fn mocked(database: &DatabaseManager) -> Result<RoutineExercise> {
+        let routine_exercise = RoutineExerciseBuilder::fake()
+            .create(database)
+            .await?;
+
+        Ok(routine_exercise)
+    }
+}
+
+impl RoutineExerciseBuilder<NoRoutineExerciseRoutineId, NoRoutineExerciseExerciseId, NoRoutineExerciseSequenceNumber, NoRoutineExerciseTargetSets> {
+    pub fn fake() -> RoutineExerciseBuilder<NoRoutineExerciseRoutineId, NoRoutineExerciseExerciseId, RoutineExerciseSequenceNumber, RoutineExerciseTargetSets> {
+        RoutineExerciseBuilder::new()
+            .sequence_number(1)
+            .target_sets((1..6).fake::<i16>())
+            .target_repetitions(Some((1..20).fake::<i16>()))
+            .target_weight(Some((20..200).fake::<i16>() as f64))
+            .rest_seconds(Some(90))
+    }
+}
+
+impl RoutineExerciseBuilder<NoRoutineExerciseRoutineId, NoRoutineExerciseExerciseId, RoutineExerciseSequenceNumber, RoutineExerciseTargetSets> {
+    pub async fn create(self, database: &DatabaseManager) -> Result<RoutineExercise> {
+        let routine = Routine::mocked(database).await?;
+        let exercise = Exercise::mocked(database).await?;
+
+        let routine_exercise = self.routine(&routine)
+            .exercise(&exercise)
+            .create(database)
+            .await?;
+
+        Ok(routine_exercise)
+    }
+}
+
+impl RoutineExerciseBuilder<RoutineExerciseRoutineId, NoRoutineExerciseExerciseId, RoutineExerciseSequenceNumber, RoutineExerciseTargetSets> {
+    pub async fn create(self, database: &DatabaseManager) -> Result<RoutineExercise> {
+        let exercise = Exercise::mocked(database).await?;
+
+        let routine_exercise = self.exercise(&exercise)
+            .create(database)
+            .await?;
+
+        Ok(routine_exercise)
+    }
+}
+
+impl RoutineExerciseBuilder<NoRoutineExerciseRoutineId, RoutineExerciseExerciseId, RoutineExerciseSequenceNumber, RoutineExerciseTargetSets> {
+    pub async fn create(self, database: &DatabaseManager) -> Result<RoutineExercise> {
+        let routine = Routine::mocked(database).await?;
+
+        let routine_exercise = self.routine(&routine)
+            .create(database)
+            .await?;
+
+        Ok(routine_exercise)
+    }
+}
+
+// endregion
+
+// region Program
+
+use crate::models::program::{
+    ProgramBuilder,
+    Name as ProgramName,
+    Weeks as ProgramWeeks,
+    NoName as NoProgramName,
+    NoUserId as NoProgramUserId,
+    NoWeeks as NoProgramWeeks,
+};
+
+impl Program {
+    pub fn fake() -> ProgramBuilder<NoProgramUserId, ProgramName, ProgramWeeks> {
+        ProgramBuilder::fake()
+    }
+
+    pub async fn mocked(database: &DatabaseManager) -> Result<Program> {
+        let program = ProgramBuilder::fake()
+            .create(database)
+            .await?;
+
+        Ok(program)
+    }
+}
+
+impl ProgramBuilder<NoProgramUserId, NoProgramName, NoProgramWeeks> {
+    pub fn fake() -> ProgramBuilder<NoProgramUserId, ProgramName, ProgramWeeks> {
+        let tokens: Vec<String> = Words(1..5).fake();
+        let name = tokens.join(" ");
+
+        Program::new()
+            .name(name)
+            .weeks((4..12).fake::<i16>())
+    }
+}
+
+impl ProgramBuilder<NoProgramUserId, ProgramName, ProgramWeeks> {
+    pub async fn create(self, database: &DatabaseManager) -> Result<Program> {
+        let user = User::mocked(database).await?;
+
+        let program = self.user(&user)
+            .create(database)
+            .await?;
+
+        Ok(program)
+    }
+}
+
+// endregion
+
+// region ProgramDay
+
+use crate::models::program_day::{
+    ProgramDayBuilder,
+    ProgramId as ProgramDayProgramId,
+    Schedule as ProgramDaySchedule,
+    NoProgramId as NoProgramDayProgramId,
+    NoRoutineId as NoProgramDayRoutineId,
+    NoSchedule as NoProgramDaySchedule,
+};
+
+impl ProgramDay {
+    pub fn fake() -> ProgramDayBuilder<NoProgramDayProgramId, NoProgramDayRoutineId, ProgramDaySchedule> {
+        ProgramDayBuilder::fake()
+    }
+
+    pub async fn mocked(database: &DatabaseManager) -> Result<ProgramDay> {
+        let day = ProgramDayBuilder::fake()
+            .create(database)
+            .await?;
+
+        Ok(day)
+    }
+}
+
+impl ProgramDayBuilder<NoProgramDayProgramId, NoProgramDayRoutineId, NoProgramDaySchedule> {
+    pub fn fake() -> ProgramDayBuilder<NoProgramDayProgramId, NoProgramDayRoutineId, ProgramDaySchedule> {
+        ProgramDayBuilder::new()
+            .schedule(1, 1)
+    }
+}
+
+impl ProgramDayBuilder<NoProgramDayProgramId, NoProgramDayRoutineId, ProgramDaySchedule> {
+    pub async fn create(self, database: &DatabaseManager) -> Result<ProgramDay> {
+        let program = Program::mocked(database).await?;
+
+        self.program(&program)
+            .create(database)
+            .await
+    }
+}
+
+impl ProgramDayBuilder<ProgramDayProgramId, NoProgramDayRoutineId, ProgramDaySchedule> {
+    pub async fn create(self, database: &DatabaseManager) -> Result<ProgramDay> {
+        let routine = Routine::mocked(database).await?;
+
+        let day = self.routine(&routine)
+            .create(database)
+            .await?;
+
+        Ok(day)
+    }
+}
+
+// endregion
+
+// region ProgramEnrollment
+
+use crate::models::program_enrollment::{
+    ProgramEnrollmentBuilder,
+    ProgramId as ProgramEnrollmentProgramId,
+    StartedOn as ProgramEnrollmentStartedOn,
+    NoProgramId as NoProgramEnrollmentProgramId,
+    NoStartedOn as NoProgramEnrollmentStartedOn,
+    NoUserId as NoProgramEnrollmentUserId,
+};
+
+impl ProgramEnrollment {
+    pub fn fake() -> ProgramEnrollmentBuilder<NoProgramEnrollmentProgramId, NoProgramEnrollmentUserId, ProgramEnrollmentStartedOn> {
+        ProgramEnrollmentBuilder::fake()
+    }
+
+    pub async fn mocked(database: &DatabaseManager) -> Result<ProgramEnrollment> {
+        let enrollment = ProgramEnrollmentBuilder::fake()
+            .create(database)
+            .await?;
+
+        Ok(enrollment)
+    }
+}
+
+impl ProgramEnrollmentBuilder<NoProgramEnrollmentProgramId, NoProgramEnrollmentUserId, NoProgramEnrollmentStartedOn> {
+    pub fn fake() -> ProgramEnrollmentBuilder<NoProgramEnrollmentProgramId, NoProgramEnrollmentUserId, ProgramEnrollmentStartedOn> {
+        ProgramEnrollmentBuilder::new()
+            .started_on(Utc::now().date_naive())
+    }
+}
+
+impl ProgramEnrollmentBuilder<NoProgramEnrollmentProgramId, NoProgramEnrollmentUserId, ProgramEnrollmentStartedOn> {
+    pub async fn create(self, database: &DatabaseManager) -> Result<ProgramEnrollment> {
+        let program = Program::mocked(database).await?;
+
+        self.program(&program)
+            .create(database)
+            .await
+    }
+}
+
+impl ProgramEnrollmentBuilder<ProgramEnrollmentProgramId, NoProgramEnrollmentUserId, ProgramEnrollmentStartedOn> {
+    pub async fn create(self, database: &DatabaseManager) -> Result<ProgramEnrollment> {
+        let user = User::mocked(database).await?;
+
+        let enrollment = self.user(&user)
+            .create(database)
+            .await?;
+
+        Ok(enrollment)
+    }
+}
+
+// endregion
+
+// region PersonalRecord
+
+use crate::models::personal_record::{
+    PersonalRecordBuilder,
+    Record as PersonalRecordRecord,
+    NoExerciseId as NoPersonalRecordExerciseId,
+    NoRecord as NoPersonalRecordRecord,
+    NoUserId as NoPersonalRecordUserId,
+    NoWorkoutSetId as NoPersonalRecordWorkoutSetId,
+};
+
+impl PersonalRecord {
+    pub fn fake() -> PersonalRecordBuilder<NoPersonalRecordUserId, NoPersonalRecordExerciseId, NoPersonalRecordWorkoutSetId, PersonalRecordRecord> {
+        PersonalRecordBuilder::fake()
+    }
+
+    pub async fn mocked(database: &DatabaseManager) -> Result<PersonalRecord> {
+        let record = PersonalRecordBuilder::fake()
+            .create(database)
+            .await?;
+
+        Ok(record)
+    }
+}
+
+impl PersonalRecordBuilder<NoPersonalRecordUserId, NoPersonalRecordExerciseId, NoPersonalRecordWorkoutSetId, NoPersonalRecordRecord> {
+    pub fn fake() -> PersonalRecordBuilder<NoPersonalRecordUserId, NoPersonalRecordExerciseId, NoPersonalRecordWorkoutSetId, PersonalRecordRecord> {
+        PersonalRecordBuilder::new()
+            .record(PersonalRecordType::HeaviestWeight, (20..200).fake::<i16>() as f64)
+    }
+}
+
+impl PersonalRecordBuilder<NoPersonalRecordUserId, NoPersonalRecordExerciseId, NoPersonalRecordWorkoutSetId, PersonalRecordRecord> {
+    pub async fn create(self, database: &DatabaseManager) -> Result<PersonalRecord> {
+        let set = WorkoutSet::mocked(database).await?;
+        let workout_exercise = set.workout_exercise(database).await?;
+        let workout = workout_exercise.workout(database).await?;
+
+        let record = self.user_id(workout.user_id)
+            .exercise_id(workout_exercise.exercise_id)
+            .workout_set(&set)
+            .create(database)
+            .await?;
+
+        Ok(record)
+    }
+}
+
+// endregion
+
+// region CoachingRelationship
+
+use crate::models::coaching_relationship::{
+    CoachingRelationshipBuilder,
+    ClientId as CoachingRelationshipClientId,
+    CoachId as CoachingRelationshipCoachId,
+    NoClientId as NoCoachingRelationshipClientId,
+    NoCoachId as NoCoachingRelationshipCoachId,
+};
+
+impl CoachingRelationship {
+    pub fn fake() -> CoachingRelationshipBuilder<NoCoachingRelationshipCoachId, NoCoachingRelationshipClientId> {
+        CoachingRelationshipBuilder::new()
+    }
+
+    pub async fn mocked(database: &DatabaseManager) -> Result<CoachingRelationship> {
+        let relationship = CoachingRelationshipBuilder::new()
+            .create(database)
+            .await?;
+
+        Ok(relationship)
+    }
+}
+
+impl CoachingRelationshipBuilder<NoCoachingRelationshipCoachId, NoCoachingRelationshipClientId> {
+    pub async fn create(self, database: &DatabaseManager) -> Result<CoachingRelationship> {
+        let coach = User::fake()
+            .role(Role::Coach)
+            .create(database)
+            .await?;
+
+        self.coach(&coach)
+            .create(database)
+            .await
+    }
+}
+
+impl CoachingRelationshipBuilder<NoCoachingRelationshipCoachId, CoachingRelationshipClientId> {
+    pub async fn create(self, database: &DatabaseManager) -> Result<CoachingRelationship> {
+        let coach = User::fake()
+            .role(Role::Coach)
+            .create(database)
+            .await?;
+
+        let relationship = self.coach(&coach)
+            .create(database)
+            .await?;
+
+        Ok(relationship)
+    }
+}
+
+impl CoachingRelationshipBuilder<CoachingRelationshipCoachId, NoCoachingRelationshipClientId> {
+    pub async fn create(self, database: &DatabaseManager) -> Result<CoachingRelationship> {
+        let client = User::mocked(database).await?;
+
+        let relationship = self.client(&client)
+            .create(database)
+            .await?;
+
+        Ok(relationship)
+    }
+}
+
+// endregion
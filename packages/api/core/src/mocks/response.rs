@@ -34,6 +34,10 @@ impl MockResponse {
         self.0.assert_status_not_found();
     }
 
+    pub fn assert_conflict(&self) {
+        self.0.assert_status(StatusCode::CONFLICT);
+    }
+
     pub fn assert_unprocessable(&self) {
         self.0.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
     }
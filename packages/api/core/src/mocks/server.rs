@@ -44,6 +44,19 @@ impl MockServer {
         server
     }
 
+    pub async fn authenticated_coach(pool: PgPool) -> Self {
+        let mut server = Self::init(pool).await;
+        let user = MockUser::create_coach(server.database()).await;
+
+        login(&server, json!({
+            "email": user.email(),
+            "password": user.password(),
+        })).await;
+
+        server.user = Some(user);
+        server
+    }
+
     pub async fn authenticated_admin(pool: PgPool) -> Self {
         let mut server = Self::init(pool).await;
         let user = MockUser::create_admin(server.database()).await;
@@ -57,6 +70,19 @@ impl MockServer {
         server
     }
 
+    pub async fn authenticated_moderator(pool: PgPool) -> Self {
+        let mut server = Self::init(pool).await;
+        let user = MockUser::create_moderator(server.database()).await;
+
+        login_as_admin(&server, json!({
+            "email": user.email(),
+            "password": user.password(),
+        })).await;
+
+        server.user = Some(user);
+        server
+    }
+
     // endregion
 
     // region Instance Methods
@@ -91,10 +117,26 @@ impl MockServer {
         MockResponse(self.server.get(path).add_query_params(&params).await)
     }
 
+    /// Requests a link handed out by the API, such as a pagination link.
+    pub async fn get_link(&self, link: &str) -> MockResponse {
+        let (path, query) = link.split_once('?').unwrap_or((link, ""));
+        let params = serde_urlencoded::from_str::<Vec<(String, String)>>(query).unwrap_or_default();
+
+        MockResponse(self.server.get(path).add_query_params(&params).await)
+    }
+
     pub async fn post(&self, path: &str, body: Value) -> MockResponse {
         MockResponse(self.server.post(path).json(&body).await)
     }
 
+    pub async fn put(&self, path: &str, body: Value) -> MockResponse {
+        MockResponse(self.server.put(path).json(&body).await)
+    }
+
+    pub async fn patch(&self, path: &str, body: Value) -> MockResponse {
+        MockResponse(self.server.patch(path).json(&body).await)
+    }
+
     pub async fn delete(&self, path: &str) -> MockResponse {
         MockResponse(self.server.delete(path).await)
     }
@@ -1,3 +1,4 @@
+use crate::enums::Role;
 use crate::models::{Profile, User};
 use crate::prelude::*;
 
@@ -21,6 +22,40 @@ impl MockUser {
         }
     }
 
+    pub async fn create_moderator(database: &DatabaseManager) -> Self {
+        let user = User::fake()
+            .role(Role::Moderator)
+            .password("#Password1234")
+            .create(database)
+            .await
+            .unwrap();
+
+        Self {
+            password: "#Password1234",
+            user,
+        }
+    }
+
+    pub async fn create_coach(database: &DatabaseManager) -> Self {
+        let user = User::fake()
+            .role(Role::Coach)
+            .password("#Password1234")
+            .create(database)
+            .await
+            .unwrap();
+
+        Profile::fake()
+            .user(&user)
+            .create(database)
+            .await
+            .unwrap();
+
+        Self {
+            password: "#Password1234",
+            user,
+        }
+    }
+
     pub async fn create(database: &DatabaseManager) -> Self {
         let user = User::fake()
             .password("#Password1234")
@@ -0,0 +1,290 @@
+use super::{Error, Result, User};
+use crate::enums::CoachingStatus;
+use crate::prelude::*;
+use database::{DatabaseManager, HasRouteKey, Model};
+use sqlx::FromRow;
+
+#[cfg(test)]
+pub(crate) use builder::*;
+
+/// A coach's access to a client's training. It starts out as an invitation
+/// from the coach and only grants access once the client has accepted it.
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "coaching_relationships", route_key = "ulid")]
+pub struct CoachingRelationship {
+    #[model(generated)]
+    pub id: i32,
+    #[model(generated)]
+    pub ulid: String,
+    pub coach_id: i16,
+    pub client_id: i16,
+    pub status: CoachingStatus,
+    pub responded_at: Option<ISO8601DateTimeUTC>,
+    pub ended_at: Option<ISO8601DateTimeUTC>,
+    pub created_at: ISO8601DateTimeUTC,
+    pub updated_at: ISO8601DateTimeUTC,
+}
+
+mod builder {
+    use super::{CoachingRelationship, Result, User};
+    use database::{Connection, Model, SqlxAction};
+
+    // region Type States
+
+    #[derive(Default)]
+    pub struct NoCoachId;
+    #[derive(Default)]
+    pub struct CoachId(pub i16);
+
+    #[derive(Default)]
+    pub struct NoClientId;
+    #[derive(Default)]
+    pub struct ClientId(pub i16);
+
+    // endregion
+
+    // region Builder
+
+    #[derive(Default)]
+    pub struct CoachingRelationshipBuilder<C, L> {
+        coach_id: C,
+        client_id: L,
+    }
+
+    impl CoachingRelationshipBuilder<NoCoachId, NoClientId> {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl<C, L> CoachingRelationshipBuilder<C, L> {
+        pub fn coach_id(self, id: i16) -> CoachingRelationshipBuilder<CoachId, L> {
+            CoachingRelationshipBuilder {
+                coach_id: CoachId(id),
+                client_id: self.client_id,
+            }
+        }
+
+        pub fn coach(self, coach: &User) -> CoachingRelationshipBuilder<CoachId, L> {
+            self.coach_id(coach.id)
+        }
+
+        pub fn client_id(self, id: i16) -> CoachingRelationshipBuilder<C, ClientId> {
+            CoachingRelationshipBuilder {
+                coach_id: self.coach_id,
+                client_id: ClientId(id),
+            }
+        }
+
+        pub fn client(self, client: &User) -> CoachingRelationshipBuilder<C, ClientId> {
+            self.client_id(client.id)
+        }
+    }
+
+    impl CoachingRelationshipBuilder<CoachId, ClientId> {
+        pub async fn create(self, mut database: impl Connection) -> Result<CoachingRelationship> {
+            let model = CoachingRelationship::query()
+                .insert()
+                .value("coach_id", self.coach_id.0)
+                .value("client_id", self.client_id.0)
+                .returning(&["*"])
+                .one::<_, CoachingRelationship>(database.executor())
+                .await?;
+
+            Ok(model)
+        }
+    }
+
+    // endregion
+}
+
+use builder::*;
+
+impl CoachingRelationship {
+    // region Static Methods
+
+    pub fn new() -> CoachingRelationshipBuilder<NoCoachId, NoClientId> {
+        CoachingRelationshipBuilder::new()
+    }
+
+    /// The pending invitation or accepted relationship between a coach and
+    /// a client, if there is one.
+    pub async fn find_open(coach_id: i16, client_id: i16, database: &DatabaseManager) -> Result<Option<Self>> {
+        let relationship = sqlx::query_as::<_, Self>(format!(
+            "SELECT * FROM {} WHERE coach_id = $1 AND client_id = $2 AND status IN ($3, $4)",
+            Self::TABLE_NAME,
+        ).as_str())
+            .bind(coach_id)
+            .bind(client_id)
+            .bind(CoachingStatus::Pending)
+            .bind(CoachingStatus::Accepted)
+            .fetch_optional(database.connection())
+            .await?;
+
+        Ok(relationship)
+    }
+
+    /// The pending invitations and accepted relationships that `user_id` is
+    /// either the coach or the client in, newest first.
+    pub async fn all_open_for_user(user_id: i16, database: &DatabaseManager) -> Result<Vec<Self>> {
+        let relationships = sqlx::query_as::<_, Self>(format!(
+            "SELECT * FROM {} WHERE (coach_id = $1 OR client_id = $1) AND status IN ($2, $3) ORDER BY created_at DESC, id DESC",
+            Self::TABLE_NAME,
+        ).as_str())
+            .bind(user_id)
+            .bind(CoachingStatus::Pending)
+            .bind(CoachingStatus::Accepted)
+            .fetch_all(database.connection())
+            .await?;
+
+        Ok(relationships)
+    }
+
+    /// Finds a pending invitation or accepted relationship that `user_id` is
+    /// either the coach or the client in.
+    pub async fn find_open_for_user(ulid: String, user_id: i16, database: &DatabaseManager) -> Result<Self> {
+        let relationship = Self::find_by_route_key(ulid, database).await?;
+
+        if (relationship.coach_id != user_id && relationship.client_id != user_id) || !relationship.is_open() {
+            return Err(relationship.not_found());
+        }
+
+        Ok(relationship)
+    }
+
+    /// Finds an invitation that is still waiting on `client_id` to answer it.
+    pub async fn find_pending_for_client(ulid: String, client_id: i16, database: &DatabaseManager) -> Result<Self> {
+        let relationship = Self::find_by_route_key(ulid, database).await?;
+
+        if relationship.client_id != client_id || relationship.status != CoachingStatus::Pending {
+            return Err(relationship.not_found());
+        }
+
+        Ok(relationship)
+    }
+
+    /// Finds a relationship in which the client has given `coach_id` access.
+    pub async fn find_accepted_for_coach(ulid: String, coach_id: i16, database: &DatabaseManager) -> Result<Self> {
+        let relationship = Self::find_by_route_key(ulid, database).await?;
+
+        if relationship.coach_id != coach_id || relationship.status != CoachingStatus::Accepted {
+            return Err(relationship.not_found());
+        }
+
+        Ok(relationship)
+    }
+
+    // endregion
+
+    // region Relationships
+
+    pub async fn coach(&self, database: &DatabaseManager) -> Result<User> {
+        let user = User::find_by_pk(self.coach_id, database).await?;
+
+        Ok(user)
+    }
+
+    pub async fn client(&self, database: &DatabaseManager) -> Result<User> {
+        let user = User::find_by_pk(self.client_id, database).await?;
+
+        Ok(user)
+    }
+
+    // endregion
+
+    // region Instance Methods
+
+    pub fn is_open(&self) -> bool {
+        matches!(self.status, CoachingStatus::Pending | CoachingStatus::Accepted)
+    }
+
+    pub async fn accept(&mut self, database: &DatabaseManager) -> Result<()> {
+        self.status = CoachingStatus::Accepted;
+        self.responded_at = Some(chrono::Utc::now());
+
+        self.save(database).await?;
+
+        Ok(())
+    }
+
+    pub async fn decline(&mut self, database: &DatabaseManager) -> Result<()> {
+        self.status = CoachingStatus::Declined;
+        self.responded_at = Some(chrono::Utc::now());
+
+        self.save(database).await?;
+
+        Ok(())
+    }
+
+    /// Ends the relationship, taking away the coach's access. A pending
+    /// invitation can be revoked as well.
+    pub async fn revoke(&mut self, database: &DatabaseManager) -> Result<()> {
+        self.status = CoachingStatus::Revoked;
+        self.ended_at = Some(chrono::Utc::now());
+
+        self.save(database).await?;
+
+        Ok(())
+    }
+
+    fn not_found(&self) -> Error {
+        Error::ModelNotFound(format!(
+            "No {} found where {} = {}.",
+            Self::MODEL_NAME, Self::ROUTE_KEY, self.ulid,
+        ))
+    }
+
+    // endregion
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CoachingRelationship;
+    use crate::enums::CoachingStatus;
+    use crate::prelude::*;
+
+    #[sqlx::test]
+    async fn cannot_invite_the_same_client_twice(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let relationship = CoachingRelationship::mocked(&database).await?;
+
+        // Act
+        let result = CoachingRelationship::new()
+            .coach_id(relationship.coach_id)
+            .client_id(relationship.client_id)
+            .create(&database)
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn can_invite_again_after_revoking(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let mut relationship = CoachingRelationship::mocked(&database).await?;
+        relationship.revoke(&database).await?;
+
+        // Act
+        let invitation = CoachingRelationship::new()
+            .coach_id(relationship.coach_id)
+            .client_id(relationship.client_id)
+            .create(&database)
+            .await?;
+
+        // Assert
+        assert_eq!(CoachingStatus::Pending, invitation.status);
+        assert_some_eq(
+            invitation.id,
+            CoachingRelationship::find_open(relationship.coach_id, relationship.client_id, &database)
+                .await?
+                .map(|open| open.id),
+        );
+
+        Ok(())
+    }
+}
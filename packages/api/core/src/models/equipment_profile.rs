@@ -0,0 +1,228 @@
+use super::{Error, ExerciseEquipment, Result, User};
+use crate::prelude::*;
+use database::{Connection, DatabaseManager, HasRouteKey, Model, SqlxAction, SqlxQuery};
+use sqlx::FromRow;
+
+#[cfg(test)]
+pub(crate) use builder::*;
+
+/// A named set of equipment a user has access to, such as
+/// the equipment they own at home or the equipment of a gym.
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "equipment_profiles", route_key = "ulid")]
+pub struct EquipmentProfile {
+    #[model(generated)]
+    pub id: i32,
+    #[model(generated)]
+    pub ulid: String,
+    pub user_id: i16,
+    pub name: String,
+    pub created_at: ISO8601DateTimeUTC,
+    pub updated_at: ISO8601DateTimeUTC,
+}
+
+mod builder {
+    use super::{EquipmentProfile, Result, User};
+    use database::{Connection, Model, SqlxAction};
+
+    // region Type States
+
+    #[derive(Default)]
+    pub struct NoUserId;
+    #[derive(Default)]
+    pub struct UserId(pub i16);
+
+    #[derive(Default)]
+    pub struct NoName;
+    #[derive(Default)]
+    pub struct Name(pub String);
+
+    // endregion
+
+    // region Builder
+
+    #[derive(Default)]
+    pub struct EquipmentProfileBuilder<U, N> {
+        user_id: U,
+        name: N,
+    }
+
+    impl EquipmentProfileBuilder<NoUserId, NoName> {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl<U, N> EquipmentProfileBuilder<U, N> {
+        pub fn user_id(self, id: i16) -> EquipmentProfileBuilder<UserId, N> {
+            EquipmentProfileBuilder {
+                user_id: UserId(id),
+                name: self.name,
+            }
+        }
+
+        pub fn user(self, user: &User) -> EquipmentProfileBuilder<UserId, N> {
+            EquipmentProfileBuilder {
+                user_id: UserId(user.id),
+                name: self.name,
+            }
+        }
+
+        pub fn name(self, name: impl Into<String>) -> EquipmentProfileBuilder<U, Name> {
+            EquipmentProfileBuilder {
+                user_id: self.user_id,
+                name: Name(name.into()),
+            }
+        }
+    }
+
+    impl EquipmentProfileBuilder<UserId, Name> {
+        pub async fn create(self, mut database: impl Connection) -> Result<EquipmentProfile> {
+            let model = EquipmentProfile::query()
+                .insert()
+                .value("user_id", self.user_id.0)
+                .value("name", self.name.0)
+                .returning(&["*"])
+                .one::<_, EquipmentProfile>(database.executor())
+                .await?;
+
+            Ok(model)
+        }
+    }
+
+    // endregion
+}
+
+use builder::*;
+
+impl EquipmentProfile {
+    /// The table linking equipment profiles to the equipment they contain.
+    pub const EQUIPMENT_TABLE_NAME: &'static str = "equipment_profiles_equipment";
+
+    // region Static Methods
+
+    pub fn new() -> EquipmentProfileBuilder<NoUserId, NoName> {
+        EquipmentProfileBuilder::new()
+    }
+
+    pub async fn find_for_user(ulid: String, user_id: i16, database: &DatabaseManager) -> Result<Self> {
+        let profile = Self::find_by_route_key(ulid, database).await?;
+
+        if profile.user_id != user_id {
+            return Err(Error::ModelNotFound(format!(
+                "No {} found where {} = {}.",
+                Self::MODEL_NAME, Self::ROUTE_KEY, profile.ulid,
+            )));
+        }
+
+        Ok(profile)
+    }
+
+    // endregion
+
+    // region Relationships
+
+    pub async fn user(&self, database: &DatabaseManager) -> Result<User> {
+        let user = User::find_by_pk(self.user_id, database).await?;
+
+        Ok(user)
+    }
+
+    pub async fn equipment(&self, database: &DatabaseManager) -> Result<Vec<ExerciseEquipment>> {
+        let equipment = ExerciseEquipment::query()
+            .select(&["equipment.*"])
+            .alias("equipment")
+            .inner_join(Self::EQUIPMENT_TABLE_NAME, "pivot.equipment_id", "=", "equipment.id")
+            .alias("pivot")
+            .and_where("pivot.equipment_profile_id", "=", self.id)
+            .order_by("equipment.name", true)
+            .all::<_, ExerciseEquipment>(database.connection())
+            .await?;
+
+        Ok(equipment)
+    }
+
+    pub async fn equipment_ids(&self, database: &DatabaseManager) -> Result<Vec<i16>> {
+        let ids = self.equipment(database)
+            .await?
+            .into_iter()
+            .map(|equipment| equipment.id)
+            .collect();
+
+        Ok(ids)
+    }
+
+    // endregion
+
+    // region Instance Methods
+
+    /// Replaces the equipment in the profile with the given equipment.
+    pub async fn sync_equipment(&self, equipment_ids: &[i16], mut database: impl Connection) -> Result<()> {
+        SqlxQuery::table(Self::EQUIPMENT_TABLE_NAME)
+            .delete()
+            .and_where("equipment_profile_id", "=", self.id)
+            .execute(database.executor())
+            .await?;
+
+        let mut equipment_ids = equipment_ids.to_vec();
+        equipment_ids.sort();
+        equipment_ids.dedup();
+
+        for equipment_id in equipment_ids {
+            SqlxQuery::table(Self::EQUIPMENT_TABLE_NAME)
+                .insert()
+                .value("equipment_profile_id", self.id)
+                .value("equipment_id", equipment_id)
+                .execute(database.executor())
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    // endregion
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EquipmentProfile;
+    use crate::models::ExerciseEquipment;
+    use crate::prelude::*;
+
+    #[sqlx::test]
+    async fn sync_equipment_replaces_equipment(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let profile = EquipmentProfile::mocked(&database).await?;
+        let barbell = ExerciseEquipment::mocked(&database).await?;
+        let dumbbell = ExerciseEquipment::mocked(&database).await?;
+        profile.sync_equipment(&[barbell.id], &database).await?;
+
+        // Act
+        profile.sync_equipment(&[dumbbell.id, dumbbell.id], &database).await?;
+
+        // Assert
+        assert_eq!(vec![dumbbell.id], profile.equipment_ids(&database).await?);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn cannot_create_profile_with_duplicate_name_for_user(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let profile = EquipmentProfile::mocked(&database).await?;
+
+        // Act
+        let result = EquipmentProfile::fake()
+            .user_id(profile.user_id)
+            .name(profile.name)
+            .create(&database)
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
@@ -6,7 +6,8 @@ use serde_with::{serde_as, DisplayFromStr};
 
 #[derive(Debug, strum_macros::Display, thiserror::Error, Serialize)]
 pub enum Error {
-    ModelNotCreated(String),
+    Conflict(String),
+    InvalidData(String),
     ModelNotFound(String),
     Unknown(String),
 }
@@ -22,7 +23,8 @@ impl From<database::Error> for Error {
         use database::Error as DatabaseError;
 
         match error {
-            DatabaseError::ModelNotCreated(message) => Self::ModelNotCreated(message),
+            DatabaseError::Conflict(message) => Self::Conflict(message),
+            DatabaseError::InvalidData(message) => Self::InvalidData(message),
             DatabaseError::ModelNotFound { .. } => Self::ModelNotFound(error.to_string()),
             DatabaseError::Unknown(err) => Self::Unknown(err.to_string()),
         }
@@ -31,25 +33,19 @@ impl From<database::Error> for Error {
 
 impl From<sqlx::Error> for Error {
     fn from(error: sqlx::Error) -> Self {
-        if let Some(db_error) = error.into_database_error() {
-            let message = db_error.message();
-
-            if db_error.is_check_violation()
-                || db_error.is_foreign_key_violation()
-                || db_error.is_unique_violation()
-            {
-                return Self::ModelNotCreated(message.to_owned());
-            }
+        match database::Error::from(error) {
+            database::Error::Unknown(_) => Self::Unknown(__("errors.unknownSystemError")),
+            error => error.into(),
         }
-
-        Self::Unknown(__("errors.unknownSystemError"))
     }
 }
 
 impl From<Error> for crate::http::Error {
     fn from(error: Error) -> Self {
         match error {
-            Error::ModelNotCreated(message) => Self::unprocessable(ClientError::Validation, Domain::Database)
+            Error::Conflict(message) => Self::conflict(ClientError::Conflict, Domain::Database)
+                .with_message(message),
+            Error::InvalidData(message) => Self::unprocessable(ClientError::Validation, Domain::Database)
                 .with_message(message),
             Error::ModelNotFound(message) => Self::not_found(ClientError::ResourceNotFound, Domain::Database)
                 .with_message(message),
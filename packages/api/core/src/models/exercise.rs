@@ -1,17 +1,20 @@
-use super::{Error, ExerciseEquipment, ExerciseInstruction, ExerciseMuscleMap, Muscle, MuscleGroup, Result};
+use super::{Error, ExerciseEquipment, ExerciseInstruction, ExerciseMuscleMap, Muscle, MuscleGroup, Result, RoutineExercise, WorkoutExercise};
 use crate::prelude::*;
-use crate::enums::{ExerciseForce, ExerciseMechanic, ExerciseMuscleTarget, ExerciseType, Measurement};
-use async_trait::async_trait;
-use database::{DatabaseManager, HasRouteKey, Model};
+use crate::enums::{ExerciseForce, ExerciseMechanic, ExerciseMuscleTarget, ExerciseSort, ExerciseType, Measurement};
+use crate::utils::search;
+use database::{Connection, DatabaseManager, HasRouteKey, Model, SelectAction, SqlxAction, SqlxQuery};
 use serde::Deserialize;
 use sqlx::{postgres::PgPool, FromRow};
 
 #[cfg(test)]
 pub(crate) use builder::*;
 
-#[derive(Clone, Debug, FromRow)]
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "exercises", route_key = "ulid")]
 pub struct Exercise {
+    #[model(generated)]
     pub id: i16,
+    #[model(generated)]
     pub ulid: String,
     pub external_id: Option<i16>,
     #[sqlx(rename = "type")]
@@ -28,10 +31,38 @@ pub struct Exercise {
     pub updated_at: ISO8601DateTimeUTC,
 }
 
+/// An exercise that can stand in for another, along with how closely it matches.
+#[derive(Clone, Debug, FromRow)]
+pub struct ExerciseAlternative {
+    #[sqlx(flatten)]
+    pub exercise: Exercise,
+    pub score: f64,
+}
+
+/// Conditions exercises must meet to be listed, and the order they are listed in.
+/// Each list matches exercises with any of its values.
+#[derive(Clone, Debug, Default)]
+pub struct ExerciseFilter {
+    /// Exercises that target any of these muscles or their child muscles.
+    pub muscle_ids: Option<Vec<i16>>,
+    pub muscle_group_ids: Option<Vec<i16>>,
+    pub equipment_ids: Option<Vec<i16>>,
+    pub types: Option<Vec<ExerciseType>>,
+    pub mechanics: Option<Vec<ExerciseMechanic>>,
+    pub forces: Option<Vec<ExerciseForce>>,
+    pub measurements: Option<Vec<Measurement>>,
+    /// Case-insensitive search over the name and alternative name.
+    pub search: Option<String>,
+    /// Only exercises that can be performed with this equipment are
+    /// listed. Exercises that need no equipment are always listed.
+    pub available_equipment_ids: Option<Vec<i16>>,
+    pub sort: ExerciseSort,
+}
+
 mod builder {
     use super::{Error, Exercise, ExerciseEquipment, MuscleGroup, Result};
     use crate::enums::{ExerciseForce, ExerciseMechanic, ExerciseType, Measurement};
-    use database::{DatabaseManager, Model};
+    use database::{Connection, Model, SqlxAction};
 
     // region Type States
 
@@ -152,22 +183,21 @@ mod builder {
     }
 
     impl ExerciseBuilder<Type, Name> {
-        pub async fn create(self, database: &DatabaseManager) -> Result<Exercise> {
-            let model = sqlx::query_as::<_, Exercise>(format!(
-                "INSERT INTO {} (external_id, type, target_muscle_group_id, name, name_alternative, description, equipment_id, mechanic, force, measurement) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) RETURNING *",
-                Exercise::TABLE_NAME,
-            ).as_str())
-                .bind(self.external_id)
-                .bind(self.exercise_type.0)
-                .bind(self.target_muscle_group_id)
-                .bind(self.name.0)
-                .bind(self.name_alternative)
-                .bind(self.description)
-                .bind(self.equipment_id)
-                .bind(self.mechanic)
-                .bind(self.force)
-                .bind(self.measurement)
-                .fetch_one(database.connection())
+        pub async fn create(self, mut database: impl Connection) -> Result<Exercise> {
+            let model = Exercise::query()
+                .insert()
+                .value("external_id", self.external_id)
+                .value("type", self.exercise_type.0)
+                .value("target_muscle_group_id", self.target_muscle_group_id)
+                .value("name", self.name.0)
+                .value("name_alternative", self.name_alternative)
+                .value("description", self.description)
+                .value("equipment_id", self.equipment_id)
+                .value("mechanic", self.mechanic)
+                .value("force", self.force)
+                .value("measurement", self.measurement)
+                .returning(&["*"])
+                .one::<_, Exercise>(database.executor())
                 .await?;
 
             Ok(model)
@@ -179,31 +209,124 @@ mod builder {
 
 use builder::*;
 
-#[async_trait]
-impl Model for Exercise {
-    const MODEL_NAME: &'static str = "Exercise";
-    const TABLE_NAME: &'static str = "exercises";
+impl Exercise {
+    // region Static Methods
 
-    type PrimaryKey = i16;
-    fn primary_key(&self) -> Self::PrimaryKey {
-        self.id
+    pub fn new() -> ExerciseBuilder<NoType, NoName> {
+        ExerciseBuilder::new()
     }
-}
 
-impl HasRouteKey for Exercise {
-    const ROUTE_KEY: &'static str = "ulid";
-    type RouteKey = String;
+    /// Builds the query for the exercises matching `filter`, in the order it asks for.
+    pub fn filtered(filter: &ExerciseFilter) -> SelectAction<'static> {
+        fn strings<T: ToString>(values: &[T]) -> Vec<String> {
+            values.iter().map(ToString::to_string).collect()
+        }
+
+        let mut query = Self::query().select(&["*"]);
+
+        if let Some(muscle_ids) = &filter.muscle_ids {
+            query = query.where_raw(format!(
+                "id IN (SELECT exercise_id FROM {maps} WHERE muscle_id IN ( \
+                    WITH RECURSIVE selected AS (SELECT id FROM {muscles} WHERE id = ANY(?) \
+                    UNION SELECT {muscles}.id FROM {muscles} JOIN selected ON {muscles}.parent_id = selected.id) \
+                    SELECT id FROM selected))",
+                maps = ExerciseMuscleMap::TABLE_NAME,
+                muscles = Muscle::TABLE_NAME,
+            ), (muscle_ids.clone(),));
+        }
+
+        if let Some(muscle_group_ids) = &filter.muscle_group_ids {
+            query = query.where_in("target_muscle_group_id", muscle_group_ids.clone());
+        }
+
+        if let Some(equipment_ids) = &filter.equipment_ids {
+            query = query.where_in("equipment_id", equipment_ids.clone());
+        }
+
+        if let Some(types) = &filter.types {
+            query = query.where_in("type", strings(types));
+        }
+
+        if let Some(mechanics) = &filter.mechanics {
+            query = query.where_in("mechanic", strings(mechanics));
+        }
+
+        if let Some(forces) = &filter.forces {
+            query = query.where_in("force", strings(forces));
+        }
+
+        if let Some(measurements) = &filter.measurements {
+            query = query.where_in("measurement", strings(measurements));
+        }
+
+        if let Some(search) = &filter.search {
+            let pattern = format!(
+                "%{}%",
+                search.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_"),
+            );
+
+            query = query.where_group(|group| group
+                .and_where("name", "ILIKE", pattern.clone())
+                .or_where("name_alternative", "ILIKE", pattern)
+            );
+        }
+
+        if let Some(available_equipment_ids) = &filter.available_equipment_ids {
+            query = query.where_group(|group| group
+                .where_null("equipment_id")
+                .or_where_in("equipment_id", available_equipment_ids.clone())
+                .or_where_raw(
+                    format!("equipment_id IN (SELECT id FROM {} WHERE name = ?)", ExerciseEquipment::TABLE_NAME),
+                    (ExerciseEquipment::BODYWEIGHT,),
+                )
+            );
+        }
 
-    fn route_key(&self) -> Self::RouteKey {
-        self.ulid.clone()
+        query
+            .order_by(filter.sort.column(), filter.sort.is_ascending())
+            .order_by(Self::PRIMARY_KEY, filter.sort.is_ascending())
     }
-}
 
-impl Exercise {
-    pub fn new() -> ExerciseBuilder<NoType, NoName> {
-        ExerciseBuilder::new()
+    /// Searches the name, alternative name, description and instructions of
+    /// exercises, best matches first. Names are also matched by trigram
+    /// similarity so that queries with typos or missing spaces still match.
+    pub async fn search(
+        query: &str,
+        limit: i64,
+        offset: i64,
+        database: &DatabaseManager,
+    ) -> Result<Vec<Self>> {
+        let exercises = sqlx::query_as::<_, Self>(format!(
+            "WITH query AS (SELECT websearch_to_tsquery('english', $1) AS tsquery), \
+            instruction_ranks AS (SELECT exercise_id, MAX(ts_rank(to_tsvector('english', content), query.tsquery)) AS rank \
+                FROM {instructions}, query WHERE to_tsvector('english', content) @@ query.tsquery GROUP BY exercise_id) \
+            SELECT {exercises}.* FROM {exercises} CROSS JOIN query \
+            LEFT JOIN instruction_ranks ON instruction_ranks.exercise_id = {exercises}.id \
+            WHERE {exercises}.search_vector @@ query.tsquery OR instruction_ranks.exercise_id IS NOT NULL \
+                OR $1 % {exercises}.name OR $1 <% {exercises}.name \
+                OR $1 % {exercises}.name_alternative OR $1 <% {exercises}.name_alternative \
+            ORDER BY ts_rank({exercises}.search_vector, query.tsquery) \
+                + COALESCE(instruction_ranks.rank, 0) * 0.25 \
+                + GREATEST( \
+                    similarity($1, {exercises}.name), word_similarity($1, {exercises}.name), \
+                    COALESCE(similarity($1, {exercises}.name_alternative), 0), \
+                    COALESCE(word_similarity($1, {exercises}.name_alternative), 0) \
+                ) DESC, {exercises}.name ASC \
+            LIMIT $2 OFFSET $3",
+            exercises = Self::TABLE_NAME,
+            instructions = ExerciseInstruction::TABLE_NAME,
+        ).as_str())
+            .bind(search::normalize_query(query))
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(database.connection())
+            .await?;
+
+        Ok(exercises)
     }
 
+    // endregion
+
     // region Relationships
 
     pub async fn equipment(&self, database: &DatabaseManager) -> Result<Option<ExerciseEquipment>> {
@@ -238,13 +361,14 @@ impl Exercise {
     }
 
     async fn muscles(&self, target: ExerciseMuscleTarget, database: &DatabaseManager) -> Result<Vec<Muscle>> {
-        let results = sqlx::query_as::<_, Muscle>(
-            "SELECT muscles.* FROM muscles INNER JOIN exercises_muscles ON muscles.id = exercises_muscles.muscle_id WHERE exercises_muscles.exercise_id = $1 AND exercises_muscles.target = $2",
-        )
-        .bind(self.id)
-        .bind(target)
-        .fetch_all(database.connection())
-        .await?;
+        let results = Muscle::query()
+            .select(&["muscles.*"])
+            .inner_join(ExerciseMuscleMap::TABLE_NAME, "exercises_muscles.muscle_id", "=", "muscles.id")
+            .and_where("exercises_muscles.exercise_id", "=", self.id)
+            .and_where("exercises_muscles.target", "=", target)
+            .order_by("exercises_muscles.id", true)
+            .all::<_, Muscle>(database.connection())
+            .await?;
 
         Ok(results)
     }
@@ -276,36 +400,112 @@ impl Exercise {
 
     // region Instance Methods
 
-    pub async fn save(&mut self, database: &DatabaseManager) -> Result<()> {
-        let model = sqlx::query_as::<_, Self>(format!(
-            "UPDATE {} SET (external_id, type, target_muscle_group_id, name, name_alternative, description, equipment_id, mechanic, force, measurement, updated_at) = ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) WHERE {} = {} RETURNING *",
-            Self::TABLE_NAME, Self::PRIMARY_KEY, &self.primary_key(),
+    /// Ranks other exercises by how well they can replace this one. Every
+    /// primary or secondary muscle both exercises share adds to the score:
+    /// 4 when it is primary to both, 1 when it is secondary to both and 2
+    /// otherwise. Sharing the target muscle group adds 2, and sharing the
+    /// mechanic or force adds 1 each. Only exercises that share at least
+    /// one muscle are returned, optionally limited to the given equipment.
+    pub async fn alternatives(
+        &self,
+        equipment_ids: Option<&[i16]>,
+        limit: i64,
+        offset: i64,
+        database: &DatabaseManager,
+    ) -> Result<Vec<ExerciseAlternative>> {
+        let alternatives = sqlx::query_as::<_, ExerciseAlternative>(format!(
+            "WITH source AS (SELECT muscle_id, target FROM {maps} WHERE exercise_id = $1 AND target IN ($2, $3)), \
+            overlap AS (SELECT candidate.exercise_id, SUM(CASE WHEN source.target = $2 AND candidate.target = $2 THEN 4 WHEN source.target = $3 AND candidate.target = $3 THEN 1 ELSE 2 END) AS score \
+            FROM {maps} AS candidate JOIN source ON source.muscle_id = candidate.muscle_id \
+            WHERE candidate.exercise_id <> $1 AND candidate.target IN ($2, $3) GROUP BY candidate.exercise_id) \
+            SELECT {exercises}.*, (overlap.score \
+            + CASE WHEN {exercises}.target_muscle_group_id = $4 THEN 2 ELSE 0 END \
+            + CASE WHEN {exercises}.mechanic = $5 THEN 1 ELSE 0 END \
+            + CASE WHEN {exercises}.force = $6 THEN 1 ELSE 0 END)::DOUBLE PRECISION AS score \
+            FROM {exercises} JOIN overlap ON overlap.exercise_id = {exercises}.id \
+            WHERE $7::SMALLINT[] IS NULL OR {exercises}.equipment_id = ANY($7) \
+            ORDER BY score DESC, {exercises}.name ASC LIMIT $8 OFFSET $9",
+            maps = ExerciseMuscleMap::TABLE_NAME,
+            exercises = Self::TABLE_NAME,
         ).as_str())
-            .bind(self.external_id)
-            .bind(self.exercise_type.clone())
+            .bind(self.id)
+            .bind(ExerciseMuscleTarget::Primary)
+            .bind(ExerciseMuscleTarget::Secondary)
             .bind(self.target_muscle_group_id)
-            .bind(self.name.clone())
-            .bind(self.name_alternative.clone())
-            .bind(self.description.clone())
-            .bind(self.equipment_id)
             .bind(self.mechanic.clone())
             .bind(self.force.clone())
-            .bind(self.measurement.clone())
-            .bind(chrono::Utc::now())
-            .fetch_one(database.connection())
+            .bind(equipment_ids)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(database.connection())
+            .await?;
+
+        Ok(alternatives)
+    }
+
+    /// Whether a routine or a logged workout includes the exercise. Deleting
+    /// it would also delete those entries.
+    pub async fn is_in_use(&self, mut database: impl Connection) -> Result<bool> {
+        for table in [RoutineExercise::TABLE_NAME, WorkoutExercise::TABLE_NAME] {
+            let in_use = SqlxQuery::table(table)
+                .select(&["id"])
+                .and_where("exercise_id", "=", self.id)
+                .exists(database.executor())
+                .await?;
+
+            if in_use {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Replaces the muscles the exercise targets with the given muscles.
+    pub async fn replace_muscles(
+        &self,
+        muscles: Vec<(i16, ExerciseMuscleTarget)>,
+        mut database: impl Connection,
+    ) -> Result<()> {
+        ExerciseMuscleMap::query()
+            .delete()
+            .and_where("exercise_id", "=", self.id)
+            .execute(database.executor())
+            .await?;
+
+        for (muscle_id, target) in muscles {
+            ExerciseMuscleMap::new()
+                .exercise_id(self.id)
+                .muscle_id(muscle_id)
+                .target(target)
+                .create(&mut database)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the instructions of the exercise with the given sequence
+    /// numbers and contents.
+    pub async fn replace_instructions(
+        &self,
+        instructions: Vec<(i16, String)>,
+        mut database: impl Connection,
+    ) -> Result<()> {
+        ExerciseInstruction::query()
+            .delete()
+            .and_where("exercise_id", "=", self.id)
+            .execute(database.executor())
             .await?;
 
-        self.external_id = model.external_id;
-        self.exercise_type = model.exercise_type;
-        self.target_muscle_group_id = model.target_muscle_group_id;
-        self.name = model.name;
-        self.name_alternative = model.name_alternative;
-        self.description = model.description;
-        self.equipment_id = model.equipment_id;
-        self.mechanic = model.mechanic;
-        self.force = model.force;
-        self.measurement = model.measurement;
-        self.updated_at = model.updated_at;
+        for (sequence_number, content) in instructions {
+            ExerciseInstruction::new()
+                .exercise_id(self.id)
+                .sequence_number(sequence_number)
+                .content(content)
+                .create(&mut database)
+                .await?;
+        }
 
         Ok(())
     }
@@ -317,8 +517,10 @@ impl Exercise {
 mod tests {
     use super::Exercise;
     use crate::enums::{ExerciseForce, ExerciseMechanic, ExerciseType, Measurement};
-    use crate::models::{ExerciseEquipment, MuscleGroup};
+    use crate::enums::ExerciseMuscleTarget;
+    use crate::models::{ExerciseEquipment, ExerciseMuscleMap, Muscle, MuscleGroup};
     use crate::prelude::*;
+    use database::Connection;
 
     #[sqlx::test]
     async fn create_exercise_success(pool: PgPool) -> Result<()> {
@@ -399,4 +601,36 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn lock_holds_off_new_references_until_the_transaction_ends(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let exercise = Exercise::mocked(&database).await?;
+        let muscle = Muscle::mocked(&database).await?;
+        let reference = || ExerciseMuscleMap::new()
+            .exercise_id(exercise.id)
+            .muscle_id(muscle.id)
+            .target(ExerciseMuscleTarget::Primary);
+
+        // Act
+        let mut transaction = database.transaction().await?;
+        exercise.lock(&mut transaction).await?;
+
+        let mut other = database.transaction().await?;
+        sqlx::query("SET LOCAL lock_timeout = '100ms'")
+            .execute(other.executor())
+            .await?;
+        let blocked = reference().create(&mut other).await;
+        other.rollback().await?;
+
+        transaction.rollback().await?;
+        let created = reference().create(&database).await;
+
+        // Assert
+        assert!(blocked.is_err());
+        assert!(created.is_ok());
+
+        Ok(())
+    }
 }
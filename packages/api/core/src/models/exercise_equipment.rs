@@ -1,20 +1,21 @@
-use super::{Error, Exercise, Result};
-use async_trait::async_trait;
-use database::{DatabaseManager, HasRouteKey, Model, SqlxAction};
+use super::{EquipmentProfile, Error, Exercise, Result};
+use database::{Connection, DatabaseManager, HasRouteKey, Model, SqlxAction, SqlxQuery};
 use sqlx::{FromRow, PgPool};
 
 #[cfg(test)]
 pub(crate) use builder::*;
 
-#[derive(Clone, Debug, FromRow)]
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "exercise_equipment", route_key = "id")]
 pub struct ExerciseEquipment {
+    #[model(generated)]
     pub id: i16,
     pub name: String,
 }
 
 mod builder {
     use super::{ExerciseEquipment, Result};
-    use database::{DatabaseManager, Model};
+    use database::{Connection, Model, SqlxAction};
 
     // region Type States
 
@@ -45,13 +46,12 @@ mod builder {
     }
 
     impl ExerciseEquipmentBuilder<Name> {
-        pub async fn create(self, database: &DatabaseManager) -> Result<ExerciseEquipment> {
-            let model = sqlx::query_as::<_, ExerciseEquipment>(format!(
-                "INSERT INTO {} (name) VALUES ($1) RETURNING *",
-                ExerciseEquipment::TABLE_NAME,
-            ).as_str())
-                .bind(self.name.0)
-                .fetch_one(database.connection())
+        pub async fn create(self, mut database: impl Connection) -> Result<ExerciseEquipment> {
+            let model = ExerciseEquipment::query()
+                .insert()
+                .value("name", self.name.0)
+                .returning(&["*"])
+                .one::<_, ExerciseEquipment>(database.executor())
                 .await?;
 
             Ok(model)
@@ -61,27 +61,10 @@ mod builder {
 
 use builder::*;
 
-#[async_trait]
-impl Model for ExerciseEquipment {
-    const MODEL_NAME: &'static str = "ExerciseEquipment";
-    const TABLE_NAME: &'static str = "exercise_equipment";
-
-    type PrimaryKey = i16;
-    fn primary_key(&self) -> Self::PrimaryKey {
-        self.id
-    }
-}
-
-impl HasRouteKey for ExerciseEquipment {
-    const ROUTE_KEY: &'static str = "id";
-    type RouteKey = i16;
-
-    fn route_key(&self) -> Self::RouteKey {
-        self.id
-    }
-}
-
 impl ExerciseEquipment {
+    /// Name of the equipment used by exercises that only require the body.
+    pub const BODYWEIGHT: &'static str = "bodyweight";
+
     pub fn new() -> ExerciseEquipmentBuilder<NoName> {
         ExerciseEquipmentBuilder::new()
     }
@@ -102,18 +85,26 @@ impl ExerciseEquipment {
 
     // region Instance Methods
 
-    pub async fn save(&mut self, database: &DatabaseManager) -> Result<()> {
-        let model = sqlx::query_as::<_, Self>(format!(
-            "UPDATE {} SET (name) = ($1) WHERE {} = {} RETURNING *",
-            Self::TABLE_NAME, Self::PRIMARY_KEY, &self.primary_key(),
-        ).as_str())
-            .bind(self.name.clone())
-            .fetch_one(database.connection())
+    /// Whether an exercise needs the equipment or an equipment profile
+    /// contains it. Deleting it would also delete those exercises.
+    pub async fn is_in_use(&self, mut database: impl Connection) -> Result<bool> {
+        let is_needed = Exercise::query()
+            .select(&["id"])
+            .and_where("equipment_id", "=", self.id)
+            .exists(database.executor())
             .await?;
 
-        self.name = model.name;
+        if is_needed {
+            return Ok(true);
+        }
 
-        Ok(())
+        let is_owned = SqlxQuery::table(EquipmentProfile::EQUIPMENT_TABLE_NAME)
+            .select(&["equipment_id"])
+            .and_where("equipment_id", "=", self.id)
+            .exists(database.executor())
+            .await?;
+
+        Ok(is_owned)
     }
 
     // endregion
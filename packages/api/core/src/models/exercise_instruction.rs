@@ -1,17 +1,18 @@
 use super::{Error, Exercise, Result};
 use crate::prelude::*;
 use crate::enums::{ExerciseForce, ExerciseMechanic, ExerciseMuscleTarget, ExerciseType};
-use async_trait::async_trait;
 use database::{DatabaseManager, HasRouteKey, Model};
 use sqlx::{postgres::PgPool, FromRow};
 
 #[cfg(test)]
 pub use builder::*;
 
-#[derive(Clone, Debug, FromRow)]
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "exercise_instructions", route_key = "id")]
 pub struct ExerciseInstruction {
     #[sqlx(skip)]
     database: Option<DatabaseManager>,
+    #[model(generated)]
     pub id: i16,
     pub exercise_id: i16,
     pub sequence_number: i16,
@@ -23,7 +24,7 @@ pub struct ExerciseInstruction {
 mod builder {
     use super::{ExerciseInstruction, Result};
     use crate::models::Exercise;
-    use database::{DatabaseManager, Model};
+    use database::{Connection, Model, SqlxAction};
 
     // region Type States
 
@@ -94,15 +95,14 @@ mod builder {
     }
 
     impl ExerciseInstructionBuilder<ExerciseId, SequenceNumber, Content> {
-        pub async fn create(self, database: &DatabaseManager) -> Result<ExerciseInstruction> {
-            let model = sqlx::query_as::<_, ExerciseInstruction>(format!(
-                "INSERT INTO {} (exercise_id, sequence_number, content) VALUES ($1, $2, $3) RETURNING *",
-                ExerciseInstruction::TABLE_NAME,
-            ).as_str())
-                .bind(self.exercise_id.0)
-                .bind(self.sequence_number.0)
-                .bind(self.content.0)
-                .fetch_one(database.connection())
+        pub async fn create(self, mut database: impl Connection) -> Result<ExerciseInstruction> {
+            let model = ExerciseInstruction::query()
+                .insert()
+                .value("exercise_id", self.exercise_id.0)
+                .value("sequence_number", self.sequence_number.0)
+                .value("content", self.content.0)
+                .returning(&["*"])
+                .one::<_, ExerciseInstruction>(database.executor())
                 .await?;
 
             Ok(model)
@@ -114,26 +114,6 @@ mod builder {
 
 use builder::*;
 
-#[async_trait]
-impl Model for ExerciseInstruction {
-    const MODEL_NAME: &'static str = "ExerciseInstruction";
-    const TABLE_NAME: &'static str = "exercise_instructions";
-
-    type PrimaryKey = i16;
-    fn primary_key(&self) -> Self::PrimaryKey {
-        self.id
-    }
-}
-
-impl HasRouteKey for ExerciseInstruction {
-    const ROUTE_KEY: &'static str = "id";
-    type RouteKey = i16;
-
-    fn route_key(&self) -> Self::RouteKey {
-        self.id
-    }
-}
-
 impl ExerciseInstruction {
     pub fn new() -> ExerciseInstructionBuilder<NoExerciseId, NoSequenceNumber, NoContent> {
         ExerciseInstructionBuilder::new()
@@ -151,26 +131,6 @@ impl ExerciseInstruction {
 
     // region Instance Methods
 
-    pub async fn save(&mut self, database: &DatabaseManager) -> Result<()> {
-        let model = sqlx::query_as::<_, Self>(format!(
-            "UPDATE {} SET (exercise_id, sequence_number, content, updated_at) = ($1, $2, $3, $4) WHERE {} = {} RETURNING *",
-            Self::TABLE_NAME, Self::PRIMARY_KEY, &self.primary_key(),
-        ).as_str())
-            .bind(self.exercise_id)
-            .bind(self.sequence_number)
-            .bind(self.content.clone())
-            .bind(chrono::Utc::now())
-            .fetch_one(database.connection())
-            .await?;
-
-        self.exercise_id = model.exercise_id;
-        self.sequence_number = model.sequence_number;
-        self.content = model.content;
-        self.updated_at = model.updated_at;
-
-        Ok(())
-    }
-
     // endregion
 }
 
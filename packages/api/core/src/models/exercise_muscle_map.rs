@@ -1,7 +1,6 @@
 use super::{Error, Exercise, Muscle, Result};
 use crate::prelude::*;
 use crate::enums::ExerciseMuscleTarget;
-use async_trait::async_trait;
 use database::{DatabaseManager, Model, SqlxAction};
 use serde::Deserialize;
 use sqlx::{FromRow, PgPool};
@@ -9,8 +8,10 @@ use sqlx::{FromRow, PgPool};
 #[cfg(test)]
 pub use builder::*;
 
-#[derive(Clone, Debug, FromRow)]
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "exercises_muscles")]
 pub struct ExerciseMuscleMap {
+    #[model(generated)]
     pub id: i16,
     pub exercise_id: i16,
     pub muscle_id: i16,
@@ -21,7 +22,7 @@ mod builder {
     use super::{ExerciseMuscleMap, Result};
     use crate::enums::ExerciseMuscleTarget;
     use crate::models::{Exercise, Muscle};
-    use database::{DatabaseManager, Model};
+    use database::{Connection, Model, SqlxAction};
 
     // region Type States
 
@@ -98,15 +99,14 @@ mod builder {
     }
 
     impl ExerciseMuscleMapBuilder<ExerciseId, MuscleId, Target> {
-        pub async fn create(self, database: &DatabaseManager) -> Result<ExerciseMuscleMap> {
-            let mut model = sqlx::query_as::<_, ExerciseMuscleMap>(format!(
-                "INSERT INTO {} (exercise_id, muscle_id, target) VALUES ($1, $2, $3) RETURNING *",
-                ExerciseMuscleMap::TABLE_NAME,
-            ).as_str())
-                .bind(self.exercise_id.0)
-                .bind(self.muscle_id.0)
-                .bind(self.target.0)
-                .fetch_one(database.connection())
+        pub async fn create(self, mut database: impl Connection) -> Result<ExerciseMuscleMap> {
+            let mut model = ExerciseMuscleMap::query()
+                .insert()
+                .value("exercise_id", self.exercise_id.0)
+                .value("muscle_id", self.muscle_id.0)
+                .value("target", self.target.0)
+                .returning(&["*"])
+                .one::<_, ExerciseMuscleMap>(database.executor())
                 .await?;
 
             Ok(model)
@@ -116,17 +116,6 @@ mod builder {
 
 use builder::*;
 
-#[async_trait]
-impl Model for ExerciseMuscleMap {
-    const MODEL_NAME: &'static str = "ExerciseMuscleMap";
-    const TABLE_NAME: &'static str = "exercises_muscles";
-
-    type PrimaryKey = i16;
-    fn primary_key(&self) -> Self::PrimaryKey {
-        self.id
-    }
-}
-
 impl ExerciseMuscleMap {
     pub fn new() -> ExerciseMuscleMapBuilder<NoExerciseId, NoMuscleId, NoTarget> {
         ExerciseMuscleMapBuilder::new()
@@ -149,24 +138,6 @@ impl ExerciseMuscleMap {
 
     // region Instance Methods
 
-    pub async fn save(&mut self, database: &DatabaseManager) -> Result<()> {
-        let model = sqlx::query_as::<_, Self>(format!(
-            "UPDATE {} SET (exercise_id, muscle_id, target) = ($1, $2, $3) WHERE {} = {} RETURNING *",
-            Self::TABLE_NAME, Self::PRIMARY_KEY, &self.primary_key(),
-        ).as_str())
-            .bind(self.exercise_id)
-            .bind(self.muscle_id)
-            .bind(self.target.clone())
-            .fetch_one(database.connection())
-            .await?;
-
-        self.exercise_id = model.exercise_id;
-        self.muscle_id = model.muscle_id;
-        self.target = model.target;
-
-        Ok(())
-    }
-
     // endregion
 }
 
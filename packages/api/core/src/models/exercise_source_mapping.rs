@@ -0,0 +1,210 @@
+use super::Result;
+use crate::prelude::*;
+use crate::enums::Table;
+use database::{Connection, DatabaseManager, Model, SqlxAction};
+use sqlx::{FromRow, PgPool};
+
+#[cfg(test)]
+pub use builder::*;
+
+/// Links the id an exercise source uses for a muscle, muscle group or piece
+/// of equipment to the id of the matching row in this database.
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "exercise_source_mappings")]
+pub struct ExerciseSourceMapping {
+    #[model(generated)]
+    pub id: i32,
+    pub source: String,
+    pub model_name: Table,
+    pub model_id: i16,
+    pub external_id: i16,
+    pub created_at: ISO8601DateTimeUTC,
+    pub updated_at: ISO8601DateTimeUTC,
+}
+
+mod builder {
+    use super::{ExerciseSourceMapping, Result};
+    use crate::enums::Table;
+    use database::{Connection, Model, SqlxAction};
+
+    // region Type States
+
+    #[derive(Default)]
+    pub struct NoSource;
+    #[derive(Default)]
+    pub struct Source(String);
+
+    #[derive(Default)]
+    pub struct NoModelData;
+    #[derive(Default)]
+    pub struct ModelData(Table, i16);
+
+    #[derive(Default)]
+    pub struct NoExternalId;
+    #[derive(Default)]
+    pub struct ExternalId(i16);
+
+    // endregion
+
+    #[derive(Default)]
+    pub struct ExerciseSourceMappingBuilder<S, M, E> {
+        source: S,
+        model: M,
+        external_id: E,
+    }
+
+    impl ExerciseSourceMappingBuilder<NoSource, NoModelData, NoExternalId> {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl<S, M, E> ExerciseSourceMappingBuilder<S, M, E> {
+        pub fn source(self, source: impl Into<String>) -> ExerciseSourceMappingBuilder<Source, M, E> {
+            ExerciseSourceMappingBuilder {
+                source: Source(source.into()),
+                model: self.model,
+                external_id: self.external_id,
+            }
+        }
+
+        pub fn model(self, model_name: Table, model_id: i16) -> ExerciseSourceMappingBuilder<S, ModelData, E> {
+            ExerciseSourceMappingBuilder {
+                source: self.source,
+                model: ModelData(model_name, model_id),
+                external_id: self.external_id,
+            }
+        }
+
+        pub fn external_id(self, id: i16) -> ExerciseSourceMappingBuilder<S, M, ExternalId> {
+            ExerciseSourceMappingBuilder {
+                source: self.source,
+                model: self.model,
+                external_id: ExternalId(id),
+            }
+        }
+    }
+
+    impl ExerciseSourceMappingBuilder<Source, ModelData, ExternalId> {
+        pub async fn create(self, mut database: impl Connection) -> Result<ExerciseSourceMapping> {
+            let model = ExerciseSourceMapping::query()
+                .insert()
+                .value("source", self.source.0)
+                .value("model_name", self.model.0)
+                .value("model_id", self.model.1)
+                .value("external_id", self.external_id.0)
+                .returning(&["*"])
+                .one::<_, ExerciseSourceMapping>(database.executor())
+                .await?;
+
+            Ok(model)
+        }
+    }
+}
+
+use builder::*;
+
+impl ExerciseSourceMapping {
+    pub fn new() -> ExerciseSourceMappingBuilder<NoSource, NoModelData, NoExternalId> {
+        ExerciseSourceMappingBuilder::new()
+    }
+
+    pub async fn for_source(source: &str, database: &DatabaseManager) -> Result<Vec<ExerciseSourceMapping>> {
+        let mappings = Self::query()
+            .select(&["*"])
+            .and_where("source", "=", source.to_string())
+            .all::<&PgPool, Self>(database.connection())
+            .await?;
+
+        Ok(mappings)
+    }
+
+    /// Deletes the mappings of a model that is being deleted, so that a
+    /// sync doesn't match the external ids to it anymore.
+    pub async fn delete_for_model(model_name: Table, model_id: i16, mut database: impl Connection) -> Result<()> {
+        Self::query()
+            .delete()
+            .and_where("model_name", "=", model_name)
+            .and_where("model_id", "=", model_id)
+            .execute(database.executor())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExerciseSourceMapping;
+    use crate::prelude::*;
+    use crate::enums::Table;
+    use crate::models::Muscle;
+
+    #[sqlx::test]
+    async fn create_exercise_source_mapping_success(pool: PgPool) -> Result<()> {
+        let database = DatabaseManager::from_pool(pool);
+        let muscle = Muscle::mocked(&database).await?;
+
+        let mapping = ExerciseSourceMapping::new()
+            .source("test")
+            .model(Table::Muscles, muscle.id)
+            .external_id(1)
+            .create(&database)
+            .await?;
+
+        assert_eq!("test", mapping.source);
+        assert_eq!(Table::Muscles, mapping.model_name);
+        assert_eq!(muscle.id, mapping.model_id);
+        assert_eq!(1, mapping.external_id);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn cannot_map_an_external_id_twice(pool: PgPool) -> Result<()> {
+        let database = DatabaseManager::from_pool(pool);
+        let first = Muscle::mocked(&database).await?;
+        let second = Muscle::mocked(&database).await?;
+
+        ExerciseSourceMapping::new()
+            .source("test")
+            .model(Table::Muscles, first.id)
+            .external_id(1)
+            .create(&database)
+            .await?;
+
+        let result = ExerciseSourceMapping::new()
+            .source("test")
+            .model(Table::Muscles, second.id)
+            .external_id(1)
+            .create(&database)
+            .await;
+
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn for_source_only_returns_that_sources_mappings(pool: PgPool) -> Result<()> {
+        let database = DatabaseManager::from_pool(pool);
+        let muscle = Muscle::mocked(&database).await?;
+
+        ExerciseSourceMapping::new()
+            .source("test")
+            .model(Table::Muscles, muscle.id)
+            .external_id(1)
+            .create(&database)
+            .await?;
+
+        let mappings = ExerciseSourceMapping::for_source("test", &database).await?;
+
+        assert_eq!(1, mappings.len());
+        assert_eq!(muscle.id, mappings[0].model_id);
+        assert!(ExerciseSourceMapping::for_source("musclewiki", &database).await?
+            .iter()
+            .all(|mapping| mapping.source == "musclewiki"));
+
+        Ok(())
+    }
+}
@@ -1,17 +1,19 @@
 use super::{Error, Result};
 use crate::prelude::*;
 use crate::enums::{LinkFormat, LinkType, Table};
-use async_trait::async_trait;
-use database::{DatabaseManager, HasRouteKey, Model};
+use database::{Connection, DatabaseManager, HasRouteKey, Model, SqlxAction};
 use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, PgPool};
 
 #[cfg(test)]
 pub use builder::*;
 
-#[derive(Clone, Debug, FromRow)]
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "links", route_key = "ulid")]
 pub struct Link {
+    #[model(generated)]
     pub id: i32,
+    #[model(generated)]
     pub ulid: String,
     pub model_name: Table,
     pub model_id: i16,
@@ -28,7 +30,7 @@ pub struct Link {
 mod builder {
     use super::{Link, Result};
     use crate::enums::{LinkFormat, LinkType, Table};
-    use database::{DatabaseManager, Model};
+    use database::{Connection, Model, SqlxAction};
 
     // region Builder type states
 
@@ -138,19 +140,18 @@ mod builder {
     }
 
     impl LinkBuilder<ModelData, Type, Format, Label, Src> {
-        pub async fn create(self, database: &DatabaseManager) -> Result<Link> {
-            let model = sqlx::query_as::<_, Link>(format!(
-                "INSERT INTO {} (model_name, model_id, type, format, label, description, src) VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING *",
-                Link::TABLE_NAME,
-            ).as_str())
-                .bind(self.model.0)
-                .bind(self.model.1)
-                .bind(self.link_type.0)
-                .bind(self.format.0)
-                .bind(self.label.0)
-                .bind(self.description)
-                .bind(self.src.0)
-                .fetch_one(database.connection())
+        pub async fn create(self, mut database: impl Connection) -> Result<Link> {
+            let model = Link::query()
+                .insert()
+                .value("model_name", self.model.0)
+                .value("model_id", self.model.1)
+                .value("type", self.link_type.0)
+                .value("format", self.format.0)
+                .value("label", self.label.0)
+                .value("description", self.description)
+                .value("src", self.src.0)
+                .returning(&["*"])
+                .one::<_, Link>(database.executor())
                 .await?;
 
             Ok(model)
@@ -160,31 +161,24 @@ mod builder {
 
 use builder::*;
 
-#[async_trait]
-impl Model for Link {
-    const MODEL_NAME: &'static str = "Link";
-    const TABLE_NAME: &'static str = "links";
-
-    type PrimaryKey = i32;
-    fn primary_key(&self) -> Self::PrimaryKey {
-        self.id
-    }
-}
-
-impl HasRouteKey for Link {
-    const ROUTE_KEY: &'static str = "ulid";
-    type RouteKey = String;
-
-    fn route_key(&self) -> Self::RouteKey {
-        self.ulid.clone()
-    }
-}
-
 impl Link {
     pub fn new() -> LinkBuilder<NoModelData, NoType, NoFormat, NoLabel, NoSrc> {
         LinkBuilder::new()
     }
 
+    /// Deletes the links of a model that is being deleted, as links aren't
+    /// removed along with their model by the database.
+    pub async fn delete_for_model(model_name: Table, model_id: i16, mut database: impl Connection) -> Result<()> {
+        Self::query()
+            .delete()
+            .and_where("model_name", "=", model_name)
+            .and_where("model_id", "=", model_id)
+            .execute(database.executor())
+            .await?;
+
+        Ok(())
+    }
+
     // region Relationships
 
     async fn model_links(id: i16, model_name: Table, database: &DatabaseManager) -> Result<Vec<Link>> {
@@ -206,32 +200,6 @@ impl Link {
 
     // region Instance Methods
 
-    pub async fn save(&mut self, database: &DatabaseManager) -> Result<()> {
-        let model = sqlx::query_as::<_, Self>(format!(
-            "UPDATE {} SET (model_name, model_id, type, format, label, description, src, updated_at) = ($1, $2, $3, $4, $5, $6, $7, $8) WHERE {} = {} RETURNING *",
-            Self::TABLE_NAME, Self::PRIMARY_KEY, &self.primary_key(),
-        ).as_str())
-            .bind(self.model_name.clone())
-            .bind(self.model_id.clone())
-            .bind(self.link_type.clone())
-            .bind(self.format.clone())
-            .bind(self.label.clone())
-            .bind(self.description.clone())
-            .bind(self.src.clone())
-            .bind(chrono::Utc::now())
-            .fetch_one(database.connection())
-            .await?;
-
-        self.link_type = model.link_type;
-        self.format = model.format;
-        self.label = model.label;
-        self.description = model.description;
-        self.src = model.src;
-        self.updated_at = model.updated_at;
-
-        Ok(())
-    }
-
     // endregion
 }
 
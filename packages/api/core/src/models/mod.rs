@@ -1,23 +1,49 @@
+pub mod coaching_relationship;
+pub mod equipment_profile;
 pub mod exercise;
 pub mod exercise_equipment;
 pub mod exercise_instruction;
 pub mod exercise_muscle_map;
+pub mod exercise_source_mapping;
 mod errors;
 pub mod link;
 pub mod muscle;
 pub mod muscle_group;
 pub mod user;
+pub mod personal_record;
 pub mod profile;
+pub mod program;
+pub mod program_day;
+pub mod program_enrollment;
+pub mod routine;
+pub mod routine_exercise;
+pub mod sync_run;
+pub mod workout;
+pub mod workout_exercise;
+pub mod workout_set;
 
-pub use exercise::Exercise;
+pub use coaching_relationship::CoachingRelationship;
+pub use equipment_profile::EquipmentProfile;
+pub use exercise::{Exercise, ExerciseAlternative, ExerciseFilter};
 pub use exercise_equipment::ExerciseEquipment;
 pub use exercise_instruction::ExerciseInstruction;
 pub use exercise_muscle_map::ExerciseMuscleMap;
+pub use exercise_source_mapping::ExerciseSourceMapping;
 pub use errors::Error;
 pub use link::Link;
 pub use muscle::Muscle;
 pub use muscle_group::MuscleGroup;
+pub use personal_record::PersonalRecord;
 pub use profile::Profile;
+pub use program::Program;
+pub use program_day::ProgramDay;
+pub use program_enrollment::ProgramEnrollment;
+pub use routine::Routine;
+pub use routine_exercise::RoutineExercise;
+pub use sync_run::SyncRun;
 pub use user::User;
+pub use workout::Workout;
+pub use workout_exercise::WorkoutExercise;
+pub use workout_set::{MuscleGroupVolume, MuscleVolume, WorkoutSet};
 
 pub(self) type Result<TValue> = ::core::result::Result<TValue, Error>;
@@ -1,16 +1,18 @@
-use super::{Error, Link, MuscleGroup, Result};
+use super::{Error, ExerciseMuscleMap, Link, MuscleGroup, Result};
 use crate::prelude::*;
-use async_trait::async_trait;
-use database::{DatabaseManager, HasRouteKey, Model};
+use database::{Connection, DatabaseManager, HasRouteKey, Model};
 use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, PgPool};
 
 #[cfg(test)]
 pub use builder::*;
 
-#[derive(Clone, Debug, FromRow)]
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "muscles", route_key = "ulid")]
 pub struct Muscle {
+    #[model(generated)]
     pub id: i16,
+    #[model(generated)]
     pub ulid: String,
     pub group_id: i16,
     pub parent_id: Option<i16>,
@@ -25,7 +27,7 @@ pub struct Muscle {
 mod builder {
     use super::{Muscle, Result};
     use crate::models::MuscleGroup;
-    use database::{DatabaseManager, Model};
+    use database::{Connection, Model, SqlxAction};
 
     // region Type States
 
@@ -118,18 +120,17 @@ mod builder {
     }
 
     impl MuscleBuilder<GroupId, Name> {
-        pub async fn create(self, database: &DatabaseManager) -> Result<Muscle> {
-            let mut model = sqlx::query_as::<_, Muscle>(format!(
-                "INSERT INTO {} (group_id, parent_id, name, simple_name, description, image_source) VALUES ($1, $2, $3, $4, $5, $6) RETURNING *",
-                Muscle::TABLE_NAME,
-            ).as_str())
-                .bind(self.group_id.0)
-                .bind(self.parent_id)
-                .bind(self.name.0)
-                .bind(self.simple_name)
-                .bind(self.description)
-                .bind(self.image_source)
-                .fetch_one(database.connection())
+        pub async fn create(self, mut database: impl Connection) -> Result<Muscle> {
+            let mut model = Muscle::query()
+                .insert()
+                .value("group_id", self.group_id.0)
+                .value("parent_id", self.parent_id)
+                .value("name", self.name.0)
+                .value("simple_name", self.simple_name)
+                .value("description", self.description)
+                .value("image_source", self.image_source)
+                .returning(&["*"])
+                .one::<_, Muscle>(database.executor())
                 .await?;
 
             Ok(model)
@@ -139,26 +140,6 @@ mod builder {
 
 use builder::*;
 
-#[async_trait]
-impl Model for Muscle {
-    const MODEL_NAME: &'static str = "Muscle";
-    const TABLE_NAME: &'static str = "muscles";
-
-    type PrimaryKey = i16;
-    fn primary_key(&self) -> Self::PrimaryKey {
-        self.id
-    }
-}
-
-impl HasRouteKey for Muscle {
-    const ROUTE_KEY: &'static str = "ulid";
-    type RouteKey = String;
-
-    fn route_key(&self) -> Self::RouteKey {
-        self.ulid.clone()
-    }
-}
-
 impl Muscle {
     pub fn new() -> MuscleBuilder<NoGroupId, NoName> {
         MuscleBuilder::new()
@@ -188,30 +169,26 @@ impl Muscle {
 
     // region Instance Methods
 
-    pub async fn save(&mut self, database: &DatabaseManager) -> Result<()> {
-        let model = sqlx::query_as::<_, Self>(format!(
-            "UPDATE {} SET (group_id, parent_id, name, simple_name, description, image_source, updated_at) = ($1, $2, $3, $4, $5, $6, $7) WHERE {} = {} RETURNING *",
-            Self::TABLE_NAME, Self::PRIMARY_KEY, &self.primary_key(),
-        ).as_str())
-            .bind(self.group_id)
-            .bind(self.parent_id)
-            .bind(self.name.clone())
-            .bind(self.simple_name.clone())
-            .bind(self.description.clone())
-            .bind(self.image_source.clone())
-            .bind(chrono::Utc::now())
-            .fetch_one(database.connection())
+    /// Whether an exercise targets the muscle or another muscle is nested
+    /// under it. Deleting it would also delete those.
+    pub async fn is_in_use(&self, mut database: impl Connection) -> Result<bool> {
+        let has_children = Self::query()
+            .select(&["id"])
+            .and_where("parent_id", "=", self.id)
+            .exists(database.executor())
             .await?;
 
-        self.group_id = model.group_id;
-        self.parent_id = model.parent_id;
-        self.name = model.name;
-        self.simple_name = model.simple_name;
-        self.description = model.description;
-        self.image_source = model.image_source;
-        self.updated_at = model.updated_at;
+        if has_children {
+            return Ok(true);
+        }
+
+        let is_targeted = ExerciseMuscleMap::query()
+            .select(&["id"])
+            .and_where("muscle_id", "=", self.id)
+            .exists(database.executor())
+            .await?;
 
-        Ok(())
+        Ok(is_targeted)
     }
 
     // endregion
@@ -1,14 +1,15 @@
-use super::{Error, Exercise, Link, Result};
+use super::{Error, Exercise, Link, Muscle, Result};
 use crate::prelude::*;
-use async_trait::async_trait;
-use database::{DatabaseManager, HasRouteKey, Model, SqlxAction};
+use database::{Connection, DatabaseManager, HasRouteKey, Model, SqlxAction};
 use sqlx::{FromRow, PgPool};
 
 #[cfg(test)]
 pub(crate) use builder::*;
 
-#[derive(Clone, Debug, FromRow)]
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "muscle_groups", route_key = "id")]
 pub struct MuscleGroup {
+    #[model(generated)]
     pub id: i16,
     pub name: String,
     pub image_source: Option<String>,
@@ -18,7 +19,7 @@ pub struct MuscleGroup {
 
 mod builder {
     use super::{Error, MuscleGroup, Result};
-    use database::{DatabaseManager, Model};
+    use database::{Connection, Model, SqlxAction};
 
     // region Type States
 
@@ -56,14 +57,13 @@ mod builder {
     }
 
     impl MuscleGroupBuilder<Name> {
-        pub async fn create(self, database: &DatabaseManager) -> Result<MuscleGroup> {
-            let model = sqlx::query_as::<_, MuscleGroup>(format!(
-                "INSERT INTO {} (name, image_source) VALUES ($1, $2) RETURNING *",
-                MuscleGroup::TABLE_NAME,
-            ).as_str())
-                .bind(self.name.0)
-                .bind(self.image_source)
-                .fetch_one(database.connection())
+        pub async fn create(self, mut database: impl Connection) -> Result<MuscleGroup> {
+            let model = MuscleGroup::query()
+                .insert()
+                .value("name", self.name.0)
+                .value("image_source", self.image_source)
+                .returning(&["*"])
+                .one::<_, MuscleGroup>(database.executor())
                 .await?;
 
             Ok(model)
@@ -73,26 +73,6 @@ mod builder {
 
 use builder::*;
 
-#[async_trait]
-impl Model for MuscleGroup {
-    const MODEL_NAME: &'static str = "MuscleGroup";
-    const TABLE_NAME: &'static str = "muscle_groups";
-
-    type PrimaryKey = i16;
-    fn primary_key(&self) -> Self::PrimaryKey {
-        self.id
-    }
-}
-
-impl HasRouteKey for MuscleGroup {
-    const ROUTE_KEY: &'static str = "id";
-    type RouteKey = i16;
-
-    fn route_key(&self) -> Self::RouteKey {
-        self.id
-    }
-}
-
 impl MuscleGroup {
     pub fn new() -> MuscleGroupBuilder<NoName> {
         MuscleGroupBuilder::new()
@@ -114,22 +94,26 @@ impl MuscleGroup {
 
     // region Instance Methods
 
-    pub async fn save(&mut self, database: &DatabaseManager) -> Result<()> {
-        let model = sqlx::query_as::<_, Self>(format!(
-            "UPDATE {} SET (name, image_source, updated_at) = ($1, $2, $3) WHERE {} = {} RETURNING *",
-            Self::TABLE_NAME, Self::PRIMARY_KEY, &self.primary_key(),
-        ).as_str())
-            .bind(self.name.clone())
-            .bind(self.image_source.clone())
-            .bind(chrono::Utc::now())
-            .fetch_one(database.connection())
+    /// Whether a muscle belongs to the group or an exercise targets it.
+    /// Deleting it would also delete those.
+    pub async fn is_in_use(&self, mut database: impl Connection) -> Result<bool> {
+        let has_muscles = Muscle::query()
+            .select(&["id"])
+            .and_where("group_id", "=", self.id)
+            .exists(database.executor())
             .await?;
 
-        self.name = model.name;
-        self.image_source = model.image_source;
-        self.updated_at = model.updated_at;
+        if has_muscles {
+            return Ok(true);
+        }
 
-        Ok(())
+        let is_targeted = Exercise::query()
+            .select(&["id"])
+            .and_where("target_muscle_group_id", "=", self.id)
+            .exists(database.executor())
+            .await?;
+
+        Ok(is_targeted)
     }
 
     // endregion
@@ -0,0 +1,398 @@
+use super::{Exercise, Result, User, WorkoutSet};
+use crate::prelude::*;
+use crate::enums::{Measurement, MeasurementDenominator, MeasurementOperation, OneRepMaxFormula, PersonalRecordType};
+use crate::utils::strength;
+use database::{Connection, DatabaseManager, HasRouteKey, Model};
+use sqlx::FromRow;
+
+#[cfg(test)]
+pub(crate) use builder::*;
+
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "personal_records", route_key = "ulid")]
+pub struct PersonalRecord {
+    #[model(generated)]
+    pub id: i32,
+    #[model(generated)]
+    pub ulid: String,
+    pub user_id: i16,
+    pub exercise_id: i16,
+    pub workout_set_id: i32,
+    #[sqlx(rename = "type")]
+    pub record_type: PersonalRecordType,
+    pub value: f64,
+    pub weight: Option<f64>,
+    pub achieved_at: ISO8601DateTimeUTC,
+    pub created_at: ISO8601DateTimeUTC,
+    pub updated_at: ISO8601DateTimeUTC,
+}
+
+mod builder {
+    use super::{Exercise, PersonalRecord, Result, User, WorkoutSet};
+    use crate::enums::PersonalRecordType;
+    use crate::prelude::ISO8601DateTimeUTC;
+    use database::{Connection, Model, SqlxAction};
+
+    // region Type States
+
+    #[derive(Default)]
+    pub struct NoUserId;
+    #[derive(Default)]
+    pub struct UserId(pub i16);
+
+    #[derive(Default)]
+    pub struct NoExerciseId;
+    #[derive(Default)]
+    pub struct ExerciseId(pub i16);
+
+    #[derive(Default)]
+    pub struct NoWorkoutSetId;
+    #[derive(Default)]
+    pub struct WorkoutSetId(pub i32);
+
+    #[derive(Default)]
+    pub struct NoRecord;
+    pub struct Record(pub PersonalRecordType, pub f64);
+
+    // endregion
+
+    // region Builder
+
+    #[derive(Default)]
+    pub struct PersonalRecordBuilder<U, E, S, R> {
+        user_id: U,
+        exercise_id: E,
+        workout_set_id: S,
+        record: R,
+        weight: Option<f64>,
+        achieved_at: Option<ISO8601DateTimeUTC>,
+    }
+
+    impl PersonalRecordBuilder<NoUserId, NoExerciseId, NoWorkoutSetId, NoRecord> {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl<U, E, S, R> PersonalRecordBuilder<U, E, S, R> {
+        pub fn user_id(self, id: i16) -> PersonalRecordBuilder<UserId, E, S, R> {
+            PersonalRecordBuilder {
+                user_id: UserId(id),
+                exercise_id: self.exercise_id,
+                workout_set_id: self.workout_set_id,
+                record: self.record,
+                weight: self.weight,
+                achieved_at: self.achieved_at,
+            }
+        }
+
+        pub fn user(self, user: &User) -> PersonalRecordBuilder<UserId, E, S, R> {
+            self.user_id(user.id)
+        }
+
+        pub fn exercise_id(self, id: i16) -> PersonalRecordBuilder<U, ExerciseId, S, R> {
+            PersonalRecordBuilder {
+                user_id: self.user_id,
+                exercise_id: ExerciseId(id),
+                workout_set_id: self.workout_set_id,
+                record: self.record,
+                weight: self.weight,
+                achieved_at: self.achieved_at,
+            }
+        }
+
+        pub fn exercise(self, exercise: &Exercise) -> PersonalRecordBuilder<U, ExerciseId, S, R> {
+            self.exercise_id(exercise.id)
+        }
+
+        /// Links the record to the set it was achieved in, using the time
+        /// the set was logged as the time the record was achieved.
+        pub fn workout_set(self, set: &WorkoutSet) -> PersonalRecordBuilder<U, E, WorkoutSetId, R> {
+            PersonalRecordBuilder {
+                user_id: self.user_id,
+                exercise_id: self.exercise_id,
+                workout_set_id: WorkoutSetId(set.id),
+                record: self.record,
+                weight: self.weight,
+                achieved_at: Some(set.created_at),
+            }
+        }
+
+        pub fn record(self, record_type: PersonalRecordType, value: f64) -> PersonalRecordBuilder<U, E, S, Record> {
+            PersonalRecordBuilder {
+                user_id: self.user_id,
+                exercise_id: self.exercise_id,
+                workout_set_id: self.workout_set_id,
+                record: Record(record_type, value),
+                weight: self.weight,
+                achieved_at: self.achieved_at,
+            }
+        }
+
+        pub fn weight(mut self, weight: Option<f64>) -> Self {
+            self.weight = weight;
+            self
+        }
+
+        pub fn achieved_at(mut self, achieved_at: ISO8601DateTimeUTC) -> Self {
+            self.achieved_at = Some(achieved_at);
+            self
+        }
+    }
+
+    impl PersonalRecordBuilder<UserId, ExerciseId, WorkoutSetId, Record> {
+        pub async fn create(self, mut database: impl Connection) -> Result<PersonalRecord> {
+            let mut query = PersonalRecord::query()
+                .insert()
+                .value("user_id", self.user_id.0)
+                .value("exercise_id", self.exercise_id.0)
+                .value("workout_set_id", self.workout_set_id.0)
+                .value("type", self.record.0)
+                .value("value", self.record.1)
+                .value("weight", self.weight);
+
+            if let Some(achieved_at) = self.achieved_at {
+                query = query.value("achieved_at", achieved_at);
+            }
+
+            let model = query
+                .returning(&["*"])
+                .one::<_, PersonalRecord>(database.executor())
+                .await?;
+
+            Ok(model)
+        }
+    }
+
+    // endregion
+}
+
+use builder::*;
+
+impl PersonalRecord {
+    // region Static Methods
+
+    pub fn new() -> PersonalRecordBuilder<NoUserId, NoExerciseId, NoWorkoutSetId, NoRecord> {
+        PersonalRecordBuilder::new()
+    }
+
+    /// Returns the records a set could set for an exercise with the given measurement,
+    /// as `(type, value, weight)`. Records that are compared at a specific weight
+    /// carry that weight, all other records carry `None`.
+    ///
+    /// Weighted measurements track the heaviest weight lifted and the best result
+    /// for their denominator at each weight. When the weight is multiplied by the
+    /// denominator, an estimated one rep max is tracked as well. Measurements that
+    /// are added together track each recorded quantity on its own.
+    pub fn candidates(measurement: Option<&Measurement>, set: &WorkoutSet) -> Vec<(PersonalRecordType, f64, Option<f64>)> {
+        let mut candidates = Vec::new();
+        let weight = set.weight.filter(|weight| *weight > 0.0);
+        let repetitions = set.repetitions.filter(|repetitions| *repetitions > 0);
+        let duration = set.duration.filter(|duration| *duration > 0);
+
+        if let Some(weight) = weight {
+            candidates.push((PersonalRecordType::HeaviestWeight, weight, None));
+        }
+
+        match measurement.and_then(|measurement| measurement.denominator()) {
+            Some(MeasurementDenominator::Repetition) => if let (Some(weight), Some(repetitions)) = (weight, repetitions) {
+                candidates.push((PersonalRecordType::MostRepetitions, repetitions as f64, Some(weight)));
+            },
+            Some(MeasurementDenominator::Second) => if let (Some(weight), Some(duration)) = (weight, duration) {
+                candidates.push((PersonalRecordType::LongestDuration, duration as f64, Some(weight)));
+            },
+            None => {
+                if let Some(repetitions) = repetitions {
+                    candidates.push((PersonalRecordType::MostRepetitions, repetitions as f64, set.weight));
+                }
+
+                if let Some(duration) = duration {
+                    candidates.push((PersonalRecordType::LongestDuration, duration as f64, set.weight));
+                }
+            },
+        }
+
+        let operation = measurement.map(|measurement| measurement.operation());
+
+        if let (Some(MeasurementOperation::Multiplication), Some(weight), Some(repetitions)) = (operation, weight, repetitions) {
+            if let Some(estimate) = strength::one_rep_max(weight, repetitions, &OneRepMaxFormula::Epley) {
+                candidates.push((PersonalRecordType::EstimatedOneRepMax, estimate, None));
+            }
+        }
+
+        candidates
+    }
+
+    /// Finds the best record of the given type for a user and exercise. Records
+    /// that are compared at a specific weight only match records at that weight.
+    pub async fn best(
+        user_id: i16,
+        exercise_id: i16,
+        record_type: &PersonalRecordType,
+        weight: Option<f64>,
+        mut database: impl Connection,
+    ) -> Result<Option<Self>> {
+        let record = sqlx::query_as::<_, Self>(format!(
+            "SELECT * FROM {} WHERE user_id = $1 AND exercise_id = $2 AND type = $3 AND weight IS NOT DISTINCT FROM $4 ORDER BY value DESC LIMIT 1",
+            Self::TABLE_NAME,
+        ).as_str())
+            .bind(user_id)
+            .bind(exercise_id)
+            .bind(record_type)
+            .bind(weight)
+            .fetch_optional(database.executor())
+            .await?;
+
+        Ok(record)
+    }
+
+    /// Returns the current records of a user, one per exercise, type and weight.
+    pub async fn current_for_user(user_id: i16, database: &DatabaseManager) -> Result<Vec<Self>> {
+        let records = sqlx::query_as::<_, Self>(format!(
+            "SELECT DISTINCT ON (exercise_id, type, weight) * FROM {} WHERE user_id = $1 ORDER BY exercise_id, type, weight, value DESC",
+            Self::TABLE_NAME,
+        ).as_str())
+            .bind(user_id)
+            .fetch_all(database.connection())
+            .await?;
+
+        Ok(records)
+    }
+
+    /// Returns every record a user has set for an exercise, oldest first.
+    pub async fn timeline(user_id: i16, exercise_id: i16, database: &DatabaseManager) -> Result<Vec<Self>> {
+        let records = sqlx::query_as::<_, Self>(format!(
+            "SELECT * FROM {} WHERE user_id = $1 AND exercise_id = $2 ORDER BY achieved_at ASC, id ASC",
+            Self::TABLE_NAME,
+        ).as_str())
+            .bind(user_id)
+            .bind(exercise_id)
+            .fetch_all(database.connection())
+            .await?;
+
+        Ok(records)
+    }
+
+    // endregion
+
+    // region Relationships
+
+    pub async fn user(&self, database: &DatabaseManager) -> Result<User> {
+        let user = User::find_by_pk(self.user_id, database).await?;
+
+        Ok(user)
+    }
+
+    pub async fn exercise(&self, database: &DatabaseManager) -> Result<Exercise> {
+        let exercise = Exercise::find_by_pk(self.exercise_id, database).await?;
+
+        Ok(exercise)
+    }
+
+    pub async fn workout_set(&self, database: &DatabaseManager) -> Result<WorkoutSet> {
+        let set = WorkoutSet::find_by_pk(self.workout_set_id, database).await?;
+
+        Ok(set)
+    }
+
+    // endregion
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PersonalRecord;
+    use crate::enums::{Measurement, PersonalRecordType};
+    use crate::models::{User, WorkoutSet};
+    use crate::prelude::*;
+
+    #[sqlx::test]
+    async fn create_personal_record_success(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let user = User::mocked(&database).await?;
+        let set = WorkoutSet::mocked(&database).await?;
+        let workout_exercise = set.workout_exercise(&database).await?;
+        let count = PersonalRecord::count(&database).await?;
+
+        // Act
+        let record = PersonalRecord::new()
+            .user(&user)
+            .exercise_id(workout_exercise.exercise_id)
+            .workout_set(&set)
+            .record(PersonalRecordType::MostRepetitions, 8.0)
+            .weight(Some(100.0))
+            .create(&database)
+            .await?;
+
+        // Assert
+        assert_eq!(PersonalRecordType::MostRepetitions, record.record_type);
+        assert_eq!(8.0, record.value);
+        assert_some_eq(100.0, record.weight);
+        assert_eq!(set.created_at, record.achieved_at);
+        assert_eq!(count + 1, PersonalRecord::count(&database).await?);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn candidates_follow_measurement(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let set = WorkoutSet::fake()
+            .weight(Some(100.0))
+            .repetitions(Some(5))
+            .create(&database)
+            .await?;
+
+        // Act
+        let weighted = PersonalRecord::candidates(Some(&Measurement::WeightedRepetitions), &set);
+        let repetitions = PersonalRecord::candidates(Some(&Measurement::Repetitions), &set);
+
+        // Assert
+        assert_eq!(vec![
+            (PersonalRecordType::HeaviestWeight, 100.0, None),
+            (PersonalRecordType::MostRepetitions, 5.0, Some(100.0)),
+            (PersonalRecordType::EstimatedOneRepMax, 116.67, None),
+        ], weighted);
+        assert!(repetitions.contains(&(PersonalRecordType::MostRepetitions, 5.0, Some(100.0))));
+        assert!(!repetitions.iter().any(|(record_type, _, _)| *record_type == PersonalRecordType::EstimatedOneRepMax));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn best_only_matches_same_weight(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let set = WorkoutSet::mocked(&database).await?;
+        let workout = set.workout_exercise(&database).await?.workout(&database).await?;
+        let exercise_id = set.workout_exercise(&database).await?.exercise_id;
+
+        for (weight, repetitions) in [(100.0, 5.0), (100.0, 6.0), (80.0, 10.0)] {
+            PersonalRecord::new()
+                .user_id(workout.user_id)
+                .exercise_id(exercise_id)
+                .workout_set(&set)
+                .record(PersonalRecordType::MostRepetitions, repetitions)
+                .weight(Some(weight))
+                .create(&database)
+                .await?;
+        }
+
+        // Act
+        let best = PersonalRecord::best(
+            workout.user_id,
+            exercise_id,
+            &PersonalRecordType::MostRepetitions,
+            Some(100.0),
+            &database,
+        ).await?;
+        let current = PersonalRecord::current_for_user(workout.user_id, &database).await?;
+
+        // Assert
+        assert_eq!(6.0, best.unwrap().value);
+        assert_eq!(2, current.len());
+
+        Ok(())
+    }
+}
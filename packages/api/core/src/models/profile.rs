@@ -1,7 +1,6 @@
 use super::{Error, Result, User};
 use crate::prelude::*;
 use crate::enums::Gender;
-use async_trait::async_trait;
 use chrono::NaiveDate;
 use database::{DatabaseManager, HasRouteKey, Model, SqlxAction};
 use serde::{Deserialize, Serialize};
@@ -10,13 +9,17 @@ use sqlx::{FromRow, PgPool};
 #[cfg(test)]
 pub(crate) use builder::*;
 
-#[derive(Clone, Debug, FromRow)]
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "user_profiles", route_key = "ulid")]
 pub struct Profile {
+    #[model(generated)]
     pub id: i16,
+    #[model(generated)]
     pub ulid: String,
     pub user_id: i16,
     pub birthday: NaiveDate,
     pub gender: Gender,
+    pub bodyweight: Option<f64>,
     pub created_at: ISO8601DateTimeUTC,
     pub updated_at: ISO8601DateTimeUTC,
 }
@@ -25,7 +28,7 @@ mod builder {
     use super::{Error, Profile, Result, User};
     use crate::enums::Gender;
     use chrono::NaiveDate;
-    use database::{DatabaseManager, Model};
+    use database::{Connection, Model, SqlxAction};
 
     // region Type States
 
@@ -51,6 +54,7 @@ mod builder {
         pub user_id: U,
         pub birthday: B,
         pub gender: G,
+        pub bodyweight: Option<f64>,
     }
 
     impl ProfileBuilder<NoUserId, NoBirthday, NoUserGender> {
@@ -65,6 +69,7 @@ mod builder {
                 user_id: UserId(id),
                 birthday: self.birthday,
                 gender: self.gender,
+                bodyweight: self.bodyweight,
             }
         }
 
@@ -73,6 +78,7 @@ mod builder {
                 user_id: UserId(user.id),
                 birthday: self.birthday,
                 gender: self.gender,
+                bodyweight: self.bodyweight,
             }
         }
     }
@@ -83,6 +89,7 @@ mod builder {
                 user_id: self.user_id,
                 birthday: Birthday(birthday),
                 gender: self.gender,
+                bodyweight: self.bodyweight,
             }
         }
     }
@@ -93,20 +100,28 @@ mod builder {
                 user_id: self.user_id,
                 birthday: self.birthday,
                 gender: UserGender(gender),
+                bodyweight: self.bodyweight,
             }
         }
     }
 
+    impl<U, B, G> ProfileBuilder<U, B, G> {
+        pub fn bodyweight(mut self, bodyweight: Option<f64>) -> Self {
+            self.bodyweight = bodyweight;
+            self
+        }
+    }
+
     impl ProfileBuilder<UserId, Birthday, UserGender> {
-        pub async fn create(self, database: &DatabaseManager) -> Result<Profile> {
-            let model = sqlx::query_as::<_, Profile>(format!(
-                "INSERT INTO {} (user_id, birthday, gender) VALUES ($1, $2, $3) RETURNING *",
-                Profile::TABLE_NAME,
-            ).as_str())
-                .bind(self.user_id.0)
-                .bind(self.birthday.0)
-                .bind(self.gender.0)
-                .fetch_one(database.connection())
+        pub async fn create(self, mut database: impl Connection) -> Result<Profile> {
+            let model = Profile::query()
+                .insert()
+                .value("user_id", self.user_id.0)
+                .value("birthday", self.birthday.0)
+                .value("gender", self.gender.0)
+                .value("bodyweight", self.bodyweight)
+                .returning(&["*"])
+                .one::<_, Profile>(database.executor())
                 .await?;
 
             Ok(model)
@@ -116,26 +131,6 @@ mod builder {
 
 use builder::*;
 
-#[async_trait]
-impl Model for Profile {
-    const MODEL_NAME: &'static str = "Profile";
-    const TABLE_NAME: &'static str = "user_profiles";
-
-    type PrimaryKey = i16;
-    fn primary_key(&self) -> Self::PrimaryKey {
-        self.id
-    }
-}
-
-impl HasRouteKey for Profile {
-    const ROUTE_KEY: &'static str = "ulid";
-    type RouteKey = String;
-
-    fn route_key(&self) -> Self::RouteKey {
-        self.ulid.clone()
-    }
-}
-
 impl Profile {
     // region Static Methods
 
@@ -157,22 +152,11 @@ impl Profile {
 
     // region Instance Methods
 
-    pub async fn save(&mut self, database: &DatabaseManager) -> Result<()> {
-        let model = sqlx::query_as::<_, Self>(format!(
-            "UPDATE {} SET (birthday, gender, updated_at) = ($1, $2, $3) WHERE {} = {} RETURNING *",
-            Self::TABLE_NAME, Self::PRIMARY_KEY, &self.primary_key(),
-        ).as_str())
-            .bind(self.birthday)
-            .bind(self.gender.clone())
-            .bind(chrono::Utc::now())
-            .fetch_one(database.connection())
-            .await?;
-
-        self.birthday = model.birthday;
-        self.gender = model.gender;
-        self.updated_at = model.updated_at;
-
-        Ok(())
+    /// Returns the age in full years on the given date.
+    pub fn age_on(&self, date: NaiveDate) -> i32 {
+        date.years_since(self.birthday)
+            .map(|years| years as i32)
+            .unwrap_or(0)
     }
 
     // endregion
@@ -233,11 +217,13 @@ mod tests {
         let birthday = chrono::NaiveDate::from_ymd_opt(1999, 10, 03).unwrap();
         profile.birthday = birthday;
         profile.gender = Gender::NonBinary;
+        profile.bodyweight = Some(82.5);
 
         profile.save(&database).await?;
 
         assert_eq!(birthday, profile.birthday);
         assert_eq!(Gender::NonBinary, profile.gender);
+        assert_some_eq(82.5, profile.bodyweight);
 
         Ok(())
     }
@@ -260,4 +246,22 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn age_on_counts_full_years(pool: PgPool) -> Result<()> {
+        let database = DatabaseManager::from_pool(pool);
+        let user = User::mocked(&database).await?;
+
+        let profile = Profile::new()
+            .user(&user)
+            .birthday(chrono::NaiveDate::from_ymd_opt(1990, 6, 15).unwrap())
+            .gender(Gender::Other)
+            .create(&database)
+            .await?;
+
+        assert_eq!(32, profile.age_on(chrono::NaiveDate::from_ymd_opt(2023, 6, 14).unwrap()));
+        assert_eq!(33, profile.age_on(chrono::NaiveDate::from_ymd_opt(2023, 6, 15).unwrap()));
+
+        Ok(())
+    }
 }
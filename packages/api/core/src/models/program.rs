@@ -0,0 +1,279 @@
+use super::{ProgramDay, Result, User};
+use crate::prelude::*;
+use crate::enums::ExerciseMechanic;
+use database::{DatabaseManager, HasRouteKey, Model, SqlxAction};
+use sqlx::FromRow;
+
+#[cfg(test)]
+pub(crate) use builder::*;
+
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "programs", route_key = "ulid")]
+pub struct Program {
+    #[model(generated)]
+    pub id: i32,
+    #[model(generated)]
+    pub ulid: String,
+    pub user_id: i16,
+    pub name: String,
+    pub description: Option<String>,
+    pub weeks: i16,
+    pub weekly_increment: f64,
+    pub deload_every: Option<i16>,
+    pub deload_factor: f64,
+    pub created_at: ISO8601DateTimeUTC,
+    pub updated_at: ISO8601DateTimeUTC,
+}
+
+mod builder {
+    use super::{Program, Result, User};
+    use database::{Connection, Model, SqlxAction};
+
+    // region Type States
+
+    #[derive(Default)]
+    pub struct NoUserId;
+    #[derive(Default)]
+    pub struct UserId(pub i16);
+
+    #[derive(Default)]
+    pub struct NoName;
+    #[derive(Default)]
+    pub struct Name(pub String);
+
+    #[derive(Default)]
+    pub struct NoWeeks;
+    #[derive(Default)]
+    pub struct Weeks(pub i16);
+
+    // endregion
+
+    // region Builder
+
+    #[derive(Default)]
+    pub struct ProgramBuilder<U, N, W> {
+        user_id: U,
+        name: N,
+        weeks: W,
+        description: Option<String>,
+        weekly_increment: Option<f64>,
+        deload_every: Option<i16>,
+        deload_factor: Option<f64>,
+    }
+
+    impl ProgramBuilder<NoUserId, NoName, NoWeeks> {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl<U, N, W> ProgramBuilder<U, N, W> {
+        pub fn user_id(self, id: i16) -> ProgramBuilder<UserId, N, W> {
+            ProgramBuilder {
+                user_id: UserId(id),
+                name: self.name,
+                weeks: self.weeks,
+                description: self.description,
+                weekly_increment: self.weekly_increment,
+                deload_every: self.deload_every,
+                deload_factor: self.deload_factor,
+            }
+        }
+
+        pub fn user(self, user: &User) -> ProgramBuilder<UserId, N, W> {
+            self.user_id(user.id)
+        }
+
+        pub fn name(self, name: impl Into<String>) -> ProgramBuilder<U, Name, W> {
+            ProgramBuilder {
+                user_id: self.user_id,
+                name: Name(name.into()),
+                weeks: self.weeks,
+                description: self.description,
+                weekly_increment: self.weekly_increment,
+                deload_every: self.deload_every,
+                deload_factor: self.deload_factor,
+            }
+        }
+
+        pub fn weeks(self, weeks: i16) -> ProgramBuilder<U, N, Weeks> {
+            ProgramBuilder {
+                user_id: self.user_id,
+                name: self.name,
+                weeks: Weeks(weeks),
+                description: self.description,
+                weekly_increment: self.weekly_increment,
+                deload_every: self.deload_every,
+                deload_factor: self.deload_factor,
+            }
+        }
+
+        pub fn description(mut self, description: Option<String>) -> Self {
+            self.description = description;
+            self
+        }
+
+        pub fn weekly_increment(mut self, increment: Option<f64>) -> Self {
+            self.weekly_increment = increment;
+            self
+        }
+
+        pub fn deload_every(mut self, weeks: Option<i16>) -> Self {
+            self.deload_every = weeks;
+            self
+        }
+
+        pub fn deload_factor(mut self, factor: Option<f64>) -> Self {
+            self.deload_factor = factor;
+            self
+        }
+    }
+
+    impl ProgramBuilder<UserId, Name, Weeks> {
+        pub async fn create(self, mut database: impl Connection) -> Result<Program> {
+            let mut query = Program::query()
+                .insert()
+                .value("user_id", self.user_id.0)
+                .value("name", self.name.0)
+                .value("weeks", self.weeks.0)
+                .value("description", self.description)
+                .value("deload_every", self.deload_every);
+
+            if let Some(weekly_increment) = self.weekly_increment {
+                query = query.value("weekly_increment", weekly_increment);
+            }
+
+            if let Some(deload_factor) = self.deload_factor {
+                query = query.value("deload_factor", deload_factor);
+            }
+
+            let model = query
+                .returning(&["*"])
+                .one::<_, Program>(database.executor())
+                .await?;
+
+            Ok(model)
+        }
+    }
+
+    // endregion
+}
+
+use builder::*;
+
+impl Program {
+    pub fn new() -> ProgramBuilder<NoUserId, NoName, NoWeeks> {
+        ProgramBuilder::new()
+    }
+
+    // region Relationships
+
+    pub async fn user(&self, database: &DatabaseManager) -> Result<User> {
+        let user = User::find_by_pk(self.user_id, database).await?;
+
+        Ok(user)
+    }
+
+    pub async fn days(&self, database: &DatabaseManager) -> Result<Vec<ProgramDay>> {
+        let days = ProgramDay::query()
+            .select(&["*"])
+            .and_where("program_id", "=", self.id)
+            .order_by("week_number", true)
+            .order_by("day_number", true)
+            .all(database.connection())
+            .await?;
+
+        Ok(days)
+    }
+
+    // endregion
+
+    // region Instance Methods
+
+    pub fn is_deload_week(&self, week: i16) -> bool {
+        match self.deload_every {
+            Some(every) => week % every == 0,
+            None => false,
+        }
+    }
+
+    /// Calculates the working load for a given week of the program.
+    ///
+    /// Compound lifts progress by `weekly_increment` every week after the first,
+    /// and every load is scaled down by `deload_factor` during a deload week.
+    pub fn target_weight(&self, base: Option<f64>, mechanic: Option<&ExerciseMechanic>, week: i16) -> Option<f64> {
+        let mut load = base?;
+
+        if let Some(ExerciseMechanic::Compound) = mechanic {
+            load += self.weekly_increment * f64::from(week - 1);
+        }
+
+        if self.is_deload_week(week) {
+            load *= self.deload_factor;
+        }
+
+        Some((load * 100.0).round() / 100.0)
+    }
+
+    // endregion
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Program;
+    use crate::enums::ExerciseMechanic;
+    use crate::models::User;
+    use crate::prelude::*;
+
+    #[sqlx::test]
+    async fn create_program_success(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let user = User::mocked(&database).await?;
+        let count = Program::count(&database).await?;
+
+        // Act
+        let program = Program::new()
+            .user(&user)
+            .name("5/3/1")
+            .weeks(4)
+            .deload_every(Some(4))
+            .create(&database)
+            .await?;
+
+        // Assert
+        assert_eq!(user.id, program.user_id);
+        assert_eq!("5/3/1", program.name);
+        assert_eq!(4, program.weeks);
+        assert_eq!(2.5, program.weekly_increment);
+        assert_some_eq(4i16, program.deload_every);
+        assert_eq!(0.6, program.deload_factor);
+        assert_eq!(count + 1, Program::count(&database).await?);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn target_weight_progresses_compound_lifts(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let program = Program::fake()
+            .weeks(6)
+            .deload_every(Some(4))
+            .create(&database)
+            .await?;
+        let compound = Some(&ExerciseMechanic::Compound);
+        let isolation = Some(&ExerciseMechanic::Isolation);
+
+        // Assert
+        assert_some_eq(100.0, program.target_weight(Some(100.0), compound, 1));
+        assert_some_eq(105.0, program.target_weight(Some(100.0), compound, 3));
+        assert_some_eq(64.5, program.target_weight(Some(100.0), compound, 4));
+        assert_some_eq(110.0, program.target_weight(Some(100.0), compound, 5));
+        assert_some_eq(20.0, program.target_weight(Some(20.0), isolation, 3));
+        assert_some_eq(12.0, program.target_weight(Some(20.0), isolation, 4));
+        assert!(program.target_weight(None, compound, 2).is_none());
+
+        Ok(())
+    }
+}
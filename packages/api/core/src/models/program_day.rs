@@ -0,0 +1,186 @@
+use super::{Program, Result, Routine};
+use crate::prelude::*;
+use database::{Connection, DatabaseManager, HasRouteKey, Model};
+use sqlx::FromRow;
+
+#[cfg(test)]
+pub(crate) use builder::*;
+
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "program_days", route_key = "ulid")]
+pub struct ProgramDay {
+    #[model(generated)]
+    pub id: i32,
+    #[model(generated)]
+    pub ulid: String,
+    pub program_id: i32,
+    pub routine_id: i32,
+    pub week_number: i16,
+    pub day_number: i16,
+    pub created_at: ISO8601DateTimeUTC,
+    pub updated_at: ISO8601DateTimeUTC,
+}
+
+mod builder {
+    use super::{Program, ProgramDay, Result, Routine};
+    use database::{Connection, Model, SqlxAction};
+
+    // region Type States
+
+    #[derive(Default)]
+    pub struct NoProgramId;
+    #[derive(Default)]
+    pub struct ProgramId(pub i32);
+
+    #[derive(Default)]
+    pub struct NoRoutineId;
+    #[derive(Default)]
+    pub struct RoutineId(pub i32);
+
+    #[derive(Default)]
+    pub struct NoSchedule;
+    #[derive(Default)]
+    pub struct Schedule(pub i16, pub i16);
+
+    // endregion
+
+    // region Builder
+
+    #[derive(Default)]
+    pub struct ProgramDayBuilder<P, R, S> {
+        program_id: P,
+        routine_id: R,
+        schedule: S,
+    }
+
+    impl ProgramDayBuilder<NoProgramId, NoRoutineId, NoSchedule> {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl<P, R, S> ProgramDayBuilder<P, R, S> {
+        pub fn program_id(self, id: i32) -> ProgramDayBuilder<ProgramId, R, S> {
+            ProgramDayBuilder {
+                program_id: ProgramId(id),
+                routine_id: self.routine_id,
+                schedule: self.schedule,
+            }
+        }
+
+        pub fn program(self, program: &Program) -> ProgramDayBuilder<ProgramId, R, S> {
+            self.program_id(program.id)
+        }
+
+        pub fn routine_id(self, id: i32) -> ProgramDayBuilder<P, RoutineId, S> {
+            ProgramDayBuilder {
+                program_id: self.program_id,
+                routine_id: RoutineId(id),
+                schedule: self.schedule,
+            }
+        }
+
+        pub fn routine(self, routine: &Routine) -> ProgramDayBuilder<P, RoutineId, S> {
+            self.routine_id(routine.id)
+        }
+
+        pub fn schedule(self, week_number: i16, day_number: i16) -> ProgramDayBuilder<P, R, Schedule> {
+            ProgramDayBuilder {
+                program_id: self.program_id,
+                routine_id: self.routine_id,
+                schedule: Schedule(week_number, day_number),
+            }
+        }
+    }
+
+    impl ProgramDayBuilder<ProgramId, RoutineId, Schedule> {
+        pub async fn create(self, mut database: impl Connection) -> Result<ProgramDay> {
+            let model = ProgramDay::query()
+                .insert()
+                .value("program_id", self.program_id.0)
+                .value("routine_id", self.routine_id.0)
+                .value("week_number", self.schedule.0)
+                .value("day_number", self.schedule.1)
+                .returning(&["*"])
+                .one::<_, ProgramDay>(database.executor())
+                .await?;
+
+            Ok(model)
+        }
+    }
+
+    // endregion
+}
+
+use builder::*;
+
+impl ProgramDay {
+    pub fn new() -> ProgramDayBuilder<NoProgramId, NoRoutineId, NoSchedule> {
+        ProgramDayBuilder::new()
+    }
+
+    // region Relationships
+
+    pub async fn program(&self, database: &DatabaseManager) -> Result<Program> {
+        let program = Program::find_by_pk(self.program_id, database).await?;
+
+        Ok(program)
+    }
+
+    pub async fn routine(&self, database: impl Connection) -> Result<Routine> {
+        let routine = Routine::find_by_pk(self.routine_id, database).await?;
+
+        Ok(routine)
+    }
+
+    // endregion
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProgramDay;
+    use crate::models::{Program, Routine};
+    use crate::prelude::*;
+
+    #[sqlx::test]
+    async fn create_program_day_success(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let program = Program::mocked(&database).await?;
+        let routine = Routine::mocked(&database).await?;
+
+        // Act
+        let day = ProgramDay::new()
+            .program(&program)
+            .routine(&routine)
+            .schedule(1, 3)
+            .create(&database)
+            .await?;
+
+        // Assert
+        assert_eq!(program.id, day.program_id);
+        assert_eq!(routine.id, day.routine_id);
+        assert_eq!(1, day.week_number);
+        assert_eq!(3, day.day_number);
+        assert_eq!(1, program.days(&database).await?.len());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn cannot_create_program_day_outside_of_week(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+
+        // Act
+        let result = ProgramDay::fake()
+            .schedule(1, 8)
+            .create(&database)
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
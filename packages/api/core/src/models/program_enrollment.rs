@@ -0,0 +1,311 @@
+use super::{Program, ProgramDay, Result, User, Workout};
+use crate::prelude::*;
+use chrono::NaiveDate;
+use database::{Connection, DatabaseManager, HasRouteKey, Model, SqlxAction};
+use sqlx::FromRow;
+
+#[cfg(test)]
+pub(crate) use builder::*;
+
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "program_enrollments", route_key = "ulid")]
+pub struct ProgramEnrollment {
+    #[model(generated)]
+    pub id: i32,
+    #[model(generated)]
+    pub ulid: String,
+    pub program_id: i32,
+    pub user_id: i16,
+    pub started_on: NaiveDate,
+    pub ended_at: Option<ISO8601DateTimeUTC>,
+    pub created_at: ISO8601DateTimeUTC,
+    pub updated_at: ISO8601DateTimeUTC,
+}
+
+mod builder {
+    use super::{Program, ProgramEnrollment, Result, User};
+    use chrono::NaiveDate;
+    use database::{Connection, Model, SqlxAction};
+
+    // region Type States
+
+    #[derive(Default)]
+    pub struct NoProgramId;
+    #[derive(Default)]
+    pub struct ProgramId(pub i32);
+
+    #[derive(Default)]
+    pub struct NoUserId;
+    #[derive(Default)]
+    pub struct UserId(pub i16);
+
+    #[derive(Default)]
+    pub struct NoStartedOn;
+    #[derive(Default)]
+    pub struct StartedOn(pub NaiveDate);
+
+    // endregion
+
+    // region Builder
+
+    #[derive(Default)]
+    pub struct ProgramEnrollmentBuilder<P, U, S> {
+        program_id: P,
+        user_id: U,
+        started_on: S,
+    }
+
+    impl ProgramEnrollmentBuilder<NoProgramId, NoUserId, NoStartedOn> {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl<P, U, S> ProgramEnrollmentBuilder<P, U, S> {
+        pub fn program_id(self, id: i32) -> ProgramEnrollmentBuilder<ProgramId, U, S> {
+            ProgramEnrollmentBuilder {
+                program_id: ProgramId(id),
+                user_id: self.user_id,
+                started_on: self.started_on,
+            }
+        }
+
+        pub fn program(self, program: &Program) -> ProgramEnrollmentBuilder<ProgramId, U, S> {
+            self.program_id(program.id)
+        }
+
+        pub fn user_id(self, id: i16) -> ProgramEnrollmentBuilder<P, UserId, S> {
+            ProgramEnrollmentBuilder {
+                program_id: self.program_id,
+                user_id: UserId(id),
+                started_on: self.started_on,
+            }
+        }
+
+        pub fn user(self, user: &User) -> ProgramEnrollmentBuilder<P, UserId, S> {
+            self.user_id(user.id)
+        }
+
+        pub fn started_on(self, date: NaiveDate) -> ProgramEnrollmentBuilder<P, U, StartedOn> {
+            ProgramEnrollmentBuilder {
+                program_id: self.program_id,
+                user_id: self.user_id,
+                started_on: StartedOn(date),
+            }
+        }
+    }
+
+    impl ProgramEnrollmentBuilder<ProgramId, UserId, StartedOn> {
+        pub async fn create(self, mut database: impl Connection) -> Result<ProgramEnrollment> {
+            let model = ProgramEnrollment::query()
+                .insert()
+                .value("program_id", self.program_id.0)
+                .value("user_id", self.user_id.0)
+                .value("started_on", self.started_on.0)
+                .returning(&["*"])
+                .one::<_, ProgramEnrollment>(database.executor())
+                .await?;
+
+            Ok(model)
+        }
+    }
+
+    // endregion
+}
+
+use builder::*;
+
+impl ProgramEnrollment {
+    // region Static Methods
+
+    pub fn new() -> ProgramEnrollmentBuilder<NoProgramId, NoUserId, NoStartedOn> {
+        ProgramEnrollmentBuilder::new()
+    }
+
+    pub async fn find_active(program_id: i32, user_id: i16, database: &DatabaseManager) -> Result<Option<Self>> {
+        let enrollment = sqlx::query_as::<_, Self>(format!(
+            "SELECT * FROM {} WHERE program_id = $1 AND user_id = $2 AND ended_at IS NULL",
+            Self::TABLE_NAME,
+        ).as_str())
+            .bind(program_id)
+            .bind(user_id)
+            .fetch_optional(database.connection())
+            .await?;
+
+        Ok(enrollment)
+    }
+
+    // endregion
+
+    // region Relationships
+
+    pub async fn program(&self, database: impl Connection) -> Result<Program> {
+        let program = Program::find_by_pk(self.program_id, database).await?;
+
+        Ok(program)
+    }
+
+    pub async fn user(&self, database: &DatabaseManager) -> Result<User> {
+        let user = User::find_by_pk(self.user_id, database).await?;
+
+        Ok(user)
+    }
+
+    // endregion
+
+    // region Instance Methods
+
+    /// Returns the `(week_number, day_number)` of the program on the given date,
+    /// or `None` if the enrollment has not started yet.
+    pub fn position_on(&self, date: NaiveDate) -> Option<(i16, i16)> {
+        let days = (date - self.started_on).num_days();
+
+        if days < 0 {
+            return None;
+        }
+
+        Some(((days / 7 + 1) as i16, (days % 7 + 1) as i16))
+    }
+
+    /// Finds the earliest day of the current week, up to and including the given
+    /// date, that does not have a finished workout logged against this enrollment.
+    pub async fn next_day(&self, program: &Program, date: NaiveDate, mut database: impl Connection) -> Result<Option<ProgramDay>> {
+        let (week_number, day_number) = match self.position_on(date) {
+            Some(position) => position,
+            None => return Ok(None),
+        };
+
+        if week_number > program.weeks {
+            return Ok(None);
+        }
+
+        let day = sqlx::query_as::<_, ProgramDay>(format!(
+            "SELECT * FROM {days} WHERE program_id = $1 AND week_number = $2 AND day_number <= $3 AND NOT EXISTS (SELECT 1 FROM {workouts} WHERE {workouts}.program_enrollment_id = $4 AND {workouts}.program_day_id = {days}.id AND {workouts}.finished_at IS NOT NULL) ORDER BY day_number ASC LIMIT 1",
+            days = ProgramDay::TABLE_NAME,
+            workouts = Workout::TABLE_NAME,
+        ).as_str())
+            .bind(self.program_id)
+            .bind(week_number)
+            .bind(day_number)
+            .bind(self.id)
+            .fetch_optional(database.executor())
+            .await?;
+
+        Ok(day)
+    }
+
+    pub async fn end(&mut self, database: &DatabaseManager) -> Result<()> {
+        let model = Self::query()
+            .update()
+            .set("ended_at", chrono::Utc::now())
+            .set("updated_at", chrono::Utc::now())
+            .and_where(Self::PRIMARY_KEY, "=", self.primary_key())
+            .returning(&["*"])
+            .one::<_, Self>(database.connection())
+            .await?;
+
+        self.ended_at = model.ended_at;
+        self.updated_at = model.updated_at;
+
+        Ok(())
+    }
+
+    // endregion
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProgramEnrollment;
+    use crate::models::{Program, ProgramDay, Routine, Workout};
+    use crate::prelude::*;
+    use chrono::{Duration, NaiveDate};
+
+    #[sqlx::test]
+    async fn cannot_enroll_twice_in_the_same_program(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let enrollment = ProgramEnrollment::mocked(&database).await?;
+
+        // Act
+        let result = ProgramEnrollment::new()
+            .program_id(enrollment.program_id)
+            .user_id(enrollment.user_id)
+            .started_on(enrollment.started_on)
+            .create(&database)
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn position_on_counts_weeks_and_days(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let started_on = NaiveDate::from_ymd_opt(2023, 11, 6).unwrap();
+        let enrollment = ProgramEnrollment::fake()
+            .started_on(started_on)
+            .create(&database)
+            .await?;
+
+        // Assert
+        assert!(enrollment.position_on(started_on - Duration::days(1)).is_none());
+        assert_some_eq((1, 1), enrollment.position_on(started_on));
+        assert_some_eq((1, 7), enrollment.position_on(started_on + Duration::days(6)));
+        assert_some_eq((2, 1), enrollment.position_on(started_on + Duration::days(7)));
+        assert_some_eq((3, 4), enrollment.position_on(started_on + Duration::days(17)));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn next_day_skips_completed_days(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let program = Program::mocked(&database).await?;
+        let routine = Routine::fake()
+            .user_id(program.user_id)
+            .create(&database)
+            .await?;
+        let monday = ProgramDay::new()
+            .program(&program)
+            .routine(&routine)
+            .schedule(1, 1)
+            .create(&database)
+            .await?;
+        let wednesday = ProgramDay::new()
+            .program(&program)
+            .routine(&routine)
+            .schedule(1, 3)
+            .create(&database)
+            .await?;
+        let started_on = NaiveDate::from_ymd_opt(2023, 11, 6).unwrap();
+        let enrollment = ProgramEnrollment::fake()
+            .program(&program)
+            .started_on(started_on)
+            .create(&database)
+            .await?;
+        let thursday = started_on + Duration::days(3);
+
+        // Act
+        let first = enrollment.next_day(&program, thursday, &database).await?;
+
+        let mut workout = Workout::new()
+            .user_id(enrollment.user_id)
+            .program_day(&enrollment, &monday)
+            .create(&database)
+            .await?;
+        workout.finish(&database).await?;
+
+        let second = enrollment.next_day(&program, thursday, &database).await?;
+
+        // Assert
+        assert_eq!(monday.id, first.unwrap().id);
+        assert_eq!(wednesday.id, second.unwrap().id);
+        assert!(enrollment.next_day(&program, started_on + Duration::days(1), &database).await?.is_none());
+
+        Ok(())
+    }
+}
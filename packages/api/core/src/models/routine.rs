@@ -0,0 +1,276 @@
+use super::{Error, Result, RoutineExercise, User};
+use crate::prelude::*;
+use database::{Connection, DatabaseManager, HasRouteKey, Model, SqlxAction};
+use sqlx::FromRow;
+
+#[cfg(test)]
+pub(crate) use builder::*;
+
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "routines", route_key = "ulid")]
+pub struct Routine {
+    #[model(generated)]
+    pub id: i32,
+    #[model(generated)]
+    pub ulid: String,
+    pub user_id: i16,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: ISO8601DateTimeUTC,
+    pub updated_at: ISO8601DateTimeUTC,
+}
+
+mod builder {
+    use super::{Result, Routine, User};
+    use database::{Connection, Model, SqlxAction};
+
+    // region Type States
+
+    #[derive(Default)]
+    pub struct NoUserId;
+    #[derive(Default)]
+    pub struct UserId(pub i16);
+
+    #[derive(Default)]
+    pub struct NoName;
+    #[derive(Default)]
+    pub struct Name(pub String);
+
+    // endregion
+
+    // region Builder
+
+    #[derive(Default)]
+    pub struct RoutineBuilder<U, N> {
+        user_id: U,
+        name: N,
+        description: Option<String>,
+    }
+
+    impl RoutineBuilder<NoUserId, NoName> {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl<U, N> RoutineBuilder<U, N> {
+        pub fn user_id(self, id: i16) -> RoutineBuilder<UserId, N> {
+            RoutineBuilder {
+                user_id: UserId(id),
+                name: self.name,
+                description: self.description,
+            }
+        }
+
+        pub fn user(self, user: &User) -> RoutineBuilder<UserId, N> {
+            RoutineBuilder {
+                user_id: UserId(user.id),
+                name: self.name,
+                description: self.description,
+            }
+        }
+
+        pub fn name(self, name: impl Into<String>) -> RoutineBuilder<U, Name> {
+            RoutineBuilder {
+                user_id: self.user_id,
+                name: Name(name.into()),
+                description: self.description,
+            }
+        }
+
+        pub fn description(mut self, description: Option<String>) -> Self {
+            self.description = description;
+            self
+        }
+    }
+
+    impl RoutineBuilder<UserId, Name> {
+        pub async fn create(self, mut database: impl Connection) -> Result<Routine> {
+            let model = Routine::query()
+                .insert()
+                .value("user_id", self.user_id.0)
+                .value("name", self.name.0)
+                .value("description", self.description)
+                .returning(&["*"])
+                .one::<_, Routine>(database.executor())
+                .await?;
+
+            Ok(model)
+        }
+    }
+
+    // endregion
+}
+
+use builder::*;
+
+impl Routine {
+    // region Static Methods
+
+    pub fn new() -> RoutineBuilder<NoUserId, NoName> {
+        RoutineBuilder::new()
+    }
+
+    pub async fn find_for_user(ulid: String, user_id: i16, database: &DatabaseManager) -> Result<Self> {
+        let routine = Self::find_by_route_key(ulid, database).await?;
+
+        if routine.user_id != user_id {
+            return Err(Error::ModelNotFound(format!(
+                "No {} found where {} = {}.",
+                Self::MODEL_NAME, Self::ROUTE_KEY, routine.ulid,
+            )));
+        }
+
+        Ok(routine)
+    }
+
+    // endregion
+
+    // region Relationships
+
+    pub async fn user(&self, database: &DatabaseManager) -> Result<User> {
+        let user = User::find_by_pk(self.user_id, database).await?;
+
+        Ok(user)
+    }
+
+    pub async fn exercises(&self, mut database: impl Connection) -> Result<Vec<RoutineExercise>> {
+        let exercises = RoutineExercise::query()
+            .select(&["*"])
+            .and_where("routine_id", "=", self.id)
+            .order_by("sequence_number", true)
+            .all(database.executor())
+            .await?;
+
+        Ok(exercises)
+    }
+
+    // endregion
+
+    // region Instance Methods
+
+    // endregion
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Routine;
+    use crate::models::{RoutineExercise, User};
+    use crate::prelude::*;
+
+    #[sqlx::test]
+    async fn create_routine_success(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let user = User::mocked(&database).await?;
+        let count = Routine::count(&database).await?;
+
+        // Act
+        let routine = Routine::new()
+            .user(&user)
+            .name("Push Day")
+            .description(Some("Chest, shoulders and triceps".to_string()))
+            .create(&database)
+            .await?;
+
+        // Assert
+        assert_eq!(user.id, routine.user_id);
+        assert_eq!("Push Day", routine.name);
+        assert_some_eq("Chest, shoulders and triceps", routine.description);
+        assert_eq!(count + 1, Routine::count(&database).await?);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn edit_routine_success(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let mut routine = Routine::mocked(&database).await?;
+
+        // Act
+        routine.name = "Pull Day".to_string();
+        routine.description = None;
+
+        routine.save(&database).await?;
+
+        // Assert
+        assert_eq!("Pull Day", routine.name);
+        assert!(routine.description.is_none());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn insert_routine_copies_columns(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let mut routine = Routine::mocked(&database).await?;
+        routine.name = format!("{} (Copy)", routine.name);
+
+        // Act
+        let copy = routine.insert(&database).await?;
+
+        // Assert
+        assert_ne!(routine.id, copy.id);
+        assert_ne!(routine.ulid, copy.ulid);
+        assert_eq!(routine.user_id, copy.user_id);
+        assert_eq!(routine.name, copy.name);
+        assert_eq!(routine.description, copy.description);
+        assert_eq!(2, Routine::count(&database).await?);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn delete_routine_removes_exercises(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let routine_exercise = RoutineExercise::mocked(&database).await?;
+        let routine = routine_exercise.routine(&database).await?;
+
+        // Act
+        routine.delete(&database).await?;
+
+        // Assert
+        assert_eq!(0, Routine::count(&database).await?);
+        assert_eq!(0, RoutineExercise::count(&database).await?);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn cannot_delete_routine_twice(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let routine = Routine::mocked(&database).await?;
+        routine.clone().delete(&database).await?;
+
+        // Act
+        let result = routine.delete(&database).await;
+
+        // Assert
+        assert!(matches!(result, Err(database::Error::ModelNotFound { .. })));
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn cannot_create_routine_with_duplicate_name_for_user(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let routine = Routine::mocked(&database).await?;
+
+        // Act
+        let result = Routine::fake()
+            .user_id(routine.user_id)
+            .name(routine.name)
+            .create(&database)
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,249 @@
+use super::{Exercise, Result, Routine};
+use crate::prelude::*;
+use database::{Connection, DatabaseManager, HasRouteKey, Model};
+use sqlx::FromRow;
+
+#[cfg(test)]
+pub(crate) use builder::*;
+
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "routine_exercises", route_key = "ulid")]
+pub struct RoutineExercise {
+    #[model(generated)]
+    pub id: i32,
+    #[model(generated)]
+    pub ulid: String,
+    pub routine_id: i32,
+    pub exercise_id: i16,
+    pub sequence_number: i16,
+    pub target_sets: i16,
+    pub target_repetitions: Option<i16>,
+    pub target_weight: Option<f64>,
+    pub rest_seconds: Option<i32>,
+    pub created_at: ISO8601DateTimeUTC,
+    pub updated_at: ISO8601DateTimeUTC,
+}
+
+mod builder {
+    use super::{Exercise, Result, Routine, RoutineExercise};
+    use database::{Connection, Model, SqlxAction};
+
+    // region Type States
+
+    #[derive(Default)]
+    pub struct NoRoutineId;
+    #[derive(Default)]
+    pub struct RoutineId(pub i32);
+
+    #[derive(Default)]
+    pub struct NoExerciseId;
+    #[derive(Default)]
+    pub struct ExerciseId(pub i16);
+
+    #[derive(Default)]
+    pub struct NoSequenceNumber;
+    #[derive(Default)]
+    pub struct SequenceNumber(pub i16);
+
+    #[derive(Default)]
+    pub struct NoTargetSets;
+    #[derive(Default)]
+    pub struct TargetSets(pub i16);
+
+    // endregion
+
+    // region Builder
+
+    #[derive(Default)]
+    pub struct RoutineExerciseBuilder<R, E, S, T> {
+        routine_id: R,
+        exercise_id: E,
+        sequence_number: S,
+        target_sets: T,
+        target_repetitions: Option<i16>,
+        target_weight: Option<f64>,
+        rest_seconds: Option<i32>,
+    }
+
+    impl RoutineExerciseBuilder<NoRoutineId, NoExerciseId, NoSequenceNumber, NoTargetSets> {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl<R, E, S, T> RoutineExerciseBuilder<R, E, S, T> {
+        pub fn routine_id(self, id: i32) -> RoutineExerciseBuilder<RoutineId, E, S, T> {
+            RoutineExerciseBuilder {
+                routine_id: RoutineId(id),
+                exercise_id: self.exercise_id,
+                sequence_number: self.sequence_number,
+                target_sets: self.target_sets,
+                target_repetitions: self.target_repetitions,
+                target_weight: self.target_weight,
+                rest_seconds: self.rest_seconds,
+            }
+        }
+
+        pub fn routine(self, routine: &Routine) -> RoutineExerciseBuilder<RoutineId, E, S, T> {
+            self.routine_id(routine.id)
+        }
+
+        pub fn exercise_id(self, id: i16) -> RoutineExerciseBuilder<R, ExerciseId, S, T> {
+            RoutineExerciseBuilder {
+                routine_id: self.routine_id,
+                exercise_id: ExerciseId(id),
+                sequence_number: self.sequence_number,
+                target_sets: self.target_sets,
+                target_repetitions: self.target_repetitions,
+                target_weight: self.target_weight,
+                rest_seconds: self.rest_seconds,
+            }
+        }
+
+        pub fn exercise(self, exercise: &Exercise) -> RoutineExerciseBuilder<R, ExerciseId, S, T> {
+            self.exercise_id(exercise.id)
+        }
+
+        pub fn sequence_number(self, number: i16) -> RoutineExerciseBuilder<R, E, SequenceNumber, T> {
+            RoutineExerciseBuilder {
+                routine_id: self.routine_id,
+                exercise_id: self.exercise_id,
+                sequence_number: SequenceNumber(number),
+                target_sets: self.target_sets,
+                target_repetitions: self.target_repetitions,
+                target_weight: self.target_weight,
+                rest_seconds: self.rest_seconds,
+            }
+        }
+
+        pub fn target_sets(self, sets: i16) -> RoutineExerciseBuilder<R, E, S, TargetSets> {
+            RoutineExerciseBuilder {
+                routine_id: self.routine_id,
+                exercise_id: self.exercise_id,
+                sequence_number: self.sequence_number,
+                target_sets: TargetSets(sets),
+                target_repetitions: self.target_repetitions,
+                target_weight: self.target_weight,
+                rest_seconds: self.rest_seconds,
+            }
+        }
+
+        pub fn target_repetitions(mut self, repetitions: Option<i16>) -> Self {
+            self.target_repetitions = repetitions;
+            self
+        }
+
+        pub fn target_weight(mut self, weight: Option<f64>) -> Self {
+            self.target_weight = weight;
+            self
+        }
+
+        pub fn rest_seconds(mut self, seconds: Option<i32>) -> Self {
+            self.rest_seconds = seconds;
+            self
+        }
+    }
+
+    impl RoutineExerciseBuilder<RoutineId, ExerciseId, SequenceNumber, TargetSets> {
+        pub async fn create(self, mut database: impl Connection) -> Result<RoutineExercise> {
+            let model = RoutineExercise::query()
+                .insert()
+                .value("routine_id", self.routine_id.0)
+                .value("exercise_id", self.exercise_id.0)
+                .value("sequence_number", self.sequence_number.0)
+                .value("target_sets", self.target_sets.0)
+                .value("target_repetitions", self.target_repetitions)
+                .value("target_weight", self.target_weight)
+                .value("rest_seconds", self.rest_seconds)
+                .returning(&["*"])
+                .one::<_, RoutineExercise>(database.executor())
+                .await?;
+
+            Ok(model)
+        }
+    }
+
+    // endregion
+}
+
+use builder::*;
+
+impl RoutineExercise {
+    pub fn new() -> RoutineExerciseBuilder<NoRoutineId, NoExerciseId, NoSequenceNumber, NoTargetSets> {
+        RoutineExerciseBuilder::new()
+    }
+
+    // region Relationships
+
+    pub async fn routine(&self, database: &DatabaseManager) -> Result<Routine> {
+        let routine = Routine::find_by_pk(self.routine_id, database).await?;
+
+        Ok(routine)
+    }
+
+    pub async fn exercise(&self, database: impl Connection) -> Result<Exercise> {
+        let exercise = Exercise::find_by_pk(self.exercise_id, database).await?;
+
+        Ok(exercise)
+    }
+
+    // endregion
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RoutineExercise;
+    use crate::models::{Exercise, Routine};
+    use crate::prelude::*;
+
+    #[sqlx::test]
+    async fn create_routine_exercise_success(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let routine = Routine::mocked(&database).await?;
+        let exercise = Exercise::mocked(&database).await?;
+        let count = RoutineExercise::count(&database).await?;
+
+        // Act
+        let routine_exercise = RoutineExercise::new()
+            .routine(&routine)
+            .exercise(&exercise)
+            .sequence_number(1)
+            .target_sets(3)
+            .target_repetitions(Some(10))
+            .target_weight(Some(60.0))
+            .rest_seconds(Some(90))
+            .create(&database)
+            .await?;
+
+        // Assert
+        assert_eq!(routine.id, routine_exercise.routine_id);
+        assert_eq!(exercise.id, routine_exercise.exercise_id);
+        assert_eq!(3, routine_exercise.target_sets);
+        assert_some_eq(10i16, routine_exercise.target_repetitions);
+        assert_some_eq(60.0, routine_exercise.target_weight);
+        assert_some_eq(90, routine_exercise.rest_seconds);
+        assert_eq!(count + 1, RoutineExercise::count(&database).await?);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn cannot_create_routine_exercise_without_sets(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let routine = Routine::mocked(&database).await?;
+
+        // Act
+        let result = RoutineExercise::fake()
+            .routine(&routine)
+            .target_sets(0)
+            .create(&database)
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+use super::Result;
+use crate::prelude::*;
+use crate::enums::SyncRunStatus;
+use database::{Connection, DatabaseManager, Model, SqlxAction};
+use sqlx::{FromRow, PgPool};
+
+#[cfg(test)]
+pub use builder::*;
+
+/// A run of the exercise catalog sync, tracking how far through the source
+/// it got so that an interrupted run can carry on where it stopped.
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "sync_runs")]
+pub struct SyncRun {
+    #[model(generated)]
+    pub id: i32,
+    pub source: String,
+    pub status: SyncRunStatus,
+    pub dry_run: bool,
+    pub next_offset: i32,
+    pub created_count: i32,
+    pub updated_count: i32,
+    pub unchanged_count: i32,
+    pub failed_count: i32,
+    pub finished_at: Option<ISO8601DateTimeUTC>,
+    pub created_at: ISO8601DateTimeUTC,
+    pub updated_at: ISO8601DateTimeUTC,
+}
+
+mod builder {
+    use super::{Result, SyncRun};
+    use crate::enums::SyncRunStatus;
+    use database::{Connection, Model, SqlxAction};
+
+    // region Type States
+
+    #[derive(Default)]
+    pub struct NoSource;
+    #[derive(Default)]
+    pub struct Source(String);
+
+    // endregion
+
+    #[derive(Default)]
+    pub struct SyncRunBuilder<S> {
+        source: S,
+        dry_run: bool,
+    }
+
+    impl SyncRunBuilder<NoSource> {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl<S> SyncRunBuilder<S> {
+        pub fn source(self, source: impl Into<String>) -> SyncRunBuilder<Source> {
+            SyncRunBuilder {
+                source: Source(source.into()),
+                dry_run: self.dry_run,
+            }
+        }
+
+        pub fn dry_run(mut self, dry_run: bool) -> Self {
+            self.dry_run = dry_run;
+            self
+        }
+    }
+
+    impl SyncRunBuilder<Source> {
+        pub async fn create(self, mut database: impl Connection) -> Result<SyncRun> {
+            let model = SyncRun::query()
+                .insert()
+                .value("source", self.source.0)
+                .value("status", SyncRunStatus::Running)
+                .value("dry_run", self.dry_run)
+                .returning(&["*"])
+                .one::<_, SyncRun>(database.executor())
+                .await?;
+
+            Ok(model)
+        }
+    }
+}
+
+use builder::*;
+
+impl SyncRun {
+    pub fn new() -> SyncRunBuilder<NoSource> {
+        SyncRunBuilder::new()
+    }
+
+    /// The latest run from `source` that didn't complete, if there is one.
+    /// Dry runs are never resumed.
+    pub async fn resumable(source: &str, database: &DatabaseManager) -> Result<Option<SyncRun>> {
+        let run = Self::query()
+            .select(&["*"])
+            .and_where("source", "=", source.to_string())
+            .and_where("dry_run", "=", false)
+            .and_where("status", "<>", SyncRunStatus::Completed)
+            .order_by("id", false)
+            .limit(1)
+            .all::<&PgPool, Self>(database.connection())
+            .await?
+            .pop();
+
+        Ok(run)
+    }
+
+    // region Instance Methods
+
+    pub async fn finish(&mut self, status: SyncRunStatus, database: impl Connection) -> Result<()> {
+        self.status = status;
+        self.finished_at = Some(chrono::Utc::now());
+
+        self.save(database).await?;
+
+        Ok(())
+    }
+
+    // endregion
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SyncRun;
+    use crate::prelude::*;
+    use crate::enums::SyncRunStatus;
+
+    #[sqlx::test]
+    async fn create_sync_run_success(pool: PgPool) -> Result<()> {
+        let database = DatabaseManager::from_pool(pool);
+
+        let run = SyncRun::new()
+            .source("test")
+            .create(&database)
+            .await?;
+
+        assert_eq!("test", run.source);
+        assert_eq!(SyncRunStatus::Running, run.status);
+        assert!(!run.dry_run);
+        assert_eq!(0, run.next_offset);
+        assert!(run.finished_at.is_none());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn resumable_finds_the_latest_unfinished_run(pool: PgPool) -> Result<()> {
+        let database = DatabaseManager::from_pool(pool);
+        let mut completed = SyncRun::new().source("test").create(&database).await?;
+        completed.finish(SyncRunStatus::Completed, &database).await?;
+        let mut failed = SyncRun::new().source("test").create(&database).await?;
+        failed.finish(SyncRunStatus::Failed, &database).await?;
+        SyncRun::new().source("test").dry_run(true).create(&database).await?;
+        SyncRun::new().source("other").create(&database).await?;
+
+        let run = SyncRun::resumable("test", &database).await?;
+
+        assert_eq!(Some(failed.id), run.map(|run| run.id));
+
+        completed.finish(SyncRunStatus::Completed, &database).await?;
+        failed.finish(SyncRunStatus::Completed, &database).await?;
+        assert!(SyncRun::resumable("test", &database).await?.is_none());
+
+        Ok(())
+    }
+}
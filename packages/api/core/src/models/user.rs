@@ -1,16 +1,17 @@
-use super::{Error, Profile, Result};
-use crate::enums::Role;
+use super::{Error, Profile, Result, Routine, Workout};
+use crate::enums::{Permission, Role};
 use crate::prelude::*;
-use database::{DatabaseManager, HasRouteKey, Model};
-use async_trait::async_trait;
+use database::{Connection, DatabaseManager, HasRouteKey, Model, SqlxAction, SqlxQuery};
 use serde::{Deserialize, Serialize};
 use sqlx::{postgres::PgPool, FromRow};
 
 #[cfg(test)]
 pub(crate) use builder::*;
 
-#[derive(Clone, Debug, FromRow)]
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "users", route_key = "id")]
 pub struct User {
+    #[model(generated)]
     pub id: i16,
     pub email: String,
     pub role: Role,
@@ -26,7 +27,7 @@ mod builder {
     use super::{Error, User, Result};
     use crate::enums::Role;
     use crate::utils::crypt;
-    use database::{DatabaseManager, Model};
+    use database::{Connection, Model, SqlxAction};
 
     // region Type States
 
@@ -114,19 +115,18 @@ mod builder {
     }
 
     impl UserBuilder<Password, UserRole, Email, Name> {
-        pub async fn create(self, database: &DatabaseManager) -> Result<User> {
+        pub async fn create(self, mut database: impl Connection) -> Result<User> {
             let password = crypt::encrypt(self.password.0.as_ref())?;
 
-            let model = sqlx::query_as::<_, User>(format!(
-                "INSERT INTO {} (email, role, first_name, last_name, password) VALUES ($1, $2, $3, $4, $5) RETURNING *",
-                User::TABLE_NAME,
-            ).as_str())
-                .bind(self.email.0)
-                .bind(self.role.0)
-                .bind(self.name.0)
-                .bind(self.name.1)
-                .bind(password)
-                .fetch_one(database.connection())
+            let model = User::query()
+                .insert()
+                .value("email", self.email.0)
+                .value("role", self.role.0)
+                .value("first_name", self.name.0)
+                .value("last_name", self.name.1)
+                .value("password", password)
+                .returning(&["*"])
+                .one::<_, User>(database.executor())
                 .await?;
 
             Ok(model)
@@ -136,26 +136,6 @@ mod builder {
 
 use builder::*;
 
-#[async_trait]
-impl Model for User {
-    const MODEL_NAME: &'static str = "User";
-    const TABLE_NAME: &'static str = "users";
-
-    type PrimaryKey = i16;
-    fn primary_key(&self) -> Self::PrimaryKey {
-        self.id
-    }
-}
-
-impl HasRouteKey for User {
-    const ROUTE_KEY: &'static str = "id";
-    type RouteKey = i16;
-
-    fn route_key(&self) -> Self::RouteKey {
-        self.id
-    }
-}
-
 impl User {
     // region Static Methods
 
@@ -163,13 +143,12 @@ impl User {
         UserBuilder::new()
     }
 
-    pub async fn exists_with_email(email: impl ToString, database: &DatabaseManager) -> Result<bool> {
+    pub async fn exists_with_email(email: impl ToString, mut database: impl Connection) -> Result<bool> {
         let result = Self::query()
-            .select(&["*"])
+            .select(&[])
             .and_where("email", "=", email.to_string())
-            .optional::<&PgPool, Self>(database.connection())
-            .await
-            .map(|user| user.is_some())?;
+            .exists(database.executor())
+            .await?;
 
         Ok(result)
     }
@@ -188,36 +167,28 @@ impl User {
 
     // region Instance Methods
 
-    pub async fn save(&mut self, database: &DatabaseManager) -> Result<()> {
-        let model = sqlx::query_as::<_, Self>(format!(
-            "UPDATE {} SET (email, first_name, last_name, password, updated_at) = ($1, $2, $3, $4, $5) WHERE {} = {} RETURNING *",
-            Self::TABLE_NAME, Self::PRIMARY_KEY, self.primary_key(),
-        ).as_str())
-            .bind(self.email.clone())
-            .bind(self.first_name.clone())
-            .bind(self.last_name.clone())
-            .bind(self.password.clone())
-            .bind(chrono::Utc::now())
-            .fetch_one(database.connection())
+    /// The permissions the user's role grants. Permissions in the database
+    /// that aren't known here are left out.
+    pub async fn permissions(&self, mut database: impl Connection) -> Result<Vec<Permission>> {
+        let names = SqlxQuery::table("permissions")
+            .select(&["permissions.name"])
+            .inner_join("roles_permissions", "roles_permissions.permission_id", "=", "permissions.id")
+            .inner_join("roles", "roles.id", "=", "roles_permissions.role_id")
+            .and_where("roles.name", "=", self.role.clone())
+            .order_by("permissions.name", true)
+            .all::<_, (String,)>(database.executor())
             .await?;
 
-        self.email = model.email;
-        self.first_name = model.first_name;
-        self.last_name = model.last_name;
-        self.password = model.password;
-        self.updated_at = model.updated_at;
-
-        Ok(())
+        Ok(names.iter().filter_map(|(name,)| name.parse().ok()).collect())
     }
 
     pub async fn update_last_logged_in(&mut self, database: &DatabaseManager) -> Result<()> {
         let now = chrono::Utc::now();
 
-        let result = sqlx::query(format!(
-            "UPDATE {} SET last_logged_in_at = $1 WHERE {} = {}",
-            Self::TABLE_NAME, Self::PRIMARY_KEY, self.primary_key()
-        ).as_str())
-            .bind(now)
+        Self::query()
+            .update()
+            .set("last_logged_in_at", now)
+            .and_where(Self::PRIMARY_KEY, "=", self.primary_key())
             .execute(database.connection())
             .await?;
 
@@ -234,13 +205,35 @@ impl User {
         Profile::find_by_user(self.id, database).await
     }
 
+    pub async fn routines(&self, database: &DatabaseManager) -> Result<Vec<Routine>> {
+        let routines = Routine::query()
+            .select(&["*"])
+            .and_where("user_id", "=", self.id)
+            .order_by("name", true)
+            .all(database.connection())
+            .await?;
+
+        Ok(routines)
+    }
+
+    pub async fn workouts(&self, database: &DatabaseManager) -> Result<Vec<Workout>> {
+        let workouts = Workout::query()
+            .select(&["*"])
+            .and_where("user_id", "=", self.id)
+            .order_by("started_at", false)
+            .all(database.connection())
+            .await?;
+
+        Ok(workouts)
+    }
+
     // endregion
 }
 
 #[cfg(test)]
 mod tests {
     use super::User;
-    use crate::enums::Role;
+    use crate::enums::{Permission, Role};
     use crate::models::Profile;
     use crate::prelude::*;
     use crate::utils::crypt::decrypt_and_verify;
@@ -370,4 +363,36 @@ mod tests {
 
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn permissions_are_granted_by_role(pool: PgPool) -> Result<()> {
+        let database = DatabaseManager::from_pool(pool);
+        let admin = User::fake().role(Role::Admin).create(&database).await?;
+        let moderator = User::fake().role(Role::Moderator).create(&database).await?;
+        let user = User::fake().role(Role::User).create(&database).await?;
+
+        let moderator_permissions = moderator.permissions(&database).await?;
+
+        assert!(admin.permissions(&database).await?.contains(&Permission::ExerciseDelete));
+        assert!(moderator_permissions.contains(&Permission::ExerciseCreate));
+        assert!(!moderator_permissions.contains(&Permission::ExerciseDelete));
+        assert!(user.permissions(&database).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn cannot_create_user_with_unknown_role(pool: PgPool) -> Result<()> {
+        let database = DatabaseManager::from_pool(pool);
+
+        sqlx::query("DELETE FROM roles WHERE name = 'coach'")
+            .execute(database.connection())
+            .await?;
+
+        let result = User::fake().role(Role::Coach).create(&database).await;
+
+        assert!(result.is_err());
+
+        Ok(())
+    }
 }
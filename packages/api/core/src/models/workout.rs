@@ -0,0 +1,306 @@
+use super::{Error, ProgramDay, ProgramEnrollment, Result, Routine, User, WorkoutExercise};
+use crate::prelude::*;
+use database::{DatabaseManager, HasRouteKey, Model, SqlxAction};
+use sqlx::FromRow;
+
+#[cfg(test)]
+pub(crate) use builder::*;
+
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "workouts", route_key = "ulid")]
+pub struct Workout {
+    #[model(generated)]
+    pub id: i32,
+    #[model(generated)]
+    pub ulid: String,
+    pub user_id: i16,
+    pub routine_id: Option<i32>,
+    pub program_enrollment_id: Option<i32>,
+    pub program_day_id: Option<i32>,
+    pub name: Option<String>,
+    pub notes: Option<String>,
+    pub started_at: ISO8601DateTimeUTC,
+    pub finished_at: Option<ISO8601DateTimeUTC>,
+    pub created_at: ISO8601DateTimeUTC,
+    pub updated_at: ISO8601DateTimeUTC,
+}
+
+mod builder {
+    use super::{ProgramDay, ProgramEnrollment, Routine, User, Workout, Result};
+    use crate::prelude::ISO8601DateTimeUTC;
+    use database::{Connection, Model, SqlxAction};
+
+    // region Type States
+
+    #[derive(Default)]
+    pub struct NoUserId;
+    #[derive(Default)]
+    pub struct UserId(pub i16);
+
+    // endregion
+
+    #[derive(Default)]
+    pub struct WorkoutBuilder<U> {
+        user_id: U,
+        routine_id: Option<i32>,
+        program_enrollment_id: Option<i32>,
+        program_day_id: Option<i32>,
+        name: Option<String>,
+        notes: Option<String>,
+        started_at: Option<ISO8601DateTimeUTC>,
+    }
+
+    impl WorkoutBuilder<NoUserId> {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl<U> WorkoutBuilder<U> {
+        pub fn user_id(self, id: i16) -> WorkoutBuilder<UserId> {
+            WorkoutBuilder {
+                user_id: UserId(id),
+                routine_id: self.routine_id,
+                program_enrollment_id: self.program_enrollment_id,
+                program_day_id: self.program_day_id,
+                name: self.name,
+                notes: self.notes,
+                started_at: self.started_at,
+            }
+        }
+
+        pub fn user(self, user: &User) -> WorkoutBuilder<UserId> {
+            WorkoutBuilder {
+                user_id: UserId(user.id),
+                routine_id: self.routine_id,
+                program_enrollment_id: self.program_enrollment_id,
+                program_day_id: self.program_day_id,
+                name: self.name,
+                notes: self.notes,
+                started_at: self.started_at,
+            }
+        }
+
+        pub fn routine_id(mut self, id: Option<i32>) -> Self {
+            self.routine_id = id;
+            self
+        }
+
+        pub fn routine(mut self, routine: &Routine) -> Self {
+            self.routine_id = Some(routine.id);
+            self
+        }
+
+        pub fn program_day(mut self, enrollment: &ProgramEnrollment, day: &ProgramDay) -> Self {
+            self.program_enrollment_id = Some(enrollment.id);
+            self.program_day_id = Some(day.id);
+            self
+        }
+
+        pub fn name(mut self, name: Option<impl Into<String>>) -> Self {
+            self.name = name.map(|n| n.into());
+            self
+        }
+
+        pub fn notes(mut self, notes: Option<impl Into<String>>) -> Self {
+            self.notes = notes.map(|n| n.into());
+            self
+        }
+
+        pub fn started_at(mut self, started_at: ISO8601DateTimeUTC) -> Self {
+            self.started_at = Some(started_at);
+            self
+        }
+    }
+
+    impl WorkoutBuilder<UserId> {
+        pub async fn create(self, mut database: impl Connection) -> Result<Workout> {
+            let mut query = Workout::query()
+                .insert()
+                .value("user_id", self.user_id.0)
+                .value("routine_id", self.routine_id)
+                .value("program_enrollment_id", self.program_enrollment_id)
+                .value("program_day_id", self.program_day_id)
+                .value("name", self.name)
+                .value("notes", self.notes);
+
+            if let Some(started_at) = self.started_at {
+                query = query.value("started_at", started_at);
+            }
+
+            let model = query
+                .returning(&["*"])
+                .one::<_, Workout>(database.executor())
+                .await?;
+
+            Ok(model)
+        }
+    }
+}
+
+use builder::*;
+
+impl Workout {
+    // region Static Methods
+
+    pub fn new() -> WorkoutBuilder<NoUserId> {
+        WorkoutBuilder::new()
+    }
+
+    pub async fn find_for_user(ulid: String, user_id: i16, database: &DatabaseManager) -> Result<Self> {
+        let workout = Self::find_by_route_key(ulid, database).await?;
+
+        if workout.user_id != user_id {
+            return Err(Error::ModelNotFound(format!(
+                "No {} found where {} = {}.",
+                Self::MODEL_NAME, Self::ROUTE_KEY, workout.ulid,
+            )));
+        }
+
+        Ok(workout)
+    }
+
+    // endregion
+
+    // region Relationships
+
+    pub async fn user(&self, database: &DatabaseManager) -> Result<User> {
+        let user = User::find_by_pk(self.user_id, database).await?;
+
+        Ok(user)
+    }
+
+    pub async fn routine(&self, database: &DatabaseManager) -> Result<Option<Routine>> {
+        let routine_id = self.routine_id;
+
+        if routine_id.is_none() {
+            return Ok(None);
+        }
+
+        let routine = Routine::find_by_pk(routine_id.unwrap(), database).await?;
+
+        Ok(Some(routine))
+    }
+
+    pub async fn exercises(&self, database: &DatabaseManager) -> Result<Vec<WorkoutExercise>> {
+        let exercises = WorkoutExercise::query()
+            .select(&["*"])
+            .and_where("workout_id", "=", self.id)
+            .order_by("sequence_number", true)
+            .all(database.connection())
+            .await?;
+
+        Ok(exercises)
+    }
+
+    // endregion
+
+    // region Instance Methods
+
+    pub async fn next_exercise_sequence_number(&self, database: &DatabaseManager) -> Result<i16> {
+        let number = WorkoutExercise::query()
+            .select(&[])
+            .and_where("workout_id", "=", self.id)
+            .max::<_, i16>("sequence_number", database.connection())
+            .await?;
+
+        Ok(number.unwrap_or(0) + 1)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished_at.is_some()
+    }
+
+    pub async fn finish(&mut self, database: &DatabaseManager) -> Result<()> {
+        self.finished_at = Some(chrono::Utc::now());
+
+        self.save(database).await?;
+
+        Ok(())
+    }
+
+    // endregion
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Workout;
+    use crate::models::User;
+    use crate::prelude::*;
+
+    #[sqlx::test]
+    async fn create_workout_success(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let user = User::mocked(&database).await?;
+        let count = Workout::count(&database).await?;
+
+        // Act
+        let workout = Workout::new()
+            .user(&user)
+            .name(Some("Push Day"))
+            .notes(Some("Felt strong"))
+            .create(&database)
+            .await?;
+
+        // Assert
+        assert_eq!(user.id, workout.user_id);
+        assert_some_eq("Push Day", workout.name);
+        assert_some_eq("Felt strong", workout.notes);
+        assert!(workout.finished_at.is_none());
+        assert_eq!(count + 1, Workout::count(&database).await?);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn edit_workout_success(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let mut workout = Workout::mocked(&database).await?;
+
+        // Act
+        workout.name = Some("Pull Day".to_string());
+        workout.notes = Some("New notes".to_string());
+
+        workout.save(&database).await?;
+
+        // Assert
+        assert_some_eq("Pull Day", workout.name);
+        assert_some_eq("New notes", workout.notes);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn finish_workout_success(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let mut workout = Workout::mocked(&database).await?;
+
+        // Act
+        workout.finish(&database).await?;
+
+        // Assert
+        assert!(workout.is_finished());
+        assert!(Workout::find_by_pk(workout.id, &database).await?.is_finished());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn cannot_find_workout_for_another_user(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let workout = Workout::mocked(&database).await?;
+        let another_user = User::mocked(&database).await?;
+
+        // Act
+        let result = Workout::find_for_user(workout.ulid, another_user.id, &database).await;
+
+        // Assert
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
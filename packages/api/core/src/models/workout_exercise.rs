@@ -0,0 +1,304 @@
+use super::{Error, Exercise, Result, Workout, WorkoutSet};
+use crate::prelude::*;
+use database::{Connection, DatabaseManager, HasRouteKey, Model, SqlxAction};
+use sqlx::FromRow;
+
+#[cfg(test)]
+pub(crate) use builder::*;
+
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "workout_exercises", route_key = "ulid")]
+pub struct WorkoutExercise {
+    #[model(generated)]
+    pub id: i32,
+    #[model(generated)]
+    pub ulid: String,
+    pub workout_id: i32,
+    pub exercise_id: i16,
+    pub sequence_number: i16,
+    pub notes: Option<String>,
+    pub target_sets: Option<i16>,
+    pub target_repetitions: Option<i16>,
+    pub target_weight: Option<f64>,
+    pub rest_seconds: Option<i32>,
+    pub created_at: ISO8601DateTimeUTC,
+    pub updated_at: ISO8601DateTimeUTC,
+}
+
+mod builder {
+    use super::{Exercise, Result, Workout, WorkoutExercise};
+    use database::{Connection, Model, SqlxAction};
+
+    // region Type States
+
+    #[derive(Default)]
+    pub struct NoWorkoutId;
+    #[derive(Default)]
+    pub struct WorkoutId(pub i32);
+
+    #[derive(Default)]
+    pub struct NoExerciseId;
+    #[derive(Default)]
+    pub struct ExerciseId(pub i16);
+
+    #[derive(Default)]
+    pub struct NoSequenceNumber;
+    #[derive(Default)]
+    pub struct SequenceNumber(pub i16);
+
+    // endregion
+
+    // region Builder
+
+    #[derive(Default)]
+    pub struct WorkoutExerciseBuilder<W, E, S> {
+        workout_id: W,
+        exercise_id: E,
+        sequence_number: S,
+        notes: Option<String>,
+        target_sets: Option<i16>,
+        target_repetitions: Option<i16>,
+        target_weight: Option<f64>,
+        rest_seconds: Option<i32>,
+    }
+
+    impl WorkoutExerciseBuilder<NoWorkoutId, NoExerciseId, NoSequenceNumber> {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl<W, E, S> WorkoutExerciseBuilder<W, E, S> {
+        pub fn workout_id(self, id: i32) -> WorkoutExerciseBuilder<WorkoutId, E, S> {
+            WorkoutExerciseBuilder {
+                workout_id: WorkoutId(id),
+                exercise_id: self.exercise_id,
+                sequence_number: self.sequence_number,
+                notes: self.notes,
+                target_sets: self.target_sets,
+                target_repetitions: self.target_repetitions,
+                target_weight: self.target_weight,
+                rest_seconds: self.rest_seconds,
+            }
+        }
+
+        pub fn workout(self, workout: &Workout) -> WorkoutExerciseBuilder<WorkoutId, E, S> {
+            WorkoutExerciseBuilder {
+                workout_id: WorkoutId(workout.id),
+                exercise_id: self.exercise_id,
+                sequence_number: self.sequence_number,
+                notes: self.notes,
+                target_sets: self.target_sets,
+                target_repetitions: self.target_repetitions,
+                target_weight: self.target_weight,
+                rest_seconds: self.rest_seconds,
+            }
+        }
+
+        pub fn exercise_id(self, id: i16) -> WorkoutExerciseBuilder<W, ExerciseId, S> {
+            WorkoutExerciseBuilder {
+                workout_id: self.workout_id,
+                exercise_id: ExerciseId(id),
+                sequence_number: self.sequence_number,
+                notes: self.notes,
+                target_sets: self.target_sets,
+                target_repetitions: self.target_repetitions,
+                target_weight: self.target_weight,
+                rest_seconds: self.rest_seconds,
+            }
+        }
+
+        pub fn exercise(self, exercise: &Exercise) -> WorkoutExerciseBuilder<W, ExerciseId, S> {
+            WorkoutExerciseBuilder {
+                workout_id: self.workout_id,
+                exercise_id: ExerciseId(exercise.id),
+                sequence_number: self.sequence_number,
+                notes: self.notes,
+                target_sets: self.target_sets,
+                target_repetitions: self.target_repetitions,
+                target_weight: self.target_weight,
+                rest_seconds: self.rest_seconds,
+            }
+        }
+
+        pub fn sequence_number(self, number: i16) -> WorkoutExerciseBuilder<W, E, SequenceNumber> {
+            WorkoutExerciseBuilder {
+                workout_id: self.workout_id,
+                exercise_id: self.exercise_id,
+                sequence_number: SequenceNumber(number),
+                notes: self.notes,
+                target_sets: self.target_sets,
+                target_repetitions: self.target_repetitions,
+                target_weight: self.target_weight,
+                rest_seconds: self.rest_seconds,
+            }
+        }
+
+        pub fn notes(mut self, notes: Option<String>) -> Self {
+            self.notes = notes;
+            self
+        }
+
+        pub fn target_sets(mut self, sets: Option<i16>) -> Self {
+            self.target_sets = sets;
+            self
+        }
+
+        pub fn target_repetitions(mut self, repetitions: Option<i16>) -> Self {
+            self.target_repetitions = repetitions;
+            self
+        }
+
+        pub fn target_weight(mut self, weight: Option<f64>) -> Self {
+            self.target_weight = weight;
+            self
+        }
+
+        pub fn rest_seconds(mut self, seconds: Option<i32>) -> Self {
+            self.rest_seconds = seconds;
+            self
+        }
+    }
+
+    impl WorkoutExerciseBuilder<WorkoutId, ExerciseId, SequenceNumber> {
+        pub async fn create(self, mut database: impl Connection) -> Result<WorkoutExercise> {
+            let model = WorkoutExercise::query()
+                .insert()
+                .value("workout_id", self.workout_id.0)
+                .value("exercise_id", self.exercise_id.0)
+                .value("sequence_number", self.sequence_number.0)
+                .value("notes", self.notes)
+                .value("target_sets", self.target_sets)
+                .value("target_repetitions", self.target_repetitions)
+                .value("target_weight", self.target_weight)
+                .value("rest_seconds", self.rest_seconds)
+                .returning(&["*"])
+                .one::<_, WorkoutExercise>(database.executor())
+                .await?;
+
+            Ok(model)
+        }
+    }
+
+    // endregion
+}
+
+use builder::*;
+
+impl WorkoutExercise {
+    // region Static Methods
+
+    pub fn new() -> WorkoutExerciseBuilder<NoWorkoutId, NoExerciseId, NoSequenceNumber> {
+        WorkoutExerciseBuilder::new()
+    }
+
+    pub async fn find_for_workout(ulid: String, workout_id: i32, database: &DatabaseManager) -> Result<Self> {
+        let workout_exercise = Self::find_by_route_key(ulid, database).await?;
+
+        if workout_exercise.workout_id != workout_id {
+            return Err(Error::ModelNotFound(format!(
+                "No {} found where {} = {}.",
+                Self::MODEL_NAME, Self::ROUTE_KEY, workout_exercise.ulid,
+            )));
+        }
+
+        Ok(workout_exercise)
+    }
+
+    // endregion
+
+    // region Relationships
+
+    pub async fn workout(&self, database: &DatabaseManager) -> Result<Workout> {
+        let workout = Workout::find_by_pk(self.workout_id, database).await?;
+
+        Ok(workout)
+    }
+
+    pub async fn exercise(&self, database: impl Connection) -> Result<Exercise> {
+        let exercise = Exercise::find_by_pk(self.exercise_id, database).await?;
+
+        Ok(exercise)
+    }
+
+    pub async fn sets(&self, database: &DatabaseManager) -> Result<Vec<WorkoutSet>> {
+        let sets = WorkoutSet::query()
+            .select(&["*"])
+            .and_where("workout_exercise_id", "=", self.id)
+            .order_by("sequence_number", true)
+            .all(database.connection())
+            .await?;
+
+        Ok(sets)
+    }
+
+    // endregion
+
+    // region Instance Methods
+
+    pub async fn next_set_sequence_number(&self, mut database: impl Connection) -> Result<i16> {
+        let number = WorkoutSet::query()
+            .select(&[])
+            .and_where("workout_exercise_id", "=", self.id)
+            .max::<_, i16>("sequence_number", database.executor())
+            .await?;
+
+        Ok(number.unwrap_or(0) + 1)
+    }
+
+    // endregion
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WorkoutExercise;
+    use crate::models::{Exercise, Workout};
+    use crate::prelude::*;
+
+    #[sqlx::test]
+    async fn create_workout_exercise_success(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let workout = Workout::mocked(&database).await?;
+        let exercise = Exercise::mocked(&database).await?;
+        let count = WorkoutExercise::count(&database).await?;
+
+        // Act
+        let workout_exercise = WorkoutExercise::new()
+            .workout(&workout)
+            .exercise(&exercise)
+            .sequence_number(1)
+            .notes(Some("Slow eccentrics".to_string()))
+            .create(&database)
+            .await?;
+
+        // Assert
+        assert_eq!(workout.id, workout_exercise.workout_id);
+        assert_eq!(exercise.id, workout_exercise.exercise_id);
+        assert_eq!(1, workout_exercise.sequence_number);
+        assert_some_eq("Slow eccentrics", workout_exercise.notes);
+        assert_eq!(count + 1, WorkoutExercise::count(&database).await?);
+        assert_eq!(1, workout.exercises(&database).await?.len());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn cannot_create_workout_exercise_with_duplicate_sequence_number(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let workout_exercise = WorkoutExercise::mocked(&database).await?;
+
+        // Act
+        let result = WorkoutExercise::fake()
+            .workout_id(workout_exercise.workout_id)
+            .sequence_number(workout_exercise.sequence_number)
+            .create(&database)
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
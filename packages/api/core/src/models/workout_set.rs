@@ -0,0 +1,341 @@
+use super::{ExerciseMuscleMap, Muscle, MuscleGroup, PersonalRecord, Result, Workout, WorkoutExercise};
+use crate::prelude::*;
+use crate::enums::{ExerciseMuscleTarget, VolumeGranularity};
+use chrono::NaiveDate;
+use database::{DatabaseManager, HasRouteKey, Model, SqlxAction};
+use sqlx::FromRow;
+
+#[cfg(test)]
+pub(crate) use builder::*;
+
+#[derive(Clone, Debug, FromRow, Model)]
+#[model(table = "workout_sets", route_key = "ulid")]
+pub struct WorkoutSet {
+    #[model(generated)]
+    pub id: i32,
+    #[model(generated)]
+    pub ulid: String,
+    pub workout_exercise_id: i32,
+    pub sequence_number: i16,
+    pub weight: Option<f64>,
+    pub repetitions: Option<i16>,
+    pub duration: Option<i32>,
+    pub created_at: ISO8601DateTimeUTC,
+    pub updated_at: ISO8601DateTimeUTC,
+}
+
+/// Training volume of a single muscle over one period. Each set counts
+/// towards the muscle by the `volume_factor()` of its strongest target.
+#[derive(Clone, Debug, FromRow)]
+pub struct MuscleVolume {
+    pub period: NaiveDate,
+    pub muscle_id: String,
+    pub name: String,
+    pub sets: f64,
+    pub repetitions: f64,
+    pub tonnage: f64,
+}
+
+/// Training volume of a single muscle group over one period. Each set counts
+/// towards the group by the `volume_factor()` of its strongest target in that group.
+#[derive(Clone, Debug, FromRow)]
+pub struct MuscleGroupVolume {
+    pub period: NaiveDate,
+    pub muscle_group_id: i16,
+    pub name: String,
+    pub sets: f64,
+    pub repetitions: f64,
+    pub tonnage: f64,
+}
+
+mod builder {
+    use super::{Result, WorkoutExercise, WorkoutSet};
+    use database::{Connection, Model, SqlxAction};
+
+    // region Type States
+
+    #[derive(Default)]
+    pub struct NoWorkoutExerciseId;
+    #[derive(Default)]
+    pub struct WorkoutExerciseId(pub i32);
+
+    #[derive(Default)]
+    pub struct NoSequenceNumber;
+    #[derive(Default)]
+    pub struct SequenceNumber(pub i16);
+
+    // endregion
+
+    // region Builder
+
+    #[derive(Default)]
+    pub struct WorkoutSetBuilder<W, S> {
+        workout_exercise_id: W,
+        sequence_number: S,
+        weight: Option<f64>,
+        repetitions: Option<i16>,
+        duration: Option<i32>,
+    }
+
+    impl WorkoutSetBuilder<NoWorkoutExerciseId, NoSequenceNumber> {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl<W, S> WorkoutSetBuilder<W, S> {
+        pub fn workout_exercise_id(self, id: i32) -> WorkoutSetBuilder<WorkoutExerciseId, S> {
+            WorkoutSetBuilder {
+                workout_exercise_id: WorkoutExerciseId(id),
+                sequence_number: self.sequence_number,
+                weight: self.weight,
+                repetitions: self.repetitions,
+                duration: self.duration,
+            }
+        }
+
+        pub fn workout_exercise(self, workout_exercise: &WorkoutExercise) -> WorkoutSetBuilder<WorkoutExerciseId, S> {
+            WorkoutSetBuilder {
+                workout_exercise_id: WorkoutExerciseId(workout_exercise.id),
+                sequence_number: self.sequence_number,
+                weight: self.weight,
+                repetitions: self.repetitions,
+                duration: self.duration,
+            }
+        }
+
+        pub fn sequence_number(self, number: i16) -> WorkoutSetBuilder<W, SequenceNumber> {
+            WorkoutSetBuilder {
+                workout_exercise_id: self.workout_exercise_id,
+                sequence_number: SequenceNumber(number),
+                weight: self.weight,
+                repetitions: self.repetitions,
+                duration: self.duration,
+            }
+        }
+
+        pub fn weight(mut self, weight: Option<f64>) -> Self {
+            self.weight = weight;
+            self
+        }
+
+        pub fn repetitions(mut self, repetitions: Option<i16>) -> Self {
+            self.repetitions = repetitions;
+            self
+        }
+
+        pub fn duration(mut self, duration: Option<i32>) -> Self {
+            self.duration = duration;
+            self
+        }
+    }
+
+    impl WorkoutSetBuilder<WorkoutExerciseId, SequenceNumber> {
+        pub async fn create(self, mut database: impl Connection) -> Result<WorkoutSet> {
+            let model = WorkoutSet::query()
+                .insert()
+                .value("workout_exercise_id", self.workout_exercise_id.0)
+                .value("sequence_number", self.sequence_number.0)
+                .value("weight", self.weight)
+                .value("repetitions", self.repetitions)
+                .value("duration", self.duration)
+                .returning(&["*"])
+                .one::<_, WorkoutSet>(database.executor())
+                .await?;
+
+            Ok(model)
+        }
+    }
+
+    // endregion
+}
+
+use builder::*;
+
+impl WorkoutSet {
+    // region Static Methods
+
+    pub fn new() -> WorkoutSetBuilder<NoWorkoutExerciseId, NoSequenceNumber> {
+        WorkoutSetBuilder::new()
+    }
+
+    /// Returns every set a user has logged for an exercise, oldest first.
+    pub async fn for_user_exercise(user_id: i16, exercise_id: i16, database: &DatabaseManager) -> Result<Vec<Self>> {
+        let sets = Self::query()
+            .select(&["workout_sets.*"])
+            .inner_join(WorkoutExercise::TABLE_NAME, "workout_exercises.id", "=", "workout_sets.workout_exercise_id")
+            .inner_join(Workout::TABLE_NAME, "workouts.id", "=", "workout_exercises.workout_id")
+            .and_where("workouts.user_id", "=", user_id)
+            .and_where("workout_exercises.exercise_id", "=", exercise_id)
+            .order_by("workout_sets.created_at", true)
+            .order_by("workout_sets.id", true)
+            .all::<_, Self>(database.connection())
+            .await?;
+
+        Ok(sets)
+    }
+
+    pub async fn volume_by_muscle(
+        user_id: i16,
+        from: NaiveDate,
+        to: NaiveDate,
+        granularity: &VolumeGranularity,
+        database: &DatabaseManager,
+    ) -> Result<Vec<MuscleVolume>> {
+        let volume = sqlx::query_as::<_, MuscleVolume>(format!(
+            "{targets} SELECT period, muscle_ulid AS muscle_id, muscle_name AS name, {totals} FROM (SELECT set_id, period, muscle_ulid, muscle_name, repetitions, weight, MAX(factor) AS factor FROM targets GROUP BY set_id, period, muscle_ulid, muscle_name, repetitions, weight) AS muscle_targets GROUP BY period, muscle_ulid, muscle_name ORDER BY period ASC, tonnage DESC, sets DESC, name ASC",
+            targets = Self::volume_targets_sql(),
+            totals = Self::VOLUME_TOTALS_SQL,
+        ).as_str())
+            .bind(user_id)
+            .bind(from)
+            .bind(to)
+            .bind(granularity.to_string())
+            .fetch_all(database.connection())
+            .await?;
+
+        Ok(volume)
+    }
+
+    pub async fn volume_by_muscle_group(
+        user_id: i16,
+        from: NaiveDate,
+        to: NaiveDate,
+        granularity: &VolumeGranularity,
+        database: &DatabaseManager,
+    ) -> Result<Vec<MuscleGroupVolume>> {
+        let volume = sqlx::query_as::<_, MuscleGroupVolume>(format!(
+            "{targets} SELECT period, group_id AS muscle_group_id, group_name AS name, {totals} FROM (SELECT set_id, period, group_id, group_name, repetitions, weight, MAX(factor) AS factor FROM targets GROUP BY set_id, period, group_id, group_name, repetitions, weight) AS group_targets GROUP BY period, group_id, group_name ORDER BY period ASC, tonnage DESC, sets DESC, name ASC",
+            targets = Self::volume_targets_sql(),
+            totals = Self::VOLUME_TOTALS_SQL,
+        ).as_str())
+            .bind(user_id)
+            .bind(from)
+            .bind(to)
+            .bind(granularity.to_string())
+            .fetch_all(database.connection())
+            .await?;
+
+        Ok(volume)
+    }
+
+    const VOLUME_TOTALS_SQL: &'static str = "ROUND(SUM(factor)::NUMERIC, 2)::DOUBLE PRECISION AS sets, ROUND(SUM(repetitions * factor)::NUMERIC, 2)::DOUBLE PRECISION AS repetitions, ROUND(SUM(weight * repetitions * factor)::NUMERIC, 2)::DOUBLE PRECISION AS tonnage";
+
+    /// Every muscle each of a user's sets between two dates targets, along with
+    /// the period of the set and the factor the target contributes with.
+    fn volume_targets_sql() -> String {
+        format!(
+            "WITH targets AS (SELECT {sets}.id AS set_id, date_trunc($4, {workouts}.started_at)::DATE AS period, {muscles}.ulid AS muscle_ulid, {muscles}.name AS muscle_name, {groups}.id AS group_id, {groups}.name AS group_name, COALESCE({sets}.repetitions, 0) AS repetitions, COALESCE({sets}.weight, 0) AS weight, CASE {maps}.target WHEN '{primary}' THEN {primary_factor} WHEN '{secondary}' THEN {secondary_factor} WHEN '{tertiary}' THEN {tertiary_factor} ELSE 0 END::DOUBLE PRECISION AS factor FROM {sets} JOIN {exercises} ON {exercises}.id = {sets}.workout_exercise_id JOIN {workouts} ON {workouts}.id = {exercises}.workout_id JOIN {maps} ON {maps}.exercise_id = {exercises}.exercise_id JOIN {muscles} ON {muscles}.id = {maps}.muscle_id JOIN {groups} ON {groups}.id = {muscles}.group_id WHERE {workouts}.user_id = $1 AND {workouts}.started_at >= $2::DATE AND {workouts}.started_at < $3::DATE + 1)",
+            sets = Self::TABLE_NAME,
+            exercises = WorkoutExercise::TABLE_NAME,
+            workouts = Workout::TABLE_NAME,
+            maps = ExerciseMuscleMap::TABLE_NAME,
+            muscles = Muscle::TABLE_NAME,
+            groups = MuscleGroup::TABLE_NAME,
+            primary = ExerciseMuscleTarget::Primary,
+            secondary = ExerciseMuscleTarget::Secondary,
+            tertiary = ExerciseMuscleTarget::Tertiary,
+            primary_factor = ExerciseMuscleTarget::Primary.volume_factor(),
+            secondary_factor = ExerciseMuscleTarget::Secondary.volume_factor(),
+            tertiary_factor = ExerciseMuscleTarget::Tertiary.volume_factor(),
+        )
+    }
+
+    // endregion
+
+    // region Relationships
+
+    pub async fn workout_exercise(&self, database: &DatabaseManager) -> Result<WorkoutExercise> {
+        let workout_exercise = WorkoutExercise::find_by_pk(self.workout_exercise_id, database).await?;
+
+        Ok(workout_exercise)
+    }
+
+    pub async fn personal_records(&self, database: &DatabaseManager) -> Result<Vec<PersonalRecord>> {
+        let records = PersonalRecord::query()
+            .select(&["*"])
+            .and_where("workout_set_id", "=", self.id)
+            .order_by("id", true)
+            .all(database.connection())
+            .await?;
+
+        Ok(records)
+    }
+
+    // endregion
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WorkoutSet;
+    use crate::models::WorkoutExercise;
+    use crate::prelude::*;
+
+    #[sqlx::test]
+    async fn create_workout_set_success(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let workout_exercise = WorkoutExercise::mocked(&database).await?;
+        let count = WorkoutSet::count(&database).await?;
+
+        // Act
+        let set = WorkoutSet::new()
+            .workout_exercise(&workout_exercise)
+            .sequence_number(1)
+            .weight(Some(102.5))
+            .repetitions(Some(5))
+            .create(&database)
+            .await?;
+
+        // Assert
+        assert_eq!(workout_exercise.id, set.workout_exercise_id);
+        assert_eq!(1, set.sequence_number);
+        assert_some_eq(102.5, set.weight);
+        assert_some_eq(5i16, set.repetitions);
+        assert!(set.duration.is_none());
+        assert_eq!(count + 1, WorkoutSet::count(&database).await?);
+        assert_eq!(1, workout_exercise.sets(&database).await?.len());
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn aggregates_respect_where_clauses(pool: PgPool) -> Result<()> {
+        // Arrange
+        let database = DatabaseManager::from_pool(pool);
+        let workout_exercise = WorkoutExercise::mocked(&database).await?;
+        let other = WorkoutExercise::mocked(&database).await?;
+
+        for (sequence_number, weight) in [(1, 100.0), (2, 110.0), (3, 120.0)] {
+            WorkoutSet::new()
+                .workout_exercise(&workout_exercise)
+                .sequence_number(sequence_number)
+                .weight(Some(weight))
+                .create(&database)
+                .await?;
+        }
+
+        WorkoutSet::new()
+            .workout_exercise(&other)
+            .sequence_number(1)
+            .weight(Some(500.0))
+            .create(&database)
+            .await?;
+
+        // Act
+        let query = WorkoutSet::query()
+            .select(&[])
+            .and_where("workout_exercise_id", "=", workout_exercise.id);
+
+        // Assert
+        assert_eq!(3, query.count(database.connection()).await?);
+        assert!(query.exists(database.connection()).await?);
+        assert_some_eq(330.0, query.sum::<_, f64>("weight", database.connection()).await?);
+        assert_some_eq(110.0, query.avg("weight", database.connection()).await?);
+        assert_some_eq(100.0, query.min::<_, f64>("weight", database.connection()).await?);
+        assert_some_eq(120.0, query.max::<_, f64>("weight", database.connection()).await?);
+        assert_eq!(4, workout_exercise.next_set_sequence_number(&database).await?);
+
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@ use super::DynError;
 pub use crate::mocks::{MockResponse, MockServer, MockUser};
 pub use axum::http::StatusCode;
 pub use axum_test::{TestResponse, TestServer, TestServerConfig};
-pub use database::{DatabaseManager, HasRouteKey, Model};
+pub use database::{DatabaseManager, HasRouteKey, Model, SqlxAction};
 pub use serde_json::{json, Value};
 pub use sqlx::postgres::PgPool;
 
@@ -37,7 +37,6 @@ pub struct DatabaseConfig {
 
 pub struct ServerConfig {
     port: u16,
-    should_sync_exercises: bool,
 }
 
 impl AuthenticationConfig {
@@ -122,23 +121,18 @@ impl DatabaseConfig {
 }
 
 impl ServerConfig {
-    pub fn new(port: String, should_sync_exercises: String) -> Result<Self> {
+    pub fn new(port: String) -> Result<Self> {
         Ok(Self {
             port: port.parse::<u16>().map_err(|_| Error::StringParseFailure {
                 parse_type: "u16",
                 value: port,
             })?,
-            should_sync_exercises: should_sync_exercises.to_lowercase() == "true"
         })
     }
 
     pub fn port(&self) -> u16 {
         self.port
     }
-
-    pub fn should_sync_exercises(&self) -> bool {
-        self.should_sync_exercises
-    }
 }
 
 impl Config {
@@ -159,7 +153,6 @@ impl Config {
             )?,
             server: ServerConfig::new(
                 env("SERVER_PORT")?,
-                env("SHOULD_SYNC_EXERCISES")?,
             )?,
         })
     }
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+
+pub async fn one_rep_max_trend(server: &MockServer, exercise: &impl HasRouteKey, params: Value) -> MockResponse {
+    server.get_with_params(
+        format!("/api/analytics/one-rep-max/{}", exercise.route_key()).as_str(),
+        params,
+    ).await
+}
+
+pub async fn volume(server: &MockServer, params: Value) -> MockResponse {
+    server.get_with_params("/api/analytics/volume", params).await
+}
@@ -0,0 +1,50 @@
+use crate::prelude::*;
+
+pub async fn invite_client(server: &MockServer, payload: Value) -> MockResponse {
+    server.post("/api/coaching", payload).await
+}
+
+pub async fn list_relationships(server: &MockServer) -> MockResponse {
+    server.get("/api/coaching").await
+}
+
+pub async fn accept_invitation(server: &MockServer, relationship: &impl HasRouteKey) -> MockResponse {
+    server.post(
+        format!("/api/coaching/{}/accept", relationship.route_key()).as_str(),
+        json!({}),
+    ).await
+}
+
+pub async fn decline_invitation(server: &MockServer, relationship: &impl HasRouteKey) -> MockResponse {
+    server.post(
+        format!("/api/coaching/{}/decline", relationship.route_key()).as_str(),
+        json!({}),
+    ).await
+}
+
+pub async fn revoke_relationship(server: &MockServer, relationship: &impl HasRouteKey) -> MockResponse {
+    server.delete(format!("/api/coaching/{}", relationship.route_key()).as_str()).await
+}
+
+pub async fn list_client_workouts(server: &MockServer, relationship: &impl HasRouteKey) -> MockResponse {
+    server.get(format!("/api/coaching/{}/workouts", relationship.route_key()).as_str()).await
+}
+
+pub async fn read_client_workout(
+    server: &MockServer,
+    relationship: &impl HasRouteKey,
+    workout: &impl HasRouteKey,
+) -> MockResponse {
+    server.get(format!(
+        "/api/coaching/{}/workouts/{}",
+        relationship.route_key(),
+        workout.route_key(),
+    ).as_str()).await
+}
+
+pub async fn create_client_routine(server: &MockServer, relationship: &impl HasRouteKey, payload: Value) -> MockResponse {
+    server.post(
+        format!("/api/coaching/{}/routines", relationship.route_key()).as_str(),
+        payload,
+    ).await
+}
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+
+pub async fn create_equipment_profile(server: &MockServer, payload: Value) -> MockResponse {
+    server.post("/api/equipment-profiles", payload).await
+}
+
+pub async fn read_equipment_profile(server: &MockServer, profile: &impl HasRouteKey) -> MockResponse {
+    server.get_with_route_key("/api/equipment-profiles", profile.route_key()).await
+}
+
+pub async fn list_equipment_profiles(server: &MockServer) -> MockResponse {
+    server.get("/api/equipment-profiles").await
+}
+
+pub async fn update_equipment_profile(server: &MockServer, profile: &impl HasRouteKey, payload: Value) -> MockResponse {
+    server.put(format!("/api/equipment-profiles/{}", profile.route_key()).as_str(), payload).await
+}
+
+pub async fn delete_equipment_profile(server: &MockServer, profile: &impl HasRouteKey) -> MockResponse {
+    server.delete(format!("/api/equipment-profiles/{}", profile.route_key()).as_str()).await
+}
@@ -8,9 +8,28 @@ pub async fn read_exercise(server: &MockServer, exercise: &impl HasRouteKey) ->
     server.get_with_route_key("/api/exercises", exercise.route_key()).await
 }
 
+pub async fn update_exercise(server: &MockServer, exercise: &impl HasRouteKey, payload: Value) -> MockResponse {
+    server.patch(format!("/api/exercises/{}", exercise.route_key()).as_str(), payload).await
+}
+
+pub async fn delete_exercise(server: &MockServer, exercise: &impl HasRouteKey) -> MockResponse {
+    server.delete(format!("/api/exercises/{}", exercise.route_key()).as_str()).await
+}
+
 pub async fn list_exercises(server: &MockServer, params: Option<Value>) -> MockResponse {
     match params {
         Some(params) => server.get_with_params("/api/exercises", params).await,
         None => server.get("/api/exercises").await,
     }
 }
+
+pub async fn list_exercise_alternatives(server: &MockServer, exercise: &impl HasRouteKey, params: Value) -> MockResponse {
+    server.get_with_params(
+        format!("/api/exercises/{}/alternatives", exercise.route_key()).as_str(),
+        params,
+    ).await
+}
+
+pub async fn search_exercises(server: &MockServer, query: &str) -> MockResponse {
+    server.get_with_params("/api/exercises/search", json!({ "q": query })).await
+}
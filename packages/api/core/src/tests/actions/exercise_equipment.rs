@@ -11,3 +11,11 @@ pub async fn read_exercise_equipment(server: &MockServer, equipment: &impl HasRo
 pub async fn list_exercise_equipment(server: &MockServer) -> MockResponse {
     server.get("/api/exercise-equipment").await
 }
+
+pub async fn update_exercise_equipment(server: &MockServer, equipment: &impl HasRouteKey, payload: Value) -> MockResponse {
+    server.patch(format!("/api/exercise-equipment/{}", equipment.route_key()).as_str(), payload).await
+}
+
+pub async fn delete_exercise_equipment(server: &MockServer, equipment: &impl HasRouteKey) -> MockResponse {
+    server.delete(format!("/api/exercise-equipment/{}", equipment.route_key()).as_str()).await
+}
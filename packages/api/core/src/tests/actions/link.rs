@@ -0,0 +1,13 @@
+use crate::prelude::*;
+
+pub async fn read_link(server: &MockServer, link: &impl HasRouteKey) -> MockResponse {
+    server.get_with_route_key("/api/links", link.route_key()).await
+}
+
+pub async fn update_link(server: &MockServer, link: &impl HasRouteKey, payload: Value) -> MockResponse {
+    server.patch(format!("/api/links/{}", link.route_key()).as_str(), payload).await
+}
+
+pub async fn delete_link(server: &MockServer, link: &impl HasRouteKey) -> MockResponse {
+    server.delete(format!("/api/links/{}", link.route_key()).as_str()).await
+}
@@ -2,5 +2,14 @@ pub mod auth;
 pub mod health;
 pub mod exercise;
 pub mod exercise_equipment;
+pub mod link;
 pub mod muscle;
 pub mod muscle_group;
+pub mod workout;
+pub mod routine;
+pub mod program;
+pub mod personal_record;
+pub mod analytics;
+pub mod equipment_profile;
+pub mod coaching;
+pub mod user;
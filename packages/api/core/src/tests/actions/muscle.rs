@@ -14,3 +14,11 @@ pub async fn list_muscles(server: &MockServer, params: Option<Value>) -> MockRes
         None => server.get("/api/muscles").await,
     }
 }
+
+pub async fn update_muscle(server: &MockServer, muscle: &impl HasRouteKey, payload: Value) -> MockResponse {
+    server.patch(format!("/api/muscles/{}", muscle.route_key()).as_str(), payload).await
+}
+
+pub async fn delete_muscle(server: &MockServer, muscle: &impl HasRouteKey) -> MockResponse {
+    server.delete(format!("/api/muscles/{}", muscle.route_key()).as_str()).await
+}
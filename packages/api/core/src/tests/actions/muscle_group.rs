@@ -14,3 +14,11 @@ pub async fn list_muscle_groups(server: &MockServer, params: Option<Value>) -> M
         None => server.get("/api/muscle-groups").await,
     }
 }
+
+pub async fn update_muscle_group(server: &MockServer, route_key: impl std::fmt::Display, payload: Value) -> MockResponse {
+    server.patch(format!("/api/muscle-groups/{}", route_key).as_str(), payload).await
+}
+
+pub async fn delete_muscle_group(server: &MockServer, route_key: impl std::fmt::Display) -> MockResponse {
+    server.delete(format!("/api/muscle-groups/{}", route_key).as_str()).await
+}
@@ -0,0 +1,9 @@
+use crate::prelude::*;
+
+pub async fn list_personal_records(server: &MockServer) -> MockResponse {
+    server.get("/api/personal-records").await
+}
+
+pub async fn read_exercise_timeline(server: &MockServer, exercise: &impl HasRouteKey) -> MockResponse {
+    server.get_with_route_key("/api/personal-records/exercises", exercise.route_key()).await
+}
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+pub async fn create_program(server: &MockServer, payload: Value) -> MockResponse {
+    server.post("/api/programs", payload).await
+}
+
+pub async fn read_program(server: &MockServer, program: &impl HasRouteKey) -> MockResponse {
+    server.get_with_route_key("/api/programs", program.route_key()).await
+}
+
+pub async fn enroll_in_program(server: &MockServer, program: &impl HasRouteKey, payload: Value) -> MockResponse {
+    server.post(
+        format!("/api/programs/{}/enrollment", program.route_key()).as_str(),
+        payload,
+    ).await
+}
+
+pub async fn leave_program(server: &MockServer, program: &impl HasRouteKey) -> MockResponse {
+    server.delete(format!("/api/programs/{}/enrollment", program.route_key()).as_str()).await
+}
+
+pub async fn read_today(server: &MockServer, program: &impl HasRouteKey) -> MockResponse {
+    server.get(format!("/api/programs/{}/today", program.route_key()).as_str()).await
+}
+
+pub async fn start_today(server: &MockServer, program: &impl HasRouteKey) -> MockResponse {
+    server.post(
+        format!("/api/programs/{}/today", program.route_key()).as_str(),
+        json!({}),
+    ).await
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+
+pub async fn create_routine(server: &MockServer, payload: Value) -> MockResponse {
+    server.post("/api/routines", payload).await
+}
+
+pub async fn read_routine(server: &MockServer, routine: &impl HasRouteKey) -> MockResponse {
+    server.get_with_route_key("/api/routines", routine.route_key()).await
+}
+
+pub async fn list_routines(server: &MockServer) -> MockResponse {
+    server.get("/api/routines").await
+}
+
+pub async fn delete_routine(server: &MockServer, routine: &impl HasRouteKey) -> MockResponse {
+    server.delete(format!("/api/routines/{}", routine.route_key()).as_str()).await
+}
+
+pub async fn start_routine(server: &MockServer, routine: &impl HasRouteKey) -> MockResponse {
+    server.post(
+        format!("/api/routines/{}/start", routine.route_key()).as_str(),
+        json!({}),
+    ).await
+}
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+
+pub async fn update_user_role(server: &MockServer, user: &impl HasRouteKey, payload: Value) -> MockResponse {
+    server.put(format!("/api/users/{}/role", user.route_key()).as_str(), payload).await
+}
+
+pub async fn list_user_workouts(server: &MockServer, user: &impl HasRouteKey) -> MockResponse {
+    server.get(format!("/api/users/{}/workouts", user.route_key()).as_str()).await
+}
+
+pub async fn read_user_workout(
+    server: &MockServer,
+    user: &impl HasRouteKey,
+    workout: &impl HasRouteKey,
+) -> MockResponse {
+    server.get(format!(
+        "/api/users/{}/workouts/{}",
+        user.route_key(),
+        workout.route_key(),
+    ).as_str()).await
+}
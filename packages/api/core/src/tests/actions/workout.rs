@@ -0,0 +1,43 @@
+use crate::prelude::*;
+
+pub async fn start_workout(server: &MockServer, payload: Value) -> MockResponse {
+    server.post("/api/workouts", payload).await
+}
+
+pub async fn read_workout(server: &MockServer, workout: &impl HasRouteKey) -> MockResponse {
+    server.get_with_route_key("/api/workouts", workout.route_key()).await
+}
+
+pub async fn list_workouts(server: &MockServer) -> MockResponse {
+    server.get("/api/workouts").await
+}
+
+pub async fn add_exercise(server: &MockServer, workout: &impl HasRouteKey, payload: Value) -> MockResponse {
+    server.post(
+        format!("/api/workouts/{}/exercises", workout.route_key()).as_str(),
+        payload,
+    ).await
+}
+
+pub async fn log_set(
+    server: &MockServer,
+    workout: &impl HasRouteKey,
+    workout_exercise: &impl HasRouteKey,
+    payload: Value,
+) -> MockResponse {
+    server.post(
+        format!(
+            "/api/workouts/{}/exercises/{}/sets",
+            workout.route_key(),
+            workout_exercise.route_key(),
+        ).as_str(),
+        payload,
+    ).await
+}
+
+pub async fn finish_workout(server: &MockServer, workout: &impl HasRouteKey) -> MockResponse {
+    server.post(
+        format!("/api/workouts/{}/finish", workout.route_key()).as_str(),
+        json!({}),
+    ).await
+}
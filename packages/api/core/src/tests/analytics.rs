@@ -0,0 +1,386 @@
+use crate::enums::{ExerciseMechanic, ExerciseMuscleTarget, Gender, Measurement};
+use crate::tests::actions::analytics as actions;
+use crate::models::{Exercise, ExerciseMuscleMap, Muscle, MuscleGroup, User, Workout, WorkoutExercise, WorkoutSet};
+use crate::prelude::*;
+use chrono::{Duration, NaiveDate, TimeZone, Utc};
+
+async fn log_sets(
+    user: &User,
+    exercise: &Exercise,
+    sets: &[(i64, f64, i16)],
+    database: &DatabaseManager,
+) -> Result<()> {
+    let workout = Workout::fake()
+        .user(user)
+        .create(database)
+        .await?;
+    let workout_exercise = WorkoutExercise::fake()
+        .workout(&workout)
+        .exercise(exercise)
+        .create(database)
+        .await?;
+
+    for (index, (days_ago, weight, repetitions)) in sets.iter().enumerate() {
+        let set = WorkoutSet::new()
+            .workout_exercise(&workout_exercise)
+            .sequence_number(index as i16 + 1)
+            .weight(Some(*weight))
+            .repetitions(Some(*repetitions))
+            .create(database)
+            .await?;
+
+        sqlx::query("UPDATE workout_sets SET created_at = $1 WHERE id = $2")
+            .bind(Utc::now() - Duration::days(*days_ago))
+            .bind(set.id)
+            .execute(database.connection())
+            .await?;
+    }
+
+    Ok(())
+}
+
+async fn log_workout(
+    user: &User,
+    exercise: &Exercise,
+    started_on: NaiveDate,
+    sets: &[(f64, i16)],
+    database: &DatabaseManager,
+) -> Result<()> {
+    let workout = Workout::fake()
+        .user(user)
+        .create(database)
+        .await?;
+    let workout_exercise = WorkoutExercise::fake()
+        .workout(&workout)
+        .exercise(exercise)
+        .create(database)
+        .await?;
+
+    sqlx::query("UPDATE workouts SET started_at = $1 WHERE id = $2")
+        .bind(Utc.from_utc_datetime(&started_on.and_hms_opt(12, 0, 0).unwrap()))
+        .bind(workout.id)
+        .execute(database.connection())
+        .await?;
+
+    for (index, (weight, repetitions)) in sets.iter().enumerate() {
+        WorkoutSet::new()
+            .workout_exercise(&workout_exercise)
+            .sequence_number(index as i16 + 1)
+            .weight(Some(*weight))
+            .repetitions(Some(*repetitions))
+            .create(database)
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Creates an exercise that targets a muscle of one group as primary, and
+/// two muscles of another group as secondary and tertiary.
+async fn mapped_exercise(database: &DatabaseManager) -> Result<(Exercise, [Muscle; 3], [MuscleGroup; 2])> {
+    let exercise = weighted_exercise(database).await?;
+    let chest = MuscleGroup::mocked(database).await?;
+    let arms = MuscleGroup::mocked(database).await?;
+    let pectoralis = Muscle::fake().group(&chest).create(database).await?;
+    let triceps = Muscle::fake().group(&arms).create(database).await?;
+    let forearms = Muscle::fake().group(&arms).create(database).await?;
+
+    for (muscle, target) in [
+        (&pectoralis, ExerciseMuscleTarget::Primary),
+        (&triceps, ExerciseMuscleTarget::Secondary),
+        (&forearms, ExerciseMuscleTarget::Tertiary),
+    ] {
+        ExerciseMuscleMap::new()
+            .exercise(&exercise)
+            .muscle(muscle)
+            .target(target)
+            .create(database)
+            .await?;
+    }
+
+    Ok((exercise, [pectoralis, triceps, forearms], [chest, arms]))
+}
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+async fn weighted_exercise(database: &DatabaseManager) -> Result<Exercise> {
+    let exercise = Exercise::fake()
+        .mechanic(Some(ExerciseMechanic::Compound))
+        .measurement(Some(Measurement::WeightedRepetitions))
+        .create(database)
+        .await?;
+
+    Ok(exercise)
+}
+
+#[sqlx::test]
+async fn one_rep_max_trend_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let user = server.user().unwrap();
+    let exercise = weighted_exercise(database).await?;
+    let mut profile = user.profile(database).await?;
+    profile.birthday = NaiveDate::from_ymd_opt(1995, 1, 1).unwrap();
+    profile.gender = Gender::Male;
+    profile.bodyweight = Some(80.0);
+    profile.save(database).await?;
+    log_sets(user, &exercise, &[(7, 100.0, 5), (7, 90.0, 10), (1, 110.0, 3)], database).await?;
+
+    // Act
+    let response = actions::one_rep_max_trend(&server, &exercise, json!({})).await;
+
+    // Assert
+    response.assert_ok();
+    let body = response.0.json::<Value>();
+    assert_eq!("epley", body["data"]["formula"]);
+    assert_eq!(121.0, body["data"]["best"]);
+    assert_eq!(json!([120.0, 121.0]), json!(body["data"]["points"].as_array().unwrap()
+        .iter()
+        .map(|point| point["one_rep_max"].clone())
+        .collect::<Vec<Value>>()
+    ));
+    assert_eq!(json!({
+        "level": "intermediate",
+        "bodyweight_ratio": 1.51,
+        "bodyweight_known": true,
+    }), body["data"]["standard"]);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn one_rep_max_trend_uses_requested_formula(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let exercise = weighted_exercise(database).await?;
+    log_sets(server.user().unwrap(), &exercise, &[(0, 100.0, 5)], database).await?;
+
+    // Act
+    let brzycki = actions::one_rep_max_trend(&server, &exercise, json!({ "formula": "brzycki" })).await;
+    let lombardi = actions::one_rep_max_trend(&server, &exercise, json!({ "formula": "lombardi" })).await;
+
+    // Assert
+    brzycki.assert_ok();
+    lombardi.assert_ok();
+    assert_eq!(112.5, brzycki.0.json::<Value>()["data"]["best"]);
+    assert_eq!(117.46, lombardi.0.json::<Value>()["data"]["best"]);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn one_rep_max_trend_without_bodyweight_uses_reference_bodyweight(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let user = server.user().unwrap();
+    let exercise = weighted_exercise(database).await?;
+    let mut profile = user.profile(database).await?;
+    profile.birthday = NaiveDate::from_ymd_opt(1995, 1, 1).unwrap();
+    profile.gender = Gender::Other;
+    profile.bodyweight = None;
+    profile.save(database).await?;
+    log_sets(user, &exercise, &[(0, 60.0, 5)], database).await?;
+
+    // Act
+    let response = actions::one_rep_max_trend(&server, &exercise, json!({})).await;
+
+    // Assert
+    response.assert_ok();
+    let body = response.0.json::<Value>();
+    assert_eq!(70.0, body["data"]["best"]);
+    assert_eq!(json!({
+        "level": "novice",
+        "bodyweight_ratio": 0.97,
+        "bodyweight_known": false,
+    }), body["data"]["standard"]);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn one_rep_max_trend_only_includes_own_sets(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let exercise = weighted_exercise(database).await?;
+    let another_user = User::mocked(database).await?;
+    log_sets(&another_user, &exercise, &[(0, 200.0, 1)], database).await?;
+
+    // Act
+    let response = actions::one_rep_max_trend(&server, &exercise, json!({})).await;
+
+    // Assert
+    response.assert_ok();
+    let body = response.0.json::<Value>();
+    assert!(body["data"]["best"].is_null());
+    assert_eq!(json!([]), body["data"]["points"]);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn one_rep_max_trend_fails_for_unweighted_exercise(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let exercise = Exercise::fake()
+        .measurement(Some(Measurement::Duration))
+        .create(server.database())
+        .await?;
+
+    // Act
+    let response = actions::one_rep_max_trend(&server, &exercise, json!({})).await;
+
+    // Assert
+    response.assert_unprocessable();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn one_rep_max_trend_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::init(pool).await;
+    let exercise = weighted_exercise(server.database()).await?;
+
+    // Act
+    let response = actions::one_rep_max_trend(&server, &exercise, json!({})).await;
+
+    // Assert
+    response.assert_unauthorized();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn volume_weights_secondary_and_tertiary_targets(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let (exercise, [pectoralis, triceps, forearms], [chest, arms]) = mapped_exercise(database).await?;
+    log_workout(server.user().unwrap(), &exercise, date(2023, 11, 14), &[(100.0, 10), (80.0, 5)], database).await?;
+
+    // Act
+    let response = actions::volume(&server, json!({
+        "from": "2023-11-13",
+        "to": "2023-11-19",
+    })).await;
+
+    // Assert
+    response.assert_ok();
+    let body = response.0.json::<Value>();
+    assert_eq!("week", body["data"]["granularity"]);
+    assert_eq!(json!({ "primary": 1.0, "secondary": 0.5, "tertiary": 0.25 }), body["data"]["weights"]);
+    assert_eq!(json!([{
+        "period": "2023-11-13",
+        "muscles": [
+            { "id": pectoralis.ulid, "name": pectoralis.name, "sets": 2.0, "repetitions": 15.0, "tonnage": 1400.0 },
+            { "id": triceps.ulid, "name": triceps.name, "sets": 1.0, "repetitions": 7.5, "tonnage": 700.0 },
+            { "id": forearms.ulid, "name": forearms.name, "sets": 0.5, "repetitions": 3.75, "tonnage": 350.0 },
+        ],
+        "muscle_groups": [
+            { "id": chest.id, "name": chest.name, "sets": 2.0, "repetitions": 15.0, "tonnage": 1400.0 },
+            { "id": arms.id, "name": arms.name, "sets": 1.0, "repetitions": 7.5, "tonnage": 700.0 },
+        ],
+    }]), body["data"]["periods"]);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn volume_groups_by_granularity(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let user = server.user().unwrap();
+    let (exercise, _, _) = mapped_exercise(database).await?;
+    log_workout(user, &exercise, date(2023, 10, 2), &[(50.0, 10)], database).await?;
+    log_workout(user, &exercise, date(2023, 10, 20), &[(50.0, 10)], database).await?;
+    log_workout(user, &exercise, date(2023, 11, 6), &[(50.0, 10)], database).await?;
+    let range = |granularity: &str| json!({
+        "from": "2023-10-01",
+        "to": "2023-11-30",
+        "granularity": granularity,
+    });
+
+    // Act
+    let weekly = actions::volume(&server, range("week")).await;
+    let monthly = actions::volume(&server, range("month")).await;
+
+    // Assert
+    weekly.assert_ok();
+    monthly.assert_ok();
+    let periods = |body: Value| body["data"]["periods"].as_array().unwrap()
+        .iter()
+        .map(|period| (period["period"].clone(), period["muscle_groups"][0]["tonnage"].clone()))
+        .collect::<Vec<(Value, Value)>>();
+    assert_eq!(vec![
+        (json!("2023-10-02"), json!(500.0)),
+        (json!("2023-10-16"), json!(500.0)),
+        (json!("2023-11-06"), json!(500.0)),
+    ], periods(weekly.0.json::<Value>()));
+    assert_eq!(vec![
+        (json!("2023-10-01"), json!(1000.0)),
+        (json!("2023-11-01"), json!(500.0)),
+    ], periods(monthly.0.json::<Value>()));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn volume_only_includes_own_workouts_within_range(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let (exercise, _, _) = mapped_exercise(database).await?;
+    let another_user = User::mocked(database).await?;
+    log_workout(&another_user, &exercise, date(2023, 11, 14), &[(100.0, 10)], database).await?;
+    log_workout(server.user().unwrap(), &exercise, date(2023, 11, 20), &[(100.0, 10)], database).await?;
+
+    // Act
+    let response = actions::volume(&server, json!({
+        "from": "2023-11-13",
+        "to": "2023-11-19",
+    })).await;
+
+    // Assert
+    response.assert_ok();
+    assert_eq!(json!([]), response.0.json::<Value>()["data"]["periods"]);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn volume_fails_for_inverted_range(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+
+    // Act
+    let response = actions::volume(&server, json!({
+        "from": "2023-11-19",
+        "to": "2023-11-13",
+    })).await;
+
+    // Assert
+    response.assert_unprocessable();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn volume_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::init(pool).await;
+
+    // Act
+    let response = actions::volume(&server, json!({})).await;
+
+    // Assert
+    response.assert_unauthorized();
+
+    Ok(())
+}
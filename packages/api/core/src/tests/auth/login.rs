@@ -1,5 +1,5 @@
 use crate::tests::actions::auth as actions;
-use crate::enums::Gender;
+use crate::enums::{Gender, Role};
 use crate::models::{Profile, User};
 use crate::prelude::*;
 
@@ -203,3 +203,53 @@ async fn admin_login_fails_with_non_existent_email(pool: PgPool) -> Result<()> {
 
     Ok(())
 }
+
+#[sqlx::test]
+async fn standard_login_fails_with_moderator_user(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::init(pool).await;
+    let user = User::fake()
+        .role(Role::Moderator)
+        .email("test_user@example.com")
+        .password("#TestPassword1234")
+        .create(server.database())
+        .await?;
+
+    let payload = json!({
+        "email": "test_user@example.com",
+        "password": "#TestPassword1234",
+    });
+
+    // Act
+    let response = actions::login(&server, payload).await;
+
+    // Assert
+    response.assert_forbidden();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn admin_login_success_with_moderator_user(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::init(pool).await;
+    let user = User::fake()
+        .role(Role::Moderator)
+        .email("test_user@example.com")
+        .password("#TestPassword1234")
+        .create(server.database())
+        .await?;
+
+    let payload = json!({
+        "email": "test_user@example.com",
+        "password": "#TestPassword1234",
+    });
+
+    // Act
+    let response = actions::login_as_admin(&server, payload).await;
+
+    // Assert
+    response.assert_ok();
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use crate::tests::actions::auth as actions;
-use crate::enums::Gender;
+use crate::enums::{Gender, Role};
 use crate::models::{Profile, User};
 use crate::prelude::*;
 
@@ -39,6 +39,34 @@ async fn success(pool: PgPool) -> Result<()> {
     Ok(())
 }
 
+#[sqlx::test]
+async fn success_with_bodyweight(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::init(pool).await;
+    let database = server.database();
+
+    // Act
+    let payload = json!({
+        "birthday": "2000-01-01",
+        "bodyweight": 72.5,
+        "email": "test_user@example.com",
+        "first_name": "MyFirstName",
+        "last_name": "MyLastName",
+        "gender": Gender::Female,
+        "password": "#TestPassword1234",
+        "password_confirm": "#TestPassword1234",
+    });
+
+    let response = actions::register(&server, payload).await;
+
+    // Assert
+    response.assert_created();
+    let user = User::find_by_email("test_user@example.com", database).await?;
+    assert_some_eq(72.5, Profile::find_by_user(user.id, database).await?.bodyweight);
+
+    Ok(())
+}
+
 #[sqlx::test]
 async fn fails_with_duplicate_email(pool: PgPool) -> Result<()> {
     // Arrange
@@ -68,6 +96,85 @@ async fn fails_with_duplicate_email(pool: PgPool) -> Result<()> {
     Ok(())
 }
 
+#[sqlx::test]
+async fn does_not_create_user_when_profile_fails(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::init(pool).await;
+
+    // Act
+    let payload = json!({
+        "birthday": "2000-01-01",
+        "bodyweight": -72.5,
+        "email": "test_user@example.com",
+        "first_name": "MyFirstName",
+        "last_name": "MyLastName",
+        "gender": Gender::Male,
+        "password": "#TestPassword1234",
+        "password_confirm": "#TestPassword1234",
+    });
+
+    let response = actions::register(&server, payload).await;
+
+    // Assert
+    response.assert_unprocessable();
+    assert_eq!(User::count(server.database()).await?, 0);
+    assert_eq!(Profile::count(server.database()).await?, 0);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn fails_as_coach(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::init(pool).await;
+
+    // Act
+    let payload = json!({
+        "birthday": "2000-01-01",
+        "email": "test_user@example.com",
+        "first_name": "MyFirstName",
+        "last_name": "MyLastName",
+        "gender": Gender::Male,
+        "role": Role::Coach,
+        "password": "#TestPassword1234",
+        "password_confirm": "#TestPassword1234",
+    });
+
+    let response = actions::register(&server, payload).await;
+
+    // Assert
+    response.assert_forbidden();
+    assert_eq!(User::count(server.database()).await?, 0);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn fails_with_privileged_role(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::init(pool).await;
+
+    // Act
+    let payload = json!({
+        "birthday": "2000-01-01",
+        "email": "test_user@example.com",
+        "first_name": "MyFirstName",
+        "last_name": "MyLastName",
+        "gender": Gender::Male,
+        "role": Role::Moderator,
+        "password": "#TestPassword1234",
+        "password_confirm": "#TestPassword1234",
+    });
+
+    let response = actions::register(&server, payload).await;
+
+    // Assert
+    response.assert_forbidden();
+    assert_eq!(User::count(server.database()).await?, 0);
+
+    Ok(())
+}
+
 macro_rules! password_format_tests {
     ($($name:ident: $value:expr,)*) => {
         $(
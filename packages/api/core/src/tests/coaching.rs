@@ -0,0 +1,385 @@
+use crate::tests::actions::coaching as actions;
+use crate::enums::CoachingStatus;
+use crate::http::resources::{CoachingRelationshipResource, ModelResource, WorkoutResource};
+use crate::models::{CoachingRelationship, Exercise, Routine, User, Workout};
+use crate::prelude::*;
+
+async fn accepted_client(server: &MockServer) -> Result<(CoachingRelationship, User)> {
+    let database = server.database();
+    let client = User::mocked(database).await?;
+    let mut relationship = CoachingRelationship::fake()
+        .coach(server.user().unwrap())
+        .client(&client)
+        .create(database)
+        .await?;
+    relationship.accept(database).await?;
+
+    Ok((relationship, client))
+}
+
+#[sqlx::test]
+async fn invite_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_coach(pool).await;
+    let database = server.database();
+    let client = User::mocked(database).await?;
+
+    // Act
+    let response = actions::invite_client(&server, json!({ "email": client.email })).await;
+
+    // Assert
+    let relationship = CoachingRelationship::find("client_id", client.id, database).await?;
+    let resource = CoachingRelationshipResource::default(relationship.clone(), database).await?;
+    response.assert_created();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+    assert_eq!(server.user().unwrap().id, relationship.coach_id);
+    assert_eq!(CoachingStatus::Pending, relationship.status);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn invite_fails_if_not_coach(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let client = User::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::invite_client(&server, json!({ "email": client.email })).await;
+
+    // Assert
+    response.assert_forbidden();
+    assert_eq!(0, CoachingRelationship::count(server.database()).await?);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn invite_fails_if_already_invited(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_coach(pool).await;
+    let database = server.database();
+    let relationship = CoachingRelationship::fake()
+        .coach(server.user().unwrap())
+        .create(database)
+        .await?;
+    let client = relationship.client(database).await?;
+
+    // Act
+    let response = actions::invite_client(&server, json!({ "email": client.email })).await;
+
+    // Assert
+    response.assert_conflict();
+    assert_eq!(1, CoachingRelationship::count(database).await?);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn invite_fails_for_yourself(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_coach(pool).await;
+    let email = server.user().unwrap().email.clone();
+
+    // Act
+    let response = actions::invite_client(&server, json!({ "email": email })).await;
+
+    // Assert
+    response.assert_unprocessable();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_includes_invitations_for_the_client(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let relationship = CoachingRelationship::fake()
+        .client(server.user().unwrap())
+        .create(database)
+        .await?;
+    CoachingRelationship::mocked(database).await?;
+
+    // Act
+    let response = actions::list_relationships(&server).await;
+
+    // Assert
+    let resources = CoachingRelationshipResource::list(vec![relationship], database).await?;
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resources,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn accept_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let relationship = CoachingRelationship::fake()
+        .client(server.user().unwrap())
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::accept_invitation(&server, &relationship).await;
+
+    // Assert
+    let relationship = CoachingRelationship::find_by_pk(relationship.id, database).await?;
+    response.assert_ok();
+    assert_eq!(CoachingStatus::Accepted, relationship.status);
+    assert!(relationship.responded_at.is_some());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn accept_fails_for_the_coach(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_coach(pool).await;
+    let database = server.database();
+    let relationship = CoachingRelationship::fake()
+        .coach(server.user().unwrap())
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::accept_invitation(&server, &relationship).await;
+
+    // Assert
+    response.assert_not_found();
+    assert_eq!(CoachingStatus::Pending, CoachingRelationship::find_by_pk(relationship.id, database).await?.status);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn decline_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let relationship = CoachingRelationship::fake()
+        .client(server.user().unwrap())
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::decline_invitation(&server, &relationship).await;
+
+    // Assert
+    response.assert_ok();
+    assert_eq!(CoachingStatus::Declined, CoachingRelationship::find_by_pk(relationship.id, database).await?.status);
+    actions::accept_invitation(&server, &relationship).await.assert_not_found();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn revoke_success_by_the_client(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let mut relationship = CoachingRelationship::fake()
+        .client(server.user().unwrap())
+        .create(database)
+        .await?;
+    relationship.accept(database).await?;
+
+    // Act
+    let response = actions::revoke_relationship(&server, &relationship).await;
+
+    // Assert
+    let revoked = CoachingRelationship::find_by_pk(relationship.id, database).await?;
+    response.assert_no_content();
+    assert_eq!(CoachingStatus::Revoked, revoked.status);
+    assert!(revoked.ended_at.is_some());
+    assert!(CoachingRelationship::find_accepted_for_coach(
+        relationship.ulid,
+        relationship.coach_id,
+        database,
+    ).await.is_err());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn revoke_fails_for_someone_elses_relationship(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let relationship = CoachingRelationship::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::revoke_relationship(&server, &relationship).await;
+
+    // Assert
+    response.assert_not_found();
+    assert_eq!(CoachingStatus::Pending, CoachingRelationship::find_by_pk(relationship.id, server.database()).await?.status);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_client_workouts_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_coach(pool).await;
+    let database = server.database();
+    let (relationship, client) = accepted_client(&server).await?;
+    let workout = Workout::fake()
+        .user(&client)
+        .create(database)
+        .await?;
+    Workout::mocked(database).await?;
+
+    // Act
+    let response = actions::list_client_workouts(&server, &relationship).await;
+
+    // Assert
+    let resources = WorkoutResource::list(vec![workout], database).await?;
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resources,
+        "pagination": {
+            "per_page": 10,
+            "next": NULL,
+            "prev": NULL,
+        },
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_client_workouts_fails_while_invitation_is_pending(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_coach(pool).await;
+    let relationship = CoachingRelationship::fake()
+        .coach(server.user().unwrap())
+        .create(server.database())
+        .await?;
+
+    // Act
+    let response = actions::list_client_workouts(&server, &relationship).await;
+
+    // Assert
+    response.assert_not_found();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_client_workouts_fails_after_revoking(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_coach(pool).await;
+    let (mut relationship, _) = accepted_client(&server).await?;
+    relationship.revoke(server.database()).await?;
+
+    // Act
+    let response = actions::list_client_workouts(&server, &relationship).await;
+
+    // Assert
+    response.assert_not_found();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_client_workouts_fails_for_the_client(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let mut relationship = CoachingRelationship::fake()
+        .client(server.user().unwrap())
+        .create(database)
+        .await?;
+    relationship.accept(database).await?;
+
+    // Act
+    let response = actions::list_client_workouts(&server, &relationship).await;
+
+    // Assert
+    response.assert_forbidden();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn read_client_workout_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_coach(pool).await;
+    let database = server.database();
+    let (relationship, client) = accepted_client(&server).await?;
+    let workout = Workout::fake()
+        .user(&client)
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::read_client_workout(&server, &relationship, &workout).await;
+
+    // Assert
+    let resource = WorkoutResource::default(workout, database).await?;
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn read_client_workout_fails_for_another_users_workout(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_coach(pool).await;
+    let (relationship, _) = accepted_client(&server).await?;
+    let workout = Workout::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::read_client_workout(&server, &relationship, &workout).await;
+
+    // Assert
+    response.assert_not_found();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_client_routine_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_coach(pool).await;
+    let database = server.database();
+    let (relationship, client) = accepted_client(&server).await?;
+    let squat = Exercise::mocked(database).await?;
+    let payload = json!({
+        "name": "Leg Day",
+        "description": NULL,
+        "exercises": [
+            {
+                "exercise_id": squat.ulid,
+                "target_sets": 5,
+                "target_repetitions": 5,
+                "target_weight": 120.0,
+                "rest_seconds": 180,
+            },
+        ],
+    });
+
+    // Act
+    let response = actions::create_client_routine(&server, &relationship, payload).await;
+
+    // Assert
+    let routine = Routine::find("name", "Leg Day", database).await?;
+    response.assert_created();
+    assert_eq!(client.id, routine.user_id);
+    assert_eq!(1, routine.exercises(database).await?.len());
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use crate::tests::actions::equipment_profile as actions;
+use crate::http::resources::{EquipmentProfileResource, ModelResource};
+use crate::models::{EquipmentProfile, ExerciseEquipment};
+use crate::prelude::*;
+
+#[sqlx::test]
+async fn create_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let barbell = ExerciseEquipment::mocked(database).await?;
+    let dumbbell = ExerciseEquipment::mocked(database).await?;
+
+    // Act
+    let response = actions::create_equipment_profile(&server, json!({
+        "name": "Home",
+        "equipment_ids": [barbell.id, dumbbell.id],
+    })).await;
+
+    // Assert
+    let profile = EquipmentProfile::find("name", "Home", database).await?;
+    let mut equipment_ids = profile.equipment_ids(database).await?;
+    let mut expected = vec![barbell.id, dumbbell.id];
+    equipment_ids.sort();
+    expected.sort();
+    assert_eq!(server.user().unwrap().id, profile.user_id);
+    assert_eq!(expected, equipment_ids);
+
+    let resource = EquipmentProfileResource::default(profile, database).await?;
+    response.assert_created();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_fails_with_unknown_equipment(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+
+    // Act
+    let response = actions::create_equipment_profile(&server, json!({
+        "name": "Home",
+        "equipment_ids": [i16::MAX],
+    })).await;
+
+    // Assert
+    response.assert_unprocessable();
+    assert_eq!(0, EquipmentProfile::count(server.database()).await?);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::init(pool).await;
+
+    // Act
+    let response = actions::create_equipment_profile(&server, json!({
+        "name": "Home",
+        "equipment_ids": [],
+    })).await;
+
+    // Assert
+    response.assert_unauthorized();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn read_fails_for_another_users_profile(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let profile = EquipmentProfile::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::read_equipment_profile(&server, &profile).await;
+
+    // Assert
+    response.assert_not_found();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_only_includes_own_profiles(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let profile = EquipmentProfile::fake()
+        .user(server.user().unwrap())
+        .create(database)
+        .await?;
+    EquipmentProfile::mocked(database).await?;
+
+    // Act
+    let response = actions::list_equipment_profiles(&server).await;
+
+    // Assert
+    response.assert_ok();
+    let body = response.0.json::<Value>();
+    assert_eq!(1, body["data"].as_array().unwrap().len());
+    assert_eq!(profile.ulid, body["data"][0]["id"]);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let barbell = ExerciseEquipment::mocked(database).await?;
+    let kettlebell = ExerciseEquipment::mocked(database).await?;
+    let profile = EquipmentProfile::fake()
+        .user(server.user().unwrap())
+        .create(database)
+        .await?;
+    profile.sync_equipment(&[barbell.id], database).await?;
+
+    // Act
+    let response = actions::update_equipment_profile(&server, &profile, json!({
+        "name": "Work gym",
+        "equipment_ids": [kettlebell.id],
+    })).await;
+
+    // Assert
+    response.assert_ok();
+    let profile = EquipmentProfile::find_by_pk(profile.id, database).await?;
+    assert_eq!("Work gym", profile.name);
+    assert_eq!(vec![kettlebell.id], profile.equipment_ids(database).await?);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let profile = EquipmentProfile::fake()
+        .user(server.user().unwrap())
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::delete_equipment_profile(&server, &profile).await;
+
+    // Assert
+    response.assert_no_content();
+    assert_eq!(0, EquipmentProfile::count(database).await?);
+
+    Ok(())
+}
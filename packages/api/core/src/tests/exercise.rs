@@ -1,13 +1,72 @@
 use crate::tests::actions::exercise as actions;
-use crate::enums::{ExerciseType, ExerciseForce, ExerciseMechanic, Measurement};
+use crate::enums::{ExerciseType, ExerciseForce, ExerciseMechanic, ExerciseMuscleTarget, Measurement, Table};
+use crate::error::ClientError;
 use crate::http::resources::{ExerciseResource, ModelResource};
-use crate::models::{Exercise, ExerciseEquipment, MuscleGroup};
+use crate::models::{EquipmentProfile, Exercise, ExerciseEquipment, ExerciseInstruction, ExerciseMuscleMap, Link, Muscle, MuscleGroup, Routine, RoutineExercise, Workout, WorkoutExercise};
 use crate::prelude::*;
 
+async fn map_muscles(
+    exercise: &Exercise,
+    muscles: &[(&Muscle, ExerciseMuscleTarget)],
+    database: &DatabaseManager,
+) -> Result<()> {
+    for (muscle, target) in muscles {
+        ExerciseMuscleMap::new()
+            .exercise(exercise)
+            .muscle(muscle)
+            .target(target.clone())
+            .create(database)
+            .await?;
+    }
+
+    Ok(())
+}
+
+async fn catalog(database: &DatabaseManager) -> Result<Vec<Exercise>> {
+    let mut exercises = Vec::new();
+
+    for (name, description) in [
+        ("Barbell Bench Press", "Press the barbell from your chest while lying on a flat bench."),
+        ("Dumbbell Curl", "Curl the dumbbells towards your shoulders."),
+        ("Dumbbell Hammer Curl", "Curl the dumbbells with a neutral grip."),
+        ("Barbell Back Squat", "Squat with the barbell resting on your upper back."),
+        ("Good Morning", "Bow forward with a barbell on your back."),
+    ] {
+        exercises.push(Exercise::new()
+            .exercise_type(ExerciseType::Strength)
+            .name(name)
+            .description(Some(description.to_string()))
+            .create(database)
+            .await?
+        );
+    }
+
+    ExerciseInstruction::new()
+        .exercise(&exercises[4])
+        .sequence_number(1)
+        .content("Hinge at the hips until your torso is almost parallel to the floor.")
+        .create(database)
+        .await?;
+
+    Ok(exercises)
+}
+
+fn listed_ids(response: &MockResponse) -> Vec<String> {
+    response.0.json::<Value>()["data"].as_array().unwrap()
+        .iter()
+        .map(|exercise| exercise["id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+fn sorted(mut ids: Vec<String>) -> Vec<String> {
+    ids.sort();
+    ids
+}
+
 #[sqlx::test]
 async fn create_success(pool: PgPool) -> Result<()> {
     // Arrange
-    let server = MockServer::authenticated(pool).await;
+    let server = MockServer::authenticated_admin(pool).await;
     let database = server.database();
     let current_count = Exercise::count(database).await?;
     let exercise_type = ExerciseType::Strength;
@@ -44,6 +103,40 @@ async fn create_success(pool: PgPool) -> Result<()> {
     Ok(())
 }
 
+#[sqlx::test]
+async fn create_does_not_keep_exercise_when_a_muscle_fails(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let database = server.database();
+    let current_count = Exercise::count(database).await?;
+    let muscle = Muscle::mocked(database).await?;
+    let payload = json!({
+        "type": ExerciseType::Strength,
+        "target_muscle_group_id": NULL,
+        "name": "Test Exercise",
+        "name_alternative": NULL,
+        "description": NULL,
+        "equipment_id": NULL,
+        "mechanic": NULL,
+        "force": NULL,
+        "measurement": NULL,
+        "muscles": [
+            { "muscle_id": muscle.id, "target": ExerciseMuscleTarget::Primary },
+            { "muscle_id": muscle.id + 1000, "target": ExerciseMuscleTarget::Secondary },
+        ],
+    });
+
+    // Act
+    let response = actions::create_exercise(&server, payload).await;
+
+    // Assert
+    response.assert_conflict();
+    assert_eq!(Exercise::count(database).await?, current_count);
+    assert_eq!(ExerciseMuscleMap::count(database).await?, 0);
+
+    Ok(())
+}
+
 #[sqlx::test]
 async fn create_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
     // Arrange
@@ -78,6 +171,45 @@ async fn create_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
     Ok(())
 }
 
+#[sqlx::test]
+async fn create_fails_if_not_admin(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let current_count = Exercise::count(database).await?;
+    let exercise_type = ExerciseType::Strength;
+    let equipment = ExerciseEquipment::mocked(database).await?;
+    let force = ExerciseForce::Pull;
+    let group = MuscleGroup::mocked(database).await?;
+    let mechanic = ExerciseMechanic::Compound;
+    let measurement = Measurement::WeightedRepetitions;
+    let payload = json!({
+        "type": exercise_type,
+        "target_muscle_group_id": Some(group.id),
+        "name": "Test Exercise",
+        "name_alternative": NULL,
+        "description": NULL,
+        "equipment_id": Some(equipment.id),
+        "mechanic": Some(mechanic),
+        "force": Some(force),
+        "measurement": Some(measurement),
+        "muscles": [],
+    });
+
+    // Act
+    let response = actions::create_exercise(&server, payload).await;
+
+    // Assert
+    response.assert_forbidden();
+    response.assert_json_error(
+        ClientError::UnauthorizedAction.to_string(),
+        __("errors.generic.http.403"),
+    );
+    assert_eq!(current_count, Exercise::count(database).await?);
+
+    Ok(())
+}
+
 #[sqlx::test]
 async fn read_success(pool: PgPool) -> Result<()> {
     // Arrange
@@ -113,6 +245,265 @@ async fn read_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
     Ok(())
 }
 
+#[sqlx::test]
+async fn update_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let database = server.database();
+    let exercise = Exercise::fake()
+        .name_alternative(Some("Old alternative".to_string()))
+        .create(database)
+        .await?;
+    let equipment = ExerciseEquipment::mocked(database).await?;
+    let payload = json!({
+        "type": ExerciseType::Stretch,
+        "name": "Renamed Exercise",
+        "name_alternative": NULL,
+        "equipment_id": equipment.id,
+        "force": ExerciseForce::Push,
+    });
+
+    // Act
+    let response = actions::update_exercise(&server, &exercise, payload).await;
+
+    // Assert
+    let updated = Exercise::find_by_pk(exercise.id, database).await?;
+    assert_eq!(ExerciseType::Stretch, updated.exercise_type);
+    assert_eq!("Renamed Exercise", updated.name);
+    assert!(updated.name_alternative.is_none());
+    assert_some_eq(equipment.id, updated.equipment_id);
+    assert_some_eq(ExerciseForce::Push, updated.force.clone());
+    assert_eq!(exercise.mechanic, updated.mechanic);
+    assert_eq!(exercise.measurement, updated.measurement);
+
+    let resource = ExerciseResource::default(updated, database).await?;
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_replaces_muscles_and_instructions(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let database = server.database();
+    let exercise = Exercise::mocked(database).await?;
+    let (old, primary, secondary) = (
+        Muscle::mocked(database).await?,
+        Muscle::mocked(database).await?,
+        Muscle::mocked(database).await?,
+    );
+    map_muscles(&exercise, &[(&old, ExerciseMuscleTarget::Primary)], database).await?;
+    ExerciseInstruction::new()
+        .exercise(&exercise)
+        .sequence_number(1)
+        .content("Old instruction.")
+        .create(database)
+        .await?;
+    let payload = json!({
+        "muscles": [
+            { "muscle_id": primary.id, "target": ExerciseMuscleTarget::Primary },
+            { "muscle_id": secondary.id, "target": ExerciseMuscleTarget::Secondary },
+        ],
+        "instructions": ["Grab the bar.", "Lift the bar."],
+    });
+
+    // Act
+    let response = actions::update_exercise(&server, &exercise, payload).await;
+
+    // Assert
+    response.assert_ok();
+
+    let ids = |muscles: Vec<Muscle>| muscles.into_iter().map(|muscle| muscle.id).collect::<Vec<i16>>();
+    assert_eq!(vec![primary.id], ids(exercise.primary_muscles(database).await?));
+    assert_eq!(vec![secondary.id], ids(exercise.secondary_muscles(database).await?));
+
+    let instructions = exercise.instructions(database).await?
+        .into_iter()
+        .map(|instruction| (instruction.sequence_number, instruction.content))
+        .collect::<Vec<(i16, String)>>();
+    assert_eq!(vec![(1, "Grab the bar.".to_string()), (2, "Lift the bar.".to_string())], instructions);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_does_not_keep_changes_when_a_muscle_fails(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let database = server.database();
+    let exercise = Exercise::mocked(database).await?;
+    let muscle = Muscle::mocked(database).await?;
+    map_muscles(&exercise, &[(&muscle, ExerciseMuscleTarget::Primary)], database).await?;
+    let payload = json!({
+        "name": "Renamed Exercise",
+        "muscles": [
+            { "muscle_id": muscle.id + 1000, "target": ExerciseMuscleTarget::Primary },
+        ],
+    });
+
+    // Act
+    let response = actions::update_exercise(&server, &exercise, payload).await;
+
+    // Assert
+    response.assert_conflict();
+    assert_eq!(exercise.name, Exercise::find_by_pk(exercise.id, database).await?.name);
+    assert_eq!(1, exercise.primary_muscles(database).await?.len());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_fails_if_not_admin(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let exercise = Exercise::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::update_exercise(&server, &exercise, json!({ "name": "Renamed Exercise" })).await;
+
+    // Assert
+    response.assert_forbidden();
+    assert_eq!(exercise.name, Exercise::find_by_pk(exercise.id, server.database()).await?.name);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_success_as_moderator(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_moderator(pool).await;
+    let exercise = Exercise::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::update_exercise(&server, &exercise, json!({ "name": "Renamed Exercise" })).await;
+
+    // Assert
+    response.assert_ok();
+    assert_eq!("Renamed Exercise", Exercise::find_by_pk(exercise.id, server.database()).await?.name);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_fails_if_not_found(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let exercise = Exercise::mocked(server.database()).await?;
+    exercise.clone().delete(server.database()).await?;
+
+    // Act
+    let response = actions::update_exercise(&server, &exercise, json!({ "name": "Renamed Exercise" })).await;
+
+    // Assert
+    response.assert_not_found();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let database = server.database();
+    let exercise = Exercise::mocked(database).await?;
+    map_muscles(&exercise, &[(&Muscle::mocked(database).await?, ExerciseMuscleTarget::Primary)], database).await?;
+    Link::fake()
+        .model(Table::Exercises, exercise.id)
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::delete_exercise(&server, &exercise).await;
+
+    // Assert
+    response.assert_no_content();
+    assert!(Exercise::find_by_pk(exercise.id, database).await.is_err());
+    assert_eq!(0, ExerciseMuscleMap::count(database).await?);
+    assert_eq!(0, Link::count(database).await?);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_fails_if_not_admin(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let exercise = Exercise::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::delete_exercise(&server, &exercise).await;
+
+    // Assert
+    response.assert_forbidden();
+    assert!(Exercise::find_by_pk(exercise.id, server.database()).await.is_ok());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_fails_as_moderator(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_moderator(pool).await;
+    let exercise = Exercise::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::delete_exercise(&server, &exercise).await;
+
+    // Assert
+    response.assert_forbidden();
+    assert!(Exercise::find_by_pk(exercise.id, server.database()).await.is_ok());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_fails_if_a_routine_includes_the_exercise(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let database = server.database();
+    let exercise = Exercise::mocked(database).await?;
+    RoutineExercise::fake()
+        .routine(&Routine::mocked(database).await?)
+        .exercise(&exercise)
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::delete_exercise(&server, &exercise).await;
+
+    // Assert
+    response.assert_conflict();
+    assert!(Exercise::find_by_pk(exercise.id, database).await.is_ok());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_fails_if_a_workout_includes_the_exercise(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let database = server.database();
+    let exercise = Exercise::mocked(database).await?;
+    WorkoutExercise::fake()
+        .workout(&Workout::mocked(database).await?)
+        .exercise(&exercise)
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::delete_exercise(&server, &exercise).await;
+
+    // Assert
+    response.assert_conflict();
+
+    Ok(())
+}
+
 #[sqlx::test]
 async fn list_success(pool: PgPool) -> Result<()> {
     // Arrange
@@ -148,6 +539,289 @@ async fn list_exercises_with_specific_muscle_group(pool: PgPool) -> Result<()> {
     Ok(())
 }
 
+#[sqlx::test]
+async fn list_resources_match_simple_resources(pool: PgPool) -> Result<()> {
+    // Arrange
+    let database = DatabaseManager::from_pool(pool);
+    let group = MuscleGroup::mocked(&database).await?;
+    let chest = Muscle::fake().group(&group).create(&database).await?;
+    let triceps = Muscle::fake().group(&group).create(&database).await?;
+    let barbell = ExerciseEquipment::mocked(&database).await?;
+    let bench_press = Exercise::fake()
+        .target_muscle_group_id(Some(group.id))
+        .equipment(&barbell)
+        .create(&database)
+        .await?;
+    let dips = Exercise::fake().create(&database).await?;
+    map_muscles(&bench_press, &[
+        (&chest, ExerciseMuscleTarget::Primary),
+        (&triceps, ExerciseMuscleTarget::Secondary),
+    ], &database).await?;
+    map_muscles(&dips, &[
+        (&triceps, ExerciseMuscleTarget::Primary),
+        (&chest, ExerciseMuscleTarget::Primary),
+    ], &database).await?;
+    let exercises = vec![bench_press, dips];
+
+    // Act
+    let listed = ExerciseResource::list(exercises.clone(), &database).await?;
+
+    // Assert
+    assert_eq!(exercises.len(), listed.len());
+    for (exercise, resource) in exercises.into_iter().zip(listed) {
+        let expected = ExerciseResource::simple(exercise, &database).await?;
+        assert_eq!(serde_json::to_value(expected)?, serde_json::to_value(resource)?);
+    }
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_exercises_available_in_equipment_profile(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let owned = ExerciseEquipment::mocked(database).await?;
+    let missing = ExerciseEquipment::mocked(database).await?;
+    let bodyweight = ExerciseEquipment::find("name", ExerciseEquipment::BODYWEIGHT, database).await?;
+    let profile = EquipmentProfile::fake()
+        .user(server.user().unwrap())
+        .create(database)
+        .await?;
+    profile.sync_equipment(&[owned.id], database).await?;
+    let with_owned = Exercise::fake().equipment(&owned).create(database).await?;
+    let with_missing = Exercise::fake().equipment(&missing).create(database).await?;
+    let with_bodyweight = Exercise::fake().equipment(&bodyweight).create(database).await?;
+    let without_equipment = Exercise::fake().create(database).await?;
+
+    // Act
+    let response = actions::list_exercises(&server, Some(json!({
+        "available_in": profile.ulid,
+        "per_page": 100,
+        "page": 1,
+    }))).await;
+
+    // Assert
+    response.assert_ok();
+    let ids = response.0.json::<Value>()["data"].as_array().unwrap()
+        .iter()
+        .map(|exercise| exercise["id"].as_str().unwrap().to_string())
+        .collect::<Vec<String>>();
+    assert!(ids.contains(&with_owned.ulid));
+    assert!(ids.contains(&with_bodyweight.ulid));
+    assert!(ids.contains(&without_equipment.ulid));
+    assert!(!ids.contains(&with_missing.ulid));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_fails_for_another_users_equipment_profile(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let profile = EquipmentProfile::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::list_exercises(&server, Some(json!({
+        "available_in": profile.ulid,
+    }))).await;
+
+    // Assert
+    response.assert_not_found();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_exercises_with_muscle_includes_child_muscles(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let group = MuscleGroup::mocked(database).await?;
+    let parent = Muscle::fake().group(&group).create(database).await?;
+    let child = Muscle::fake().group(&group).parent(Some(&parent)).create(database).await?;
+    let other = Muscle::mocked(database).await?;
+    let targets_parent = Exercise::mocked(database).await?;
+    let targets_child = Exercise::mocked(database).await?;
+    let targets_other = Exercise::mocked(database).await?;
+    map_muscles(&targets_parent, &[(&parent, ExerciseMuscleTarget::Primary)], database).await?;
+    map_muscles(&targets_child, &[(&child, ExerciseMuscleTarget::Secondary)], database).await?;
+    map_muscles(&targets_other, &[(&other, ExerciseMuscleTarget::Primary)], database).await?;
+
+    // Act
+    let response = actions::list_exercises(&server, Some(json!({
+        "muscle": parent.id,
+    }))).await;
+
+    // Assert
+    response.assert_ok();
+    assert_eq!(
+        sorted(vec![targets_parent.ulid, targets_child.ulid]),
+        sorted(listed_ids(&response)),
+    );
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_exercises_with_multiple_values(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let strength = Exercise::fake().exercise_type(ExerciseType::Strength).create(database).await?;
+    let stretch = Exercise::fake().exercise_type(ExerciseType::Stretch).create(database).await?;
+    Exercise::fake().exercise_type(ExerciseType::Class).create(database).await?;
+
+    // Act
+    let response = actions::list_exercises(&server, Some(json!({
+        "type": "strength,stretch",
+    }))).await;
+
+    // Assert
+    response.assert_ok();
+    assert_eq!(sorted(vec![strength.ulid, stretch.ulid]), sorted(listed_ids(&response)));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_exercises_with_combined_filters(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let barbell = ExerciseEquipment::mocked(database).await?;
+    let dumbbell = ExerciseEquipment::mocked(database).await?;
+    let matching = Exercise::fake()
+        .equipment(&barbell)
+        .mechanic(Some(ExerciseMechanic::Compound))
+        .force(Some(ExerciseForce::Push))
+        .measurement(Some(Measurement::WeightedRepetitions))
+        .create(database)
+        .await?;
+    Exercise::fake()
+        .equipment(&dumbbell)
+        .mechanic(Some(ExerciseMechanic::Compound))
+        .force(Some(ExerciseForce::Push))
+        .measurement(Some(Measurement::WeightedRepetitions))
+        .create(database)
+        .await?;
+    Exercise::fake()
+        .equipment(&barbell)
+        .mechanic(Some(ExerciseMechanic::Isolation))
+        .force(Some(ExerciseForce::Push))
+        .measurement(Some(Measurement::WeightedRepetitions))
+        .create(database)
+        .await?;
+    Exercise::fake()
+        .equipment(&barbell)
+        .mechanic(Some(ExerciseMechanic::Compound))
+        .force(Some(ExerciseForce::Pull))
+        .measurement(Some(Measurement::WeightedRepetitions))
+        .create(database)
+        .await?;
+    Exercise::fake()
+        .equipment(&barbell)
+        .mechanic(Some(ExerciseMechanic::Compound))
+        .force(Some(ExerciseForce::Push))
+        .measurement(Some(Measurement::Repetitions))
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::list_exercises(&server, Some(json!({
+        "equipment": barbell.id,
+        "mechanic": "compound",
+        "force": "push",
+        "measurement": "weighted_repetitions,weighted_duration",
+    }))).await;
+
+    // Assert
+    response.assert_ok();
+    assert_eq!(vec![matching.ulid], listed_ids(&response));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_exercises_with_search_matches_alternative_name(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let bench = Exercise::new()
+        .exercise_type(ExerciseType::Strength)
+        .name("Barbell Bench Press")
+        .name_alternative(Some("Flat Bench".to_string()))
+        .create(database)
+        .await?;
+    let incline = Exercise::new()
+        .exercise_type(ExerciseType::Strength)
+        .name("Incline Dumbbell Press")
+        .create(database)
+        .await?;
+    Exercise::new()
+        .exercise_type(ExerciseType::Strength)
+        .name("Barbell Row")
+        .create(database)
+        .await?;
+
+    // Act
+    let alternative = actions::list_exercises(&server, Some(json!({ "search": "flat" }))).await;
+    let name = actions::list_exercises(&server, Some(json!({ "search": "PRESS" }))).await;
+    let wildcard = actions::list_exercises(&server, Some(json!({ "search": "%" }))).await;
+
+    // Assert
+    alternative.assert_ok();
+    name.assert_ok();
+    wildcard.assert_ok();
+    assert_eq!(vec![bench.ulid.clone()], listed_ids(&alternative));
+    assert_eq!(vec![bench.ulid, incline.ulid], listed_ids(&name));
+    assert!(listed_ids(&wildcard).is_empty());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_exercises_with_sort(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+
+    for name in ["Deadlift", "Arnold Press", "Chin Up"] {
+        Exercise::new()
+            .exercise_type(ExerciseType::Strength)
+            .name(name)
+            .create(database)
+            .await?;
+    }
+
+    // Act
+    let response = actions::list_exercises(&server, Some(json!({ "sort": "-name" }))).await;
+
+    // Assert
+    response.assert_ok();
+    let listed = response.0.json::<Value>()["data"].as_array().unwrap()
+        .iter()
+        .map(|exercise| exercise["name"].as_str().unwrap().to_string())
+        .collect::<Vec<String>>();
+    assert_eq!(vec!["Deadlift", "Chin Up", "Arnold Press"], listed);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_fails_with_unknown_sort(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+
+    // Act
+    let response = actions::list_exercises(&server, Some(json!({ "sort": "id; DROP TABLE exercises" }))).await;
+
+    // Assert
+    response.assert_bad_request();
+
+    Ok(())
+}
+
 #[sqlx::test]
 async fn list_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
     // Arrange
@@ -161,3 +835,219 @@ async fn list_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
 
     Ok(())
 }
+
+#[sqlx::test]
+async fn alternatives_are_ranked_by_muscle_overlap(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let group = MuscleGroup::mocked(database).await?;
+    let chest = Muscle::mocked(database).await?;
+    let triceps = Muscle::mocked(database).await?;
+    let biceps = Muscle::mocked(database).await?;
+    let exercise = Exercise::fake()
+        .target_muscle_group(&group)
+        .mechanic(Some(ExerciseMechanic::Compound))
+        .force(Some(ExerciseForce::Push))
+        .create(database)
+        .await?;
+    let close = Exercise::fake()
+        .target_muscle_group(&group)
+        .mechanic(Some(ExerciseMechanic::Compound))
+        .force(Some(ExerciseForce::Push))
+        .create(database)
+        .await?;
+    let partial = Exercise::fake()
+        .mechanic(Some(ExerciseMechanic::Isolation))
+        .create(database)
+        .await?;
+    let unrelated = Exercise::mocked(database).await?;
+    let tertiary = Exercise::mocked(database).await?;
+    map_muscles(&exercise, &[(&chest, ExerciseMuscleTarget::Primary), (&triceps, ExerciseMuscleTarget::Secondary)], database).await?;
+    map_muscles(&close, &[(&chest, ExerciseMuscleTarget::Primary), (&triceps, ExerciseMuscleTarget::Secondary)], database).await?;
+    map_muscles(&partial, &[(&triceps, ExerciseMuscleTarget::Primary)], database).await?;
+    map_muscles(&unrelated, &[(&biceps, ExerciseMuscleTarget::Primary)], database).await?;
+    map_muscles(&tertiary, &[(&chest, ExerciseMuscleTarget::Tertiary)], database).await?;
+
+    // Act
+    let response = actions::list_exercise_alternatives(&server, &exercise, json!({})).await;
+
+    // Assert
+    response.assert_ok();
+    let alternatives = response.0.json::<Value>()["data"].as_array().unwrap()
+        .iter()
+        .map(|alternative| (alternative["exercise"]["id"].clone(), alternative["score"].clone()))
+        .collect::<Vec<(Value, Value)>>();
+    assert_eq!(vec![
+        (json!(close.ulid), json!(9.0)),
+        (json!(partial.ulid), json!(2.0)),
+    ], alternatives);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn alternatives_filtered_by_equipment(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let muscle = Muscle::mocked(database).await?;
+    let barbell = ExerciseEquipment::mocked(database).await?;
+    let dumbbell = ExerciseEquipment::mocked(database).await?;
+    let cable = ExerciseEquipment::mocked(database).await?;
+    let exercise = Exercise::fake().equipment(&barbell).create(database).await?;
+    let with_barbell = Exercise::fake().equipment(&barbell).create(database).await?;
+    let with_dumbbell = Exercise::fake().equipment(&dumbbell).create(database).await?;
+    let with_cable = Exercise::fake().equipment(&cable).create(database).await?;
+
+    for exercise in [&exercise, &with_barbell, &with_dumbbell, &with_cable] {
+        map_muscles(exercise, &[(&muscle, ExerciseMuscleTarget::Primary)], database).await?;
+    }
+
+    // Act
+    let response = actions::list_exercise_alternatives(&server, &exercise, json!({
+        "equipment": format!("{},{}", dumbbell.id, cable.id),
+    })).await;
+
+    // Assert
+    response.assert_ok();
+    let mut ids = response.0.json::<Value>()["data"].as_array().unwrap()
+        .iter()
+        .map(|alternative| alternative["exercise"]["id"].as_str().unwrap().to_string())
+        .collect::<Vec<String>>();
+    let mut expected = vec![with_dumbbell.ulid, with_cable.ulid];
+    ids.sort();
+    expected.sort();
+    assert_eq!(expected, ids);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn alternatives_fail_for_invalid_equipment(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let exercise = Exercise::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::list_exercise_alternatives(&server, &exercise, json!({
+        "equipment": "barbell",
+    })).await;
+
+    // Assert
+    response.assert_bad_request();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn alternatives_fail_if_not_authenticated(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::init(pool).await;
+    let exercise = Exercise::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::list_exercise_alternatives(&server, &exercise, json!({})).await;
+
+    // Assert
+    response.assert_unauthorized();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn search_matches_names_without_spaces(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let exercises = catalog(server.database()).await?;
+
+    // Act
+    let response = actions::search_exercises(&server, "benchpress").await;
+
+    // Assert
+    response.assert_ok();
+    assert_eq!(Some(&exercises[0].ulid), listed_ids(&response).first());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn search_expands_abbreviations_and_ranks_results(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let exercises = catalog(server.database()).await?;
+
+    // Act
+    let response = actions::search_exercises(&server, "db curl").await;
+
+    // Assert
+    response.assert_ok();
+    assert_eq!(
+        vec![exercises[1].ulid.clone(), exercises[2].ulid.clone()],
+        listed_ids(&response),
+    );
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn search_tolerates_typos(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let exercises = catalog(server.database()).await?;
+
+    // Act
+    let response = actions::search_exercises(&server, "dumbel hammer curl").await;
+
+    // Assert
+    response.assert_ok();
+    assert_eq!(Some(&exercises[2].ulid), listed_ids(&response).first());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn search_matches_instructions(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let exercises = catalog(server.database()).await?;
+
+    // Act
+    let response = actions::search_exercises(&server, "hinge hips").await;
+
+    // Assert
+    response.assert_ok();
+    assert_eq!(vec![exercises[4].ulid.clone()], listed_ids(&response));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn search_with_blank_query_returns_nothing(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    catalog(server.database()).await?;
+
+    // Act
+    let response = actions::search_exercises(&server, "  ").await;
+
+    // Assert
+    response.assert_ok();
+    assert!(listed_ids(&response).is_empty());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn search_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::init(pool).await;
+
+    // Act
+    let response = actions::search_exercises(&server, "bench").await;
+
+    // Assert
+    response.assert_unauthorized();
+
+    Ok(())
+}
@@ -1,12 +1,12 @@
 use crate::tests::actions::exercise_equipment as actions;
 use crate::http::resources::{ModelResource, ExerciseEquipmentResource};
-use crate::models::ExerciseEquipment;
+use crate::models::{EquipmentProfile, Exercise, ExerciseEquipment};
 use crate::prelude::*;
 
 #[sqlx::test]
 async fn create_success(pool: PgPool) -> Result<()> {
     // Arrange
-    let server = MockServer::authenticated(pool).await;
+    let server = MockServer::authenticated_admin(pool).await;
     let payload = json!({
         "name": "Test Equipment",
     });
@@ -43,6 +43,23 @@ async fn create_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
     Ok(())
 }
 
+#[sqlx::test]
+async fn create_fails_if_not_admin(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let payload = json!({
+        "name": "Test Equipment",
+    });
+
+    // Act
+    let response = actions::create_exercise_equipment(&server, payload).await;
+
+    // Assert
+    response.assert_forbidden();
+
+    Ok(())
+}
+
 #[sqlx::test]
 async fn read_success(pool: PgPool) -> Result<()> {
     // Arrange
@@ -105,3 +122,113 @@ async fn list_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
 
     Ok(())
 }
+
+#[sqlx::test]
+async fn update_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let equipment = ExerciseEquipment::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::update_exercise_equipment(&server, &equipment, json!({ "name": "Renamed Equipment" })).await;
+
+    // Assert
+    let equipment = ExerciseEquipment::find_by_pk(equipment.id, server.database()).await?;
+    assert_eq!("Renamed Equipment", equipment.name);
+
+    let resource = ExerciseEquipmentResource::default(equipment, server.database()).await?;
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_fails_if_name_already_exists(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let equipment = ExerciseEquipment::mocked(server.database()).await?;
+    let other = ExerciseEquipment::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::update_exercise_equipment(&server, &equipment, json!({ "name": other.name })).await;
+
+    // Assert
+    response.assert_conflict();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let equipment = ExerciseEquipment::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::delete_exercise_equipment(&server, &equipment).await;
+
+    // Assert
+    response.assert_no_content();
+    assert!(ExerciseEquipment::find_by_pk(equipment.id, server.database()).await.is_err());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_fails_if_an_exercise_needs_the_equipment(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let equipment = ExerciseEquipment::mocked(server.database()).await?;
+    Exercise::fake()
+        .equipment(&equipment)
+        .create(server.database())
+        .await?;
+
+    // Act
+    let response = actions::delete_exercise_equipment(&server, &equipment).await;
+
+    // Assert
+    response.assert_conflict();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_fails_if_an_equipment_profile_contains_the_equipment(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let equipment = ExerciseEquipment::mocked(server.database()).await?;
+    EquipmentProfile::mocked(server.database())
+        .await?
+        .sync_equipment(&[equipment.id], server.database())
+        .await?;
+
+    // Act
+    let response = actions::delete_exercise_equipment(&server, &equipment).await;
+
+    // Assert
+    response.assert_conflict();
+    assert!(ExerciseEquipment::find_by_pk(equipment.id, server.database()).await.is_ok());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_fails_if_not_found(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let equipment = ExerciseEquipment::mocked(server.database()).await?;
+    equipment.clone().delete(server.database()).await?;
+
+    // Act
+    let response = actions::delete_exercise_equipment(&server, &equipment).await;
+
+    // Assert
+    response.assert_not_found();
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use super::actions::link as actions;
+use crate::enums::{LinkFormat, LinkType};
+use crate::http::resources::{LinkResource, ModelResource};
+use crate::models::Link;
+use crate::prelude::*;
+
+#[sqlx::test]
+async fn read_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let link = Link::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::read_link(&server, &link).await;
+
+    // Assert
+    let resource = LinkResource::default(link, server.database()).await?;
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let link = Link::fake()
+        .description(Some("Old description"))
+        .create(server.database())
+        .await?;
+    let payload = json!({
+        "type": LinkType::Video,
+        "format": LinkFormat::Mp4,
+        "label": "New label",
+        "description": NULL,
+    });
+
+    // Act
+    let response = actions::update_link(&server, &link, payload).await;
+
+    // Assert
+    let updated = Link::find_by_pk(link.id, server.database()).await?;
+    assert_eq!(LinkType::Video, updated.link_type);
+    assert_eq!(LinkFormat::Mp4, updated.format);
+    assert_eq!("New label", updated.label);
+    assert!(updated.description.is_none());
+    assert_eq!(link.src, updated.src);
+
+    let resource = LinkResource::default(updated, server.database()).await?;
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::init(pool).await;
+    let link = Link::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::update_link(&server, &link, json!({ "label": "New label" })).await;
+
+    // Assert
+    response.assert_unauthorized();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_fails_if_not_admin(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let link = Link::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::update_link(&server, &link, json!({ "label": "New label" })).await;
+
+    // Assert
+    response.assert_forbidden();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let link = Link::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::delete_link(&server, &link).await;
+
+    // Assert
+    response.assert_no_content();
+    assert!(Link::find_by_pk(link.id, server.database()).await.is_err());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_fails_if_not_found(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let link = Link::mocked(server.database()).await?;
+    link.clone().delete(server.database()).await?;
+
+    // Act
+    let response = actions::delete_link(&server, &link).await;
+
+    // Assert
+    response.assert_not_found();
+
+    Ok(())
+}
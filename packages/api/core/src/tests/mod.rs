@@ -5,3 +5,12 @@ mod exercise;
 mod muscle_group;
 mod muscle;
 mod exercise_equipment;
+mod link;
+mod workout;
+mod routine;
+mod program;
+mod personal_record;
+mod analytics;
+mod equipment_profile;
+mod coaching;
+mod user;
@@ -1,12 +1,13 @@
 use super::actions::muscle as actions;
 use crate::http::resources::{ModelResource, MuscleResource};
-use crate::models::{Muscle, MuscleGroup};
+use crate::enums::{ExerciseMuscleTarget, Table};
+use crate::models::{Exercise, ExerciseMuscleMap, ExerciseSourceMapping, Link, Muscle, MuscleGroup};
 use crate::prelude::*;
 
 #[sqlx::test]
 async fn create_success(pool: PgPool) -> Result<()> {
     // Arrange
-    let server = MockServer::authenticated(pool).await;
+    let server = MockServer::authenticated_admin(pool).await;
     let group = MuscleGroup::mocked(server.database()).await?;
     let payload = json!({
         "group_id": group.id,
@@ -49,6 +50,29 @@ async fn create_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
     Ok(())
 }
 
+#[sqlx::test]
+async fn create_fails_if_not_admin(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let group = MuscleGroup::mocked(server.database()).await?;
+    let payload = json!({
+        "group_id": group.id,
+        "parent_id": NULL,
+        "name": "Test Muscle",
+        "simple_name": NULL,
+        "description": NULL,
+        "image_source": NULL,
+    });
+
+    // Act
+    let response = actions::create_muscle(&server, payload).await;
+
+    // Assert
+    response.assert_forbidden();
+
+    Ok(())
+}
+
 #[sqlx::test]
 async fn read_success(pool: PgPool) -> Result<()> {
     // Arrange
@@ -112,3 +136,147 @@ async fn list_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
 
     Ok(())
 }
+
+#[sqlx::test]
+async fn update_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let database = server.database();
+    let muscle = Muscle::fake()
+        .description(Some("Old description"))
+        .create(database)
+        .await?;
+    let parent = Muscle::mocked(database).await?;
+    let group = MuscleGroup::mocked(database).await?;
+    let payload = json!({
+        "group_id": group.id,
+        "parent_id": parent.id,
+        "name": "Renamed Muscle",
+        "simple_name": "Renamed",
+        "description": NULL,
+    });
+
+    // Act
+    let response = actions::update_muscle(&server, &muscle, payload).await;
+
+    // Assert
+    let muscle = Muscle::find_by_pk(muscle.id, database).await?;
+    assert_eq!(group.id, muscle.group_id);
+    assert_some_eq(parent.id, muscle.parent_id);
+    assert_eq!("Renamed Muscle", muscle.name);
+    assert_some_eq("Renamed", muscle.simple_name.clone());
+    assert!(muscle.description.is_none());
+
+    let resource = MuscleResource::default(muscle, database).await?;
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_fails_with_unknown_muscle_group(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let muscle = Muscle::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::update_muscle(&server, &muscle, json!({ "group_id": i16::MAX })).await;
+
+    // Assert
+    response.assert_conflict();
+    assert_eq!(muscle.group_id, Muscle::find_by_pk(muscle.id, server.database()).await?.group_id);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_fails_if_not_found(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let muscle = Muscle::mocked(server.database()).await?;
+    muscle.clone().delete(server.database()).await?;
+
+    // Act
+    let response = actions::update_muscle(&server, &muscle, json!({ "name": "Renamed Muscle" })).await;
+
+    // Assert
+    response.assert_not_found();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let database = server.database();
+    let muscle = Muscle::mocked(database).await?;
+    Link::fake()
+        .model(Table::Muscles, muscle.id)
+        .create(database)
+        .await?;
+    ExerciseSourceMapping::new()
+        .source("test")
+        .model(Table::Muscles, muscle.id)
+        .external_id(1)
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::delete_muscle(&server, &muscle).await;
+
+    // Assert
+    response.assert_no_content();
+    assert!(Muscle::find_by_pk(muscle.id, database).await.is_err());
+    assert!(muscle.links(database).await?.is_empty());
+    assert!(ExerciseSourceMapping::for_source("test", database).await?.is_empty());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_fails_if_an_exercise_targets_the_muscle(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let database = server.database();
+    let muscle = Muscle::mocked(database).await?;
+    ExerciseMuscleMap::new()
+        .exercise(&Exercise::mocked(database).await?)
+        .muscle(&muscle)
+        .target(ExerciseMuscleTarget::Primary)
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::delete_muscle(&server, &muscle).await;
+
+    // Assert
+    response.assert_conflict();
+    assert!(Muscle::find_by_pk(muscle.id, database).await.is_ok());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_fails_if_muscles_are_nested_under_the_muscle(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let database = server.database();
+    let muscle = Muscle::mocked(database).await?;
+    Muscle::fake()
+        .parent(Some(&muscle))
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::delete_muscle(&server, &muscle).await;
+
+    // Assert
+    response.assert_conflict();
+
+    Ok(())
+}
@@ -1,12 +1,13 @@
 use super::actions::muscle_group as actions;
 use crate::http::resources::{ModelResource, MuscleGroupResource};
-use crate::models::MuscleGroup;
+use crate::enums::Table;
+use crate::models::{Link, Muscle, MuscleGroup};
 use crate::prelude::*;
 
 #[sqlx::test]
 async fn create_success(pool: PgPool) -> Result<()> {
     // Arrange
-    let server = MockServer::authenticated(pool).await;
+    let server = MockServer::authenticated_admin(pool).await;
     let payload = json!({
         "name": "Test Group",
         "image_source": None::<()>,
@@ -49,9 +50,27 @@ async fn create_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
 }
 
 #[sqlx::test]
-async fn create_fails_if_name_already_exists(pool: PgPool) -> Result<()> {
+async fn create_fails_if_not_admin(pool: PgPool) -> Result<()> {
     // Arrange
     let server = MockServer::authenticated(pool).await;
+    let payload = json!({
+        "name": "Test Group",
+        "image_source": None::<()>,
+    });
+
+    // Act
+    let response = actions::create_muscle_group(&server, payload).await;
+
+    // Assert
+    response.assert_forbidden();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_fails_if_name_already_exists(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
 
     MuscleGroup::new()
         .name("Test Group")
@@ -67,7 +86,7 @@ async fn create_fails_if_name_already_exists(pool: PgPool) -> Result<()> {
     let response = actions::create_muscle_group(&server, payload).await;
 
     // Assert
-    response.assert_unprocessable();
+    response.assert_conflict();
 
     Ok(())
 }
@@ -138,3 +157,125 @@ async fn list_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
 
     Ok(())
 }
+
+#[sqlx::test]
+async fn update_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let group = MuscleGroup::fake()
+        .image_source("group.png")
+        .create(server.database())
+        .await?;
+    let payload = json!({
+        "name": "Renamed Group",
+        "image_source": None::<()>,
+    });
+
+    // Act
+    let response = actions::update_muscle_group(&server, group.id, payload).await;
+
+    // Assert
+    let group = MuscleGroup::find_by_pk(group.id, server.database()).await?;
+    assert_eq!("Renamed Group", group.name);
+    assert!(group.image_source.is_none());
+
+    let resource = MuscleGroupResource::default(group, server.database()).await?;
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_keeps_fields_that_are_left_out(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let group = MuscleGroup::fake()
+        .image_source("group.png")
+        .create(server.database())
+        .await?;
+
+    // Act
+    let response = actions::update_muscle_group(&server, group.id, json!({ "name": "Renamed Group" })).await;
+
+    // Assert
+    response.assert_ok();
+
+    let group = MuscleGroup::find_by_pk(group.id, server.database()).await?;
+    assert_eq!("Renamed Group", group.name);
+    assert_some_eq("group.png", group.image_source);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_fails_if_not_found(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+
+    // Act
+    let response = actions::update_muscle_group(&server, i16::MAX, json!({ "name": "Renamed Group" })).await;
+
+    // Assert
+    response.assert_not_found();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let group = MuscleGroup::mocked(server.database()).await?;
+    Link::fake()
+        .model(Table::MuscleGroups, group.id)
+        .create(server.database())
+        .await?;
+
+    // Act
+    let response = actions::delete_muscle_group(&server, group.id).await;
+
+    // Assert
+    response.assert_no_content();
+    assert!(MuscleGroup::find_by_pk(group.id, server.database()).await.is_err());
+    assert!(Link::all(server.database()).await?.is_empty());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_fails_if_muscles_belong_to_the_group(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let group = MuscleGroup::mocked(server.database()).await?;
+    Muscle::fake()
+        .group(&group)
+        .create(server.database())
+        .await?;
+
+    // Act
+    let response = actions::delete_muscle_group(&server, group.id).await;
+
+    // Assert
+    response.assert_conflict();
+    assert!(MuscleGroup::find_by_pk(group.id, server.database()).await.is_ok());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_fails_if_not_found(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+
+    // Act
+    let response = actions::delete_muscle_group(&server, i16::MAX).await;
+
+    // Assert
+    response.assert_not_found();
+
+    Ok(())
+}
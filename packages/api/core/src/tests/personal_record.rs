@@ -0,0 +1,207 @@
+use crate::enums::{Measurement, PersonalRecordType};
+use crate::tests::actions::personal_record as actions;
+use crate::tests::actions::workout as workout_actions;
+use crate::http::resources::{ModelResource, PersonalRecordResource};
+use crate::models::{Exercise, PersonalRecord, User, Workout, WorkoutExercise};
+use crate::prelude::*;
+
+async fn workout_exercise_for(
+    user: &User,
+    measurement: Measurement,
+    database: &DatabaseManager,
+) -> Result<(Workout, WorkoutExercise)> {
+    let workout = Workout::fake()
+        .user(user)
+        .create(database)
+        .await?;
+    let exercise = Exercise::fake()
+        .measurement(Some(measurement))
+        .create(database)
+        .await?;
+    let workout_exercise = WorkoutExercise::fake()
+        .workout(&workout)
+        .exercise(&exercise)
+        .create(database)
+        .await?;
+
+    Ok((workout, workout_exercise))
+}
+
+#[sqlx::test]
+async fn log_set_records_personal_records(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let user = server.user().unwrap();
+    let (workout, workout_exercise) = workout_exercise_for(user, Measurement::WeightedRepetitions, database).await?;
+
+    // Act
+    let first = workout_actions::log_set(&server, &workout, &workout_exercise, json!({
+        "weight": 100.0,
+        "repetitions": 5,
+    })).await;
+    let lighter = workout_actions::log_set(&server, &workout, &workout_exercise, json!({
+        "weight": 90.0,
+        "repetitions": 5,
+    })).await;
+    let more_repetitions = workout_actions::log_set(&server, &workout, &workout_exercise, json!({
+        "weight": 100.0,
+        "repetitions": 6,
+    })).await;
+
+    // Assert
+    first.assert_created();
+    lighter.assert_created();
+    more_repetitions.assert_created();
+
+    let first = first.0.json::<Value>();
+    let lighter = lighter.0.json::<Value>();
+    let more_repetitions = more_repetitions.0.json::<Value>();
+    assert_eq!(3, first["data"]["personal_records"].as_array().unwrap().len());
+    assert_eq!(json!([{
+        "id": lighter["data"]["personal_records"][0]["id"],
+        "type": "most_repetitions",
+        "value": 5.0,
+        "unit": "repetitions",
+        "weight": 90.0,
+        "achieved_at": lighter["data"]["created_at"],
+    }]), lighter["data"]["personal_records"]);
+    assert_eq!(2, more_repetitions["data"]["personal_records"].as_array().unwrap().len());
+    assert_eq!(120.0, more_repetitions["data"]["personal_records"][1]["value"]);
+
+    let timeline = PersonalRecord::timeline(user.id, workout_exercise.exercise_id, database).await?;
+    assert_eq!(6, timeline.len());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn log_set_records_longest_duration(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let user = server.user().unwrap();
+    let (workout, workout_exercise) = workout_exercise_for(user, Measurement::Duration, database).await?;
+
+    // Act
+    for duration in [60, 45, 90] {
+        workout_actions::log_set(&server, &workout, &workout_exercise, json!({
+            "duration": duration,
+        })).await.assert_created();
+    }
+
+    // Assert
+    let timeline = PersonalRecord::timeline(user.id, workout_exercise.exercise_id, database).await?;
+    let values = timeline.iter()
+        .map(|record| (record.record_type.clone(), record.value))
+        .collect::<Vec<_>>();
+    assert_eq!(vec![
+        (PersonalRecordType::LongestDuration, 60.0),
+        (PersonalRecordType::LongestDuration, 90.0),
+    ], values);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_only_includes_current_records(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let user = server.user().unwrap();
+    let (workout, workout_exercise) = workout_exercise_for(user, Measurement::WeightedDuration, database).await?;
+    PersonalRecord::mocked(database).await?;
+
+    for weight in [20.0, 25.0] {
+        workout_actions::log_set(&server, &workout, &workout_exercise, json!({
+            "weight": weight,
+            "duration": 30,
+        })).await.assert_created();
+    }
+
+    // Act
+    let response = actions::list_personal_records(&server).await;
+
+    // Assert
+    let records = PersonalRecord::current_for_user(user.id, database).await?;
+    assert_eq!(3, records.len());
+    assert!(records.iter().all(|record| record.user_id == user.id));
+
+    let mut resources = Vec::new();
+    for record in records {
+        resources.push(PersonalRecordResource::default(record, database).await?);
+    }
+
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resources,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::init(pool).await;
+
+    // Act
+    let response = actions::list_personal_records(&server).await;
+
+    // Assert
+    response.assert_unauthorized();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn timeline_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let user = server.user().unwrap();
+    let (workout, workout_exercise) = workout_exercise_for(user, Measurement::WeightedRepetitions, database).await?;
+    let exercise = workout_exercise.exercise(database).await?;
+
+    for weight in [60.0, 70.0] {
+        workout_actions::log_set(&server, &workout, &workout_exercise, json!({
+            "weight": weight,
+            "repetitions": 3,
+        })).await.assert_created();
+    }
+
+    // Act
+    let response = actions::read_exercise_timeline(&server, &exercise).await;
+
+    // Assert
+    let records = PersonalRecord::timeline(user.id, exercise.id, database).await?;
+    assert_eq!(6, records.len());
+
+    let resources = PersonalRecordResource::list(records, database).await?;
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resources,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn timeline_is_empty_without_records(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+
+    // Act
+    let response = actions::read_exercise_timeline(&server, &Exercise::fake().create(server.database()).await?).await;
+
+    // Assert
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": [],
+    }));
+
+    Ok(())
+}
@@ -0,0 +1,376 @@
+use crate::enums::ExerciseMechanic;
+use crate::tests::actions::program as actions;
+use crate::http::resources::{
+    ModelResource,
+    ProgramEnrollmentResource,
+    ProgramResource,
+    ProgramSessionResource,
+};
+use crate::models::{Exercise, Program, ProgramDay, ProgramEnrollment, Routine, RoutineExercise, User, Workout};
+use crate::prelude::*;
+use chrono::{Days, Utc};
+
+async fn enrolled_program(user: &User, weeks_ago: u64, database: &DatabaseManager) -> Result<(Program, Routine)> {
+    let routine = Routine::fake()
+        .user(user)
+        .create(database)
+        .await?;
+    let squat = Exercise::fake()
+        .mechanic(Some(ExerciseMechanic::Compound))
+        .create(database)
+        .await?;
+    RoutineExercise::fake()
+        .routine(&routine)
+        .exercise(&squat)
+        .target_weight(Some(100.0))
+        .create(database)
+        .await?;
+    let program = Program::fake()
+        .user(user)
+        .weeks(4)
+        .deload_every(Some(4))
+        .create(database)
+        .await?;
+
+    for week in 1..=4 {
+        ProgramDay::new()
+            .program(&program)
+            .routine(&routine)
+            .schedule(week, 1)
+            .create(database)
+            .await?;
+    }
+
+    ProgramEnrollment::new()
+        .program(&program)
+        .user(user)
+        .started_on(Utc::now().date_naive() - Days::new(weeks_ago * 7))
+        .create(database)
+        .await?;
+
+    Ok((program, routine))
+}
+
+#[sqlx::test]
+async fn create_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let routine = Routine::fake()
+        .user(server.user().unwrap())
+        .create(database)
+        .await?;
+    let payload = json!({
+        "name": "Linear Progression",
+        "description": NULL,
+        "weeks": 4,
+        "weekly_increment": NULL,
+        "deload_every": 4,
+        "deload_factor": NULL,
+        "days": [
+            { "routine_id": routine.ulid, "week_number": 1, "day_number": 1 },
+            { "routine_id": routine.ulid, "week_number": 1, "day_number": 4 },
+        ],
+    });
+
+    // Act
+    let response = actions::create_program(&server, payload).await;
+
+    // Assert
+    let program = Program::find("name", "Linear Progression", database).await?;
+    let days = program.days(database).await?;
+    assert_eq!(4, program.weeks);
+    assert_eq!(2.5, program.weekly_increment);
+    assert_some_eq(4i16, program.deload_every);
+    assert_eq!(2, days.len());
+    assert_eq!(4, days[1].day_number);
+
+    let resource = ProgramResource::default(program, database).await?;
+    response.assert_created();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::init(pool).await;
+
+    // Act
+    let response = actions::create_program(&server, json!({
+        "name": "Linear Progression",
+        "description": NULL,
+        "weeks": 4,
+        "days": [],
+    })).await;
+
+    // Assert
+    response.assert_unauthorized();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_fails_with_day_outside_program(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let routine = Routine::fake()
+        .user(server.user().unwrap())
+        .create(server.database())
+        .await?;
+
+    // Act
+    let response = actions::create_program(&server, json!({
+        "name": "Linear Progression",
+        "description": NULL,
+        "weeks": 4,
+        "days": [
+            { "routine_id": routine.ulid, "week_number": 5, "day_number": 1 },
+        ],
+    })).await;
+
+    // Assert
+    response.assert_unprocessable();
+    assert_eq!(0, Program::count(server.database()).await?);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_fails_with_another_users_routine(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let routine = Routine::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::create_program(&server, json!({
+        "name": "Linear Progression",
+        "description": NULL,
+        "weeks": 4,
+        "days": [
+            { "routine_id": routine.ulid, "week_number": 1, "day_number": 1 },
+        ],
+    })).await;
+
+    // Assert
+    response.assert_not_found();
+    assert_eq!(0, Program::count(server.database()).await?);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn read_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let program = Program::mocked(database).await?;
+
+    // Act
+    let response = actions::read_program(&server, &program).await;
+
+    // Assert
+    let resource = ProgramResource::default(program, database).await?;
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn enroll_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let user = server.user().unwrap();
+    let program = Program::mocked(database).await?;
+
+    // Act
+    let response = actions::enroll_in_program(&server, &program, json!({
+        "started_on": "2023-11-06",
+    })).await;
+
+    // Assert
+    let enrollment = ProgramEnrollment::find_active(program.id, user.id, database)
+        .await?
+        .unwrap();
+    assert_eq!("2023-11-06", enrollment.started_on.to_string());
+
+    let resource = ProgramEnrollmentResource::default(enrollment, database).await?;
+    response.assert_created();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn enroll_fails_if_already_enrolled(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let (program, _) = enrolled_program(server.user().unwrap(), 0, database).await?;
+
+    // Act
+    let response = actions::enroll_in_program(&server, &program, json!({})).await;
+
+    // Assert
+    response.assert_conflict();
+    assert_eq!(1, ProgramEnrollment::count(database).await?);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn leave_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let user = server.user().unwrap();
+    let (program, _) = enrolled_program(user, 0, database).await?;
+
+    // Act
+    let response = actions::leave_program(&server, &program).await;
+
+    // Assert
+    response.assert_no_content();
+    assert!(ProgramEnrollment::find_active(program.id, user.id, database).await?.is_none());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn today_includes_progressed_targets(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let (program, _) = enrolled_program(server.user().unwrap(), 2, database).await?;
+
+    // Act
+    let response = actions::read_today(&server, &program).await;
+
+    // Assert
+    let day = program.days(database)
+        .await?
+        .into_iter()
+        .find(|day| day.week_number == 3)
+        .unwrap();
+    let resource = ProgramSessionResource::new(&program, day, database).await?;
+    let body = serde_json::to_value(&resource)?;
+    assert_eq!(3, body["week_number"]);
+    assert_eq!(false, body["deload"]);
+    assert_eq!(105.0, body["exercises"][0]["target_weight"]);
+
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn today_applies_deload(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let (program, _) = enrolled_program(server.user().unwrap(), 3, database).await?;
+
+    // Act
+    let response = actions::read_today(&server, &program).await;
+
+    // Assert
+    response.assert_ok();
+    let body = response.0.json::<Value>();
+    assert_eq!(4, body["data"]["week_number"]);
+    assert_eq!(true, body["data"]["deload"]);
+    assert_eq!(64.5, body["data"]["exercises"][0]["target_weight"]);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn today_fails_if_not_enrolled(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let program = Program::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::read_today(&server, &program).await;
+
+    // Assert
+    response.assert_not_found();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn start_today_creates_progressed_workout(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let (program, routine) = enrolled_program(server.user().unwrap(), 1, database).await?;
+
+    // Act
+    let response = actions::start_today(&server, &program).await;
+
+    // Assert
+    let workout = Workout::find("routine_id", routine.id, database).await?;
+    let exercises = workout.exercises(database).await?;
+    assert!(workout.program_day_id.is_some());
+    assert_eq!(1, exercises.len());
+    assert_some_eq(102.5, exercises[0].target_weight);
+
+    response.assert_created();
+
+    // A finished session is no longer scheduled for the rest of the week
+    let mut workout = workout;
+    workout.finish(database).await?;
+
+    let response = actions::read_today(&server, &program).await;
+
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": NULL,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn start_today_fails_without_scheduled_day(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let user = server.user().unwrap();
+    let program = Program::fake()
+        .user(user)
+        .create(database)
+        .await?;
+    ProgramEnrollment::new()
+        .program(&program)
+        .user(user)
+        .started_on(Utc::now().date_naive())
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::start_today(&server, &program).await;
+
+    // Assert
+    response.assert_not_found();
+    assert_eq!(0, Workout::count(database).await?);
+
+    Ok(())
+}
@@ -0,0 +1,287 @@
+use crate::tests::actions::routine as actions;
+use crate::http::resources::{ModelResource, RoutineResource, WorkoutResource};
+use crate::models::{Exercise, Routine, RoutineExercise, Workout};
+use crate::prelude::*;
+
+#[sqlx::test]
+async fn create_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let bench = Exercise::mocked(database).await?;
+    let dips = Exercise::mocked(database).await?;
+    let payload = json!({
+        "name": "Push Day",
+        "description": NULL,
+        "exercises": [
+            {
+                "exercise_id": bench.ulid,
+                "target_sets": 5,
+                "target_repetitions": 5,
+                "target_weight": 100.0,
+                "rest_seconds": 180,
+            },
+            {
+                "exercise_id": dips.ulid,
+                "target_sets": 3,
+                "target_repetitions": 12,
+                "target_weight": NULL,
+                "rest_seconds": 90,
+            },
+        ],
+    });
+
+    // Act
+    let response = actions::create_routine(&server, payload).await;
+
+    // Assert
+    let routine = Routine::find("name", "Push Day", database).await?;
+    let exercises = routine.exercises(database).await?;
+    assert_eq!(2, exercises.len());
+    assert_eq!(bench.id, exercises[0].exercise_id);
+    assert_eq!(1, exercises[0].sequence_number);
+    assert_eq!(dips.id, exercises[1].exercise_id);
+    assert_eq!(2, exercises[1].sequence_number);
+
+    let resource = RoutineResource::default(routine, database).await?;
+    response.assert_created();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::init(pool).await;
+
+    // Act
+    let response = actions::create_routine(&server, json!({
+        "name": "Push Day",
+        "description": NULL,
+        "exercises": [],
+    })).await;
+
+    // Assert
+    response.assert_unauthorized();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn create_fails_with_unknown_exercise(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+
+    // Act
+    let response = actions::create_routine(&server, json!({
+        "name": "Push Day",
+        "description": NULL,
+        "exercises": [
+            {
+                "exercise_id": "does-not-exist",
+                "target_sets": 3,
+                "target_repetitions": NULL,
+                "target_weight": NULL,
+                "rest_seconds": NULL,
+            },
+        ],
+    })).await;
+
+    // Assert
+    response.assert_not_found();
+    assert_eq!(0, Routine::count(server.database()).await?);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn read_fails_for_another_users_routine(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let routine = Routine::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::read_routine(&server, &routine).await;
+
+    // Assert
+    response.assert_not_found();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_only_includes_own_routines(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let routine = Routine::fake()
+        .user(server.user().unwrap())
+        .create(database)
+        .await?;
+    Routine::mocked(database).await?;
+
+    // Act
+    let response = actions::list_routines(&server).await;
+
+    // Assert
+    let resources = RoutineResource::list(vec![routine], database).await?;
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resources,
+        "pagination": {
+            "total": 1,
+            "page": 1,
+            "per_page": 10,
+            "next": NULL,
+            "prev": NULL,
+        },
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_links_to_surrounding_pages(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    for name in ["A", "B", "C", "D", "E"] {
+        Routine::fake()
+            .user(server.user().unwrap())
+            .name(name)
+            .create(database)
+            .await?;
+    }
+
+    // Act
+    let response = server.get_link("/api/routines?page=2&per_page=2").await;
+
+    // Assert
+    response.assert_ok();
+    let body = response.0.json::<Value>();
+    assert_eq!(vec!["C", "D"], body["data"].as_array().unwrap().iter()
+        .map(|routine| routine["name"].as_str().unwrap())
+        .collect::<Vec<&str>>());
+    assert_eq!(json!({
+        "total": 5,
+        "page": 2,
+        "per_page": 2,
+        "next": "/api/routines?page=3&per_page=2",
+        "prev": "/api/routines?page=1&per_page=2",
+    }), body["pagination"]);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_rejects_invalid_pagination(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+
+    // Act & Assert
+    server.get_link("/api/routines?page=0").await.assert_bad_request();
+    server.get_link("/api/routines?page=first").await.assert_bad_request();
+    server.get_link("/api/routines?per_page=101").await.assert_bad_request();
+    server.get_link("/api/routines?cursor=nonsense").await.assert_bad_request();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_rejects_pages_past_the_largest_offset(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let huge = i64::MAX / 10;
+
+    // Act & Assert
+    server.get_link(format!("/api/routines?page={}", i64::MAX).as_str()).await.assert_bad_request();
+    server.get_link(format!("/api/routines?page={}", huge).as_str()).await.assert_bad_request();
+    server.get_link(format!("/api/routines?per_page=1&page={}", i64::MAX).as_str()).await.assert_bad_request();
+    server.get_link(format!("/api/routines?page={}&per_page=1", huge).as_str()).await.assert_ok();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn delete_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let routine = Routine::fake()
+        .user(server.user().unwrap())
+        .create(server.database())
+        .await?;
+
+    // Act
+    let response = actions::delete_routine(&server, &routine).await;
+
+    // Assert
+    response.assert_no_content();
+    assert_eq!(0, Routine::count(server.database()).await?);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn start_creates_prefilled_workout(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let routine = Routine::fake()
+        .user(server.user().unwrap())
+        .create(database)
+        .await?;
+    let first = RoutineExercise::fake()
+        .routine(&routine)
+        .create(database)
+        .await?;
+    let second = RoutineExercise::fake()
+        .routine(&routine)
+        .sequence_number(2)
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::start_routine(&server, &routine).await;
+
+    // Assert
+    let workout = Workout::find("routine_id", routine.id, database).await?;
+    let exercises = workout.exercises(database).await?;
+    assert_eq!(server.user().unwrap().id, workout.user_id);
+    assert_some_eq(routine.name.clone(), workout.name.clone());
+    assert_eq!(2, exercises.len());
+    assert_eq!(first.exercise_id, exercises[0].exercise_id);
+    assert_some_eq(first.target_sets, exercises[0].target_sets);
+    assert_eq!(first.target_repetitions, exercises[0].target_repetitions);
+    assert_eq!(second.exercise_id, exercises[1].exercise_id);
+    assert_eq!(second.rest_seconds, exercises[1].rest_seconds);
+
+    let resource = WorkoutResource::default(workout, database).await?;
+    response.assert_created();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn start_fails_for_another_users_routine(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let routine = Routine::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::start_routine(&server, &routine).await;
+
+    // Assert
+    response.assert_not_found();
+    assert_eq!(0, Workout::count(server.database()).await?);
+
+    Ok(())
+}
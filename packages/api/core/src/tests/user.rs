@@ -0,0 +1,183 @@
+use crate::tests::actions::user as actions;
+use crate::enums::Role;
+use crate::http::resources::{ModelResource, UserResource, WorkoutResource};
+use crate::models::{User, Workout};
+use crate::prelude::*;
+
+#[sqlx::test]
+async fn update_role_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let database = server.database();
+    let user = User::mocked(database).await?;
+
+    // Act
+    let response = actions::update_user_role(&server, &user, json!({ "role": Role::Coach })).await;
+
+    // Assert
+    let user = User::find_by_pk(user.id, database).await?;
+    let resource = UserResource::simple(user.clone(), database).await?;
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+    assert_eq!(Role::Coach, user.role);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_role_fails_as_moderator(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_moderator(pool).await;
+    let user = User::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::update_user_role(&server, &user, json!({ "role": Role::Coach })).await;
+
+    // Assert
+    response.assert_forbidden();
+    assert_eq!(Role::User, User::find_by_pk(user.id, server.database()).await?.role);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_role_fails_for_own_account_without_permission(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let user = server.user().unwrap();
+
+    // Act
+    let response = actions::update_user_role(&server, user, json!({ "role": Role::Coach })).await;
+
+    // Assert
+    response.assert_forbidden();
+    assert_eq!(Role::User, User::find_by_pk(user.id, server.database()).await?.role);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn update_role_fails_if_not_found(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let user = User::mocked(server.database()).await?;
+    user.clone().delete(server.database()).await?;
+
+    // Act
+    let response = actions::update_user_role(&server, &user, json!({ "role": Role::Coach })).await;
+
+    // Assert
+    response.assert_not_found();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_workouts_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let database = server.database();
+    let user = User::mocked(database).await?;
+    let workout = Workout::fake()
+        .user(&user)
+        .create(database)
+        .await?;
+    Workout::mocked(database).await?;
+
+    // Act
+    let response = actions::list_user_workouts(&server, &user).await;
+
+    // Assert
+    let resources = WorkoutResource::list(vec![workout], database).await?;
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resources,
+        "pagination": {
+            "per_page": 10,
+            "next": NULL,
+            "prev": NULL,
+        },
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_workouts_fails_without_permission(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_coach(pool).await;
+    let user = User::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::list_user_workouts(&server, &user).await;
+
+    // Assert
+    response.assert_forbidden();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn read_workout_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let database = server.database();
+    let user = User::mocked(database).await?;
+    let workout = Workout::fake()
+        .user(&user)
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::read_user_workout(&server, &user, &workout).await;
+
+    // Assert
+    let resource = WorkoutResource::default(workout, database).await?;
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn read_workout_fails_for_another_users_workout(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_admin(pool).await;
+    let user = User::mocked(server.database()).await?;
+    let workout = Workout::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::read_user_workout(&server, &user, &workout).await;
+
+    // Assert
+    response.assert_not_found();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn read_workout_fails_as_moderator(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated_moderator(pool).await;
+    let database = server.database();
+    let user = User::mocked(database).await?;
+    let workout = Workout::fake()
+        .user(&user)
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::read_user_workout(&server, &user, &workout).await;
+
+    // Assert
+    response.assert_forbidden();
+
+    Ok(())
+}
@@ -0,0 +1,341 @@
+use crate::tests::actions::workout as actions;
+use crate::enums::Measurement;
+use crate::http::resources::{ModelResource, WorkoutResource};
+use crate::models::{Exercise, Workout, WorkoutExercise, WorkoutSet};
+use crate::prelude::*;
+
+#[sqlx::test]
+async fn start_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let current_count = Workout::count(database).await?;
+
+    // Act
+    let response = actions::start_workout(&server, json!({
+        "name": "Leg Day",
+        "notes": NULL,
+    })).await;
+
+    // Assert
+    let workout = Workout::find("name", "Leg Day", database).await?;
+    let resource = WorkoutResource::default(workout.clone(), database).await?;
+    response.assert_created();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+    assert_eq!(current_count + 1, Workout::count(database).await?);
+    assert_eq!(server.user().unwrap().id, workout.user_id);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn start_fails_if_not_authenticated(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::init(pool).await;
+
+    // Act
+    let response = actions::start_workout(&server, json!({
+        "name": "Leg Day",
+        "notes": NULL,
+    })).await;
+
+    // Assert
+    response.assert_unauthorized();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn read_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let workout = Workout::fake()
+        .user(server.user().unwrap())
+        .create(server.database())
+        .await?;
+
+    // Act
+    let response = actions::read_workout(&server, &workout).await;
+
+    // Assert
+    let resource = WorkoutResource::default(workout, server.database()).await?;
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resource,
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn read_fails_for_another_users_workout(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let workout = Workout::mocked(server.database()).await?;
+
+    // Act
+    let response = actions::read_workout(&server, &workout).await;
+
+    // Assert
+    response.assert_not_found();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_only_includes_own_workouts(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let workout = Workout::fake()
+        .user(server.user().unwrap())
+        .create(database)
+        .await?;
+    Workout::mocked(database).await?;
+
+    // Act
+    let response = actions::list_workouts(&server).await;
+
+    // Assert
+    let resources = WorkoutResource::list(vec![workout], database).await?;
+    response.assert_ok();
+    response.assert_json(json!({
+        "success": true,
+        "data": resources,
+        "pagination": {
+            "per_page": 10,
+            "next": NULL,
+            "prev": NULL,
+        },
+    }));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn list_pages_through_workouts_with_cursors(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let mut workouts = Vec::new();
+    for _ in 0..5 {
+        workouts.push(Workout::fake()
+            .user(server.user().unwrap())
+            .create(database)
+            .await?
+            .ulid);
+    }
+    workouts.reverse();
+
+    let ids = |response: &MockResponse| response.0.json::<Value>()["data"].as_array().unwrap().iter()
+        .map(|workout| workout["id"].as_str().unwrap().to_string())
+        .collect::<Vec<String>>();
+    let link = |response: &MockResponse, name: &str| response.0.json::<Value>()["pagination"][name]
+        .as_str()
+        .map(ToString::to_string);
+
+    // Act
+    let first = server.get_link("/api/workouts?per_page=2").await;
+    let second = server.get_link(&link(&first, "next").unwrap()).await;
+    let third = server.get_link(&link(&second, "next").unwrap()).await;
+    let back = server.get_link(&link(&third, "prev").unwrap()).await;
+
+    // Assert
+    first.assert_ok();
+    assert_eq!(workouts[0..2], ids(&first));
+    assert_eq!(None, link(&first, "prev"));
+    assert_eq!(workouts[2..4], ids(&second));
+    assert_eq!(workouts[4..5], ids(&third));
+    assert_eq!(None, link(&third, "next"));
+    assert_eq!(workouts[2..4], ids(&back));
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn add_exercise_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let workout = Workout::fake()
+        .user(server.user().unwrap())
+        .create(database)
+        .await?;
+    let first = Exercise::mocked(database).await?;
+    let second = Exercise::mocked(database).await?;
+
+    // Act
+    actions::add_exercise(&server, &workout, json!({
+        "exercise_id": first.ulid,
+        "notes": NULL,
+    })).await.assert_created();
+    let response = actions::add_exercise(&server, &workout, json!({
+        "exercise_id": second.ulid,
+        "notes": "Superset with the first exercise",
+    })).await;
+
+    // Assert
+    response.assert_created();
+    let exercises = workout.exercises(database).await?;
+    assert_eq!(2, exercises.len());
+    assert_eq!(first.id, exercises[0].exercise_id);
+    assert_eq!(1, exercises[0].sequence_number);
+    assert_eq!(second.id, exercises[1].exercise_id);
+    assert_eq!(2, exercises[1].sequence_number);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn add_exercise_fails_if_workout_finished(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let mut workout = Workout::fake()
+        .user(server.user().unwrap())
+        .create(database)
+        .await?;
+    workout.finish(database).await?;
+    let exercise = Exercise::mocked(database).await?;
+
+    // Act
+    let response = actions::add_exercise(&server, &workout, json!({
+        "exercise_id": exercise.ulid,
+        "notes": NULL,
+    })).await;
+
+    // Assert
+    response.assert_conflict();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn log_set_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let workout = Workout::fake()
+        .user(server.user().unwrap())
+        .create(database)
+        .await?;
+    let workout_exercise = WorkoutExercise::fake()
+        .workout(&workout)
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::log_set(&server, &workout, &workout_exercise, json!({
+        "weight": 80.0,
+        "repetitions": 8,
+    })).await;
+
+    // Assert
+    response.assert_created();
+    let sets = workout_exercise.sets(database).await?;
+    assert_eq!(1, sets.len());
+    assert_eq!(1, sets[0].sequence_number);
+    assert_some_eq(80.0, sets[0].weight);
+    assert_some_eq(8i16, sets[0].repetitions);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn log_set_fails_if_fields_do_not_match_measurement(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let workout = Workout::fake()
+        .user(server.user().unwrap())
+        .create(database)
+        .await?;
+    let exercise = Exercise::fake()
+        .measurement(Some(Measurement::Duration))
+        .create(database)
+        .await?;
+    let workout_exercise = WorkoutExercise::fake()
+        .workout(&workout)
+        .exercise(&exercise)
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::log_set(&server, &workout, &workout_exercise, json!({
+        "weight": 80.0,
+        "repetitions": 8,
+    })).await;
+
+    // Assert
+    response.assert_unprocessable();
+    assert_eq!(0, WorkoutSet::count(database).await?);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn log_set_fails_for_exercise_from_another_workout(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let workout = Workout::fake()
+        .user(server.user().unwrap())
+        .create(database)
+        .await?;
+    let workout_exercise = WorkoutExercise::mocked(database).await?;
+
+    // Act
+    let response = actions::log_set(&server, &workout, &workout_exercise, json!({
+        "weight": 80.0,
+        "repetitions": 8,
+    })).await;
+
+    // Assert
+    response.assert_not_found();
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn finish_success(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let workout = Workout::fake()
+        .user(server.user().unwrap())
+        .create(database)
+        .await?;
+
+    // Act
+    let response = actions::finish_workout(&server, &workout).await;
+
+    // Assert
+    response.assert_ok();
+    assert!(Workout::find_by_pk(workout.id, database).await?.is_finished());
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn finish_fails_if_already_finished(pool: PgPool) -> Result<()> {
+    // Arrange
+    let server = MockServer::authenticated(pool).await;
+    let database = server.database();
+    let mut workout = Workout::fake()
+        .user(server.user().unwrap())
+        .create(database)
+        .await?;
+    workout.finish(database).await?;
+
+    // Act
+    let response = actions::finish_workout(&server, &workout).await;
+
+    // Assert
+    response.assert_conflict();
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use serde::de::{Deserialize, Deserializer, Error};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Deserializes an optional comma separated query parameter, such as
+/// `equipment=1,2,3`, into a list of values. Empty segments are ignored.
+pub fn comma_separated<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    let value = Option::<String>::deserialize(deserializer)?;
+
+    match value {
+        Some(value) => value.split(',')
+            .map(str::trim)
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| segment.parse::<T>().map_err(D::Error::custom))
+            .collect::<Result<Vec<T>, D::Error>>()
+            .map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Deserializes a field of a partial update that can be set to `null`. Used
+/// along with `#[serde(default)]`, a missing field is `None` and leaves the
+/// value as it is, while `null` is `Some(None)` and clears it.
+pub fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
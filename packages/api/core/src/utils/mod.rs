@@ -1,6 +1,9 @@
 pub mod crypt;
+pub mod deserializers;
 mod errors;
 mod lang;
+pub mod search;
+pub mod strength;
 pub mod validators;
 
 pub use errors::Error;
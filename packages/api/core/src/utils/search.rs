@@ -0,0 +1,26 @@
+/// Shorthand commonly typed when searching for exercises,
+/// along with the term used in exercise names.
+const ABBREVIATIONS: [(&str, &str); 6] = [
+    ("bb", "barbell"),
+    ("bw", "bodyweight"),
+    ("db", "dumbbell"),
+    ("kb", "kettlebell"),
+    ("ohp", "overhead press"),
+    ("rdl", "romanian deadlift"),
+];
+
+/// Normalizes a search query by lowercasing it, collapsing whitespace
+/// and replacing known abbreviations with their full terms.
+pub fn normalize_query(query: &str) -> String {
+    query.split_whitespace()
+        .map(|word| {
+            let word = word.to_lowercase();
+
+            ABBREVIATIONS.iter()
+                .find(|(abbreviation, _)| *abbreviation == word)
+                .map(|(_, term)| term.to_string())
+                .unwrap_or(word)
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
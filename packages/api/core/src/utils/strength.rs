@@ -0,0 +1,97 @@
+use crate::enums::{ExerciseMechanic, Gender, OneRepMaxFormula, StrengthLevel};
+
+/// Bodyweight used for lifters that have not recorded their own.
+const DEFAULT_BODYWEIGHT_MALE: f64 = 80.0;
+const DEFAULT_BODYWEIGHT_FEMALE: f64 = 65.0;
+
+/// Bodyweight multiples a one rep max must reach to be classified as
+/// novice, intermediate, advanced and elite respectively.
+const STANDARDS_MALE_COMPOUND: [f64; 4] = [0.75, 1.25, 1.75, 2.25];
+const STANDARDS_MALE_ISOLATION: [f64; 4] = [0.2, 0.4, 0.6, 0.8];
+const STANDARDS_FEMALE_COMPOUND: [f64; 4] = [0.5, 0.85, 1.2, 1.6];
+const STANDARDS_FEMALE_ISOLATION: [f64; 4] = [0.1, 0.25, 0.4, 0.55];
+
+/// Estimates a one rep max from a set, rounded to two decimal places.
+///
+/// Returns `None` when the set cannot produce an estimate, such as
+/// sets without weight or sets outside the range of the formula.
+pub fn one_rep_max(weight: f64, repetitions: i16, formula: &OneRepMaxFormula) -> Option<f64> {
+    if weight <= 0.0 || repetitions <= 0 {
+        return None;
+    }
+
+    if repetitions == 1 {
+        return Some(weight);
+    }
+
+    let repetitions = repetitions as f64;
+    let estimate = match formula {
+        OneRepMaxFormula::Brzycki if repetitions >= 37.0 => return None,
+        OneRepMaxFormula::Brzycki => weight * 36.0 / (37.0 - repetitions),
+        OneRepMaxFormula::Epley => weight * (1.0 + repetitions / 30.0),
+        OneRepMaxFormula::Lombardi => weight * repetitions.powf(0.1),
+    };
+
+    Some(round(estimate))
+}
+
+/// Returns the one rep max as a multiple of the lifter's bodyweight, falling
+/// back to a reference bodyweight for the lifter's gender when it is unknown.
+pub fn bodyweight_ratio(one_rep_max: f64, gender: &Gender, bodyweight: Option<f64>) -> f64 {
+    let bodyweight = bodyweight.unwrap_or_else(|| match gender {
+        Gender::Male => DEFAULT_BODYWEIGHT_MALE,
+        Gender::Female => DEFAULT_BODYWEIGHT_FEMALE,
+        Gender::NonBinary | Gender::Other => (DEFAULT_BODYWEIGHT_MALE + DEFAULT_BODYWEIGHT_FEMALE) / 2.0,
+    });
+
+    round(one_rep_max / bodyweight)
+}
+
+/// Classifies a bodyweight ratio against strength standards for the lifter's
+/// gender and the exercise mechanic. Standards are lowered for lifters under
+/// 18 and from the age of 40 onwards.
+pub fn classify(ratio: f64, mechanic: Option<&ExerciseMechanic>, gender: &Gender, age: i32) -> StrengthLevel {
+    let isolation = matches!(mechanic, Some(ExerciseMechanic::Isolation));
+    let standards = match (gender, isolation) {
+        (Gender::Male, false) => STANDARDS_MALE_COMPOUND,
+        (Gender::Male, true) => STANDARDS_MALE_ISOLATION,
+        (Gender::Female, false) => STANDARDS_FEMALE_COMPOUND,
+        (Gender::Female, true) => STANDARDS_FEMALE_ISOLATION,
+        (_, false) => average(STANDARDS_MALE_COMPOUND, STANDARDS_FEMALE_COMPOUND),
+        (_, true) => average(STANDARDS_MALE_ISOLATION, STANDARDS_FEMALE_ISOLATION),
+    };
+    let age_factor = match age {
+        ..=17 => 0.85,
+        18..=39 => 1.0,
+        40..=49 => 0.9,
+        50..=59 => 0.8,
+        _ => 0.7,
+    };
+    let levels = [
+        StrengthLevel::Novice,
+        StrengthLevel::Intermediate,
+        StrengthLevel::Advanced,
+        StrengthLevel::Elite,
+    ];
+
+    standards.iter()
+        .zip(levels)
+        .rev()
+        .find(|(standard, _)| ratio >= *standard * age_factor)
+        .map(|(_, level)| level)
+        .unwrap_or(StrengthLevel::Beginner)
+}
+
+fn average(first: [f64; 4], second: [f64; 4]) -> [f64; 4] {
+    let mut result = [0.0; 4];
+
+    for (index, value) in result.iter_mut().enumerate() {
+        *value = (first[index] + second[index]) / 2.0;
+    }
+
+    result
+}
+
+fn round(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
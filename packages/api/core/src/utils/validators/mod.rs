@@ -1,6 +1,8 @@
 mod password;
+mod workout_set;
 
 pub use password::password;
+pub use workout_set::workout_set;
 
 pub enum ValidatorResult {
     Valid,
@@ -0,0 +1,60 @@
+use super::ValidatorResult;
+use crate::enums::Measurement;
+
+pub fn workout_set(
+    measurement: Option<&Measurement>,
+    weight: Option<f64>,
+    repetitions: Option<i16>,
+    duration: Option<i32>,
+) -> ValidatorResult {
+    let mut validation_errors: Vec<String> = Vec::new();
+
+    let (weight_allowed, weight_required, repetitions_allowed, duration_allowed) = match measurement {
+        Some(Measurement::Bodyweight) => (true, false, true, false),
+        Some(Measurement::Duration) => (false, false, false, true),
+        Some(Measurement::Repetitions) => (false, false, true, false),
+        Some(Measurement::WeightedDuration) => (true, true, false, true),
+        Some(Measurement::WeightedRepetitions) => (true, true, true, false),
+        None => (true, false, true, true),
+    };
+
+    if let Some(weight) = weight {
+        if !weight_allowed {
+            validation_errors.push("Weight is not recorded for this exercise".to_string());
+        } else if weight < 0.0 {
+            validation_errors.push("Weight must not be negative".to_string());
+        }
+    } else if weight_required {
+        validation_errors.push("Weight is required for this exercise".to_string());
+    }
+
+    if let Some(repetitions) = repetitions {
+        if !repetitions_allowed {
+            validation_errors.push("Repetitions are not recorded for this exercise".to_string());
+        } else if repetitions <= 0 {
+            validation_errors.push("Repetitions must be greater than zero".to_string());
+        }
+    } else if repetitions_allowed && measurement.is_some() {
+        validation_errors.push("Repetitions are required for this exercise".to_string());
+    }
+
+    if let Some(duration) = duration {
+        if !duration_allowed {
+            validation_errors.push("Duration is not recorded for this exercise".to_string());
+        } else if duration <= 0 {
+            validation_errors.push("Duration must be greater than zero".to_string());
+        }
+    } else if duration_allowed && measurement.is_some() {
+        validation_errors.push("Duration is required for this exercise".to_string());
+    }
+
+    if measurement.is_none() && weight.is_none() && repetitions.is_none() && duration.is_none() {
+        validation_errors.push("A set must record a weight, repetitions, or duration".to_string());
+    }
+
+    if !validation_errors.is_empty() {
+        return ValidatorResult::Invalid(validation_errors);
+    }
+
+    ValidatorResult::Valid
+}
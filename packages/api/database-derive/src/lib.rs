@@ -0,0 +1,45 @@
+mod model;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Derives `database::Model`, and `database::HasRouteKey` when a route key is
+/// given, along with `insert()`, `save()` and `delete()` methods built from
+/// the struct's fields. Each of them takes any `database::Connection`, so they
+/// can be run inside a transaction.
+///
+/// ```ignore
+/// #[derive(Clone, Debug, FromRow, Model)]
+/// #[model(table = "routines", route_key = "ulid")]
+/// pub struct Routine {
+///     #[model(generated)]
+///     pub id: i32,
+///     #[model(generated)]
+///     pub ulid: String,
+///     pub name: String,
+///     pub created_at: ISO8601DateTimeUTC,
+///     pub updated_at: ISO8601DateTimeUTC,
+/// }
+/// ```
+///
+/// Struct attributes:
+/// - `table`: the table the model is stored in. Required.
+/// - `primary_key`: the field holding the primary key. Defaults to `id`.
+/// - `route_key`: the field used to find the model from a route.
+/// - `name`: the model name used in errors. Defaults to the struct name.
+///
+/// Field attributes:
+/// - `generated`: the column is filled in by the database and never written.
+///
+/// The primary key is inserted unless it is generated, and never updated.
+/// `created_at` is never written, and `updated_at` is set to the current time
+/// on save. `delete()` fails with `ModelNotFound` when the row is already gone. Fields marked `#[sqlx(skip)]` are kept
+/// as they are, and `#[sqlx(rename = "...")]` is used as the column name.
+#[proc_macro_derive(Model, attributes(model))]
+pub fn derive_model(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    model::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,469 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Field, Fields, Ident, LitStr, Result, Type};
+
+const CREATED_AT: &str = "created_at";
+const UPDATED_AT: &str = "updated_at";
+
+struct ModelAttributes {
+    table: LitStr,
+    name: Option<LitStr>,
+    primary_key: Option<LitStr>,
+    route_key: Option<LitStr>,
+}
+
+struct ModelField {
+    ident: Ident,
+    ty: Type,
+    column: String,
+    generated: bool,
+    skipped: bool,
+}
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let attributes = model_attributes(&input)?;
+    let fields = model_fields(&input)?;
+    let ident = &input.ident;
+
+    let table = &attributes.table;
+    let name = attributes.name
+        .map(|name| name.value())
+        .unwrap_or_else(|| ident.to_string());
+
+    let primary_key = find_field(&fields, attributes.primary_key.as_ref(), "id", ident)?;
+    let primary_key_ident = &primary_key.ident;
+    let primary_key_type = &primary_key.ty;
+    let primary_key_column = &primary_key.column;
+
+    let route_key = match &attributes.route_key {
+        Some(route_key) => {
+            let field = find_field(&fields, Some(route_key), "", ident)?;
+            let field_ident = &field.ident;
+            let field_type = &field.ty;
+            let column = &field.column;
+
+            quote! {
+                #[automatically_derived]
+                impl ::database::HasRouteKey for #ident {
+                    const ROUTE_KEY: &'static str = #column;
+                    type RouteKey = #field_type;
+
+                    fn route_key(&self) -> Self::RouteKey {
+                        self.#field_ident.clone()
+                    }
+                }
+            }
+        },
+        None => TokenStream::new(),
+    };
+
+    let writable = fields.iter()
+        .filter(|field| !field.skipped && !field.generated && field.column != CREATED_AT)
+        .collect::<Vec<&ModelField>>();
+
+    let insert_values = writable.iter()
+        .filter(|field| field.column != UPDATED_AT)
+        .map(|field| {
+            let (field_ident, column) = (&field.ident, &field.column);
+            quote! { .value(#column, self.#field_ident.clone()) }
+        });
+
+    let save_values = writable.iter()
+        .filter(|field| field.ident != *primary_key_ident)
+        .map(|field| {
+            let (field_ident, column) = (&field.ident, &field.column);
+
+            match column == UPDATED_AT {
+                true => quote! { .set(#column, ::chrono::Utc::now()) },
+                false => quote! { .set(#column, self.#field_ident.clone()) },
+            }
+        })
+        .collect::<Vec<TokenStream>>();
+
+    let skipped = fields.iter()
+        .filter(|field| field.skipped)
+        .map(|field| &field.ident)
+        .collect::<Vec<&Ident>>();
+    let mutable = match skipped.is_empty() {
+        true => TokenStream::new(),
+        false => quote! { mut },
+    };
+
+    // An update without any assignments is never run, so a model without writable
+    // columns re-reads its row instead.
+    let saved = match save_values.is_empty() {
+        true => quote! {
+            <Self as ::database::Model>::find_by_pk(::database::Model::primary_key(self), &mut database).await?
+        },
+        false => quote! {
+            <Self as ::database::Model>::query()
+                .update()
+                #(#save_values)*
+                .and_where(<Self as ::database::Model>::PRIMARY_KEY, "=", ::database::Model::primary_key(self))
+                .returning(&["*"])
+                .one::<_, Self>(::database::Connection::executor(&mut database))
+                .await?
+        },
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl ::database::Model for #ident {
+            const MODEL_NAME: &'static str = #name;
+            const TABLE_NAME: &'static str = #table;
+            const PRIMARY_KEY: &'static str = #primary_key_column;
+
+            type PrimaryKey = #primary_key_type;
+            fn primary_key(&self) -> Self::PrimaryKey {
+                self.#primary_key_ident.clone()
+            }
+        }
+
+        #route_key
+
+        #[automatically_derived]
+        impl #ident {
+            /// Inserts a copy of the model, returning the newly created row.
+            pub async fn insert<C>(&self, mut database: C) -> ::core::result::Result<Self, ::database::Error>
+            where
+                C: ::database::Connection,
+            {
+                use ::database::SqlxAction as _;
+
+                let #mutable model = <Self as ::database::Model>::query()
+                    .insert()
+                    #(#insert_values)*
+                    .returning(&["*"])
+                    .one::<_, Self>(::database::Connection::executor(&mut database))
+                    .await?;
+
+                #(model.#skipped = self.#skipped.clone();)*
+
+                Ok(model)
+            }
+
+            /// Writes every column of the model to the database. A model without any writable
+            /// columns is refreshed from its row instead.
+            pub async fn save<C>(&mut self, mut database: C) -> ::core::result::Result<(), ::database::Error>
+            where
+                C: ::database::Connection,
+            {
+                use ::database::SqlxAction as _;
+
+                let #mutable model = #saved;
+
+                #(model.#skipped = ::core::mem::take(&mut self.#skipped);)*
+                *self = model;
+
+                Ok(())
+            }
+
+            /// Deletes the model's row, failing with `ModelNotFound` when it is already gone.
+            pub async fn delete<C>(self, mut database: C) -> ::core::result::Result<(), ::database::Error>
+            where
+                C: ::database::Connection,
+            {
+                use ::database::SqlxAction as _;
+
+                let primary_key = ::database::Model::primary_key(&self);
+                let deleted = <Self as ::database::Model>::query()
+                    .delete()
+                    .and_where(<Self as ::database::Model>::PRIMARY_KEY, "=", primary_key.clone())
+                    .execute(::database::Connection::executor(&mut database))
+                    .await?;
+
+                if deleted == 0 {
+                    return Err(::database::Error::ModelNotFound {
+                        name: <Self as ::database::Model>::MODEL_NAME,
+                        table: <Self as ::database::Model>::TABLE_NAME,
+                        search_key: <Self as ::database::Model>::PRIMARY_KEY.to_string(),
+                        search_value: primary_key.to_string(),
+                    });
+                }
+
+                Ok(())
+            }
+        }
+    })
+}
+
+fn model_attributes(input: &DeriveInput) -> Result<ModelAttributes> {
+    let mut table = None;
+    let mut name = None;
+    let mut primary_key = None;
+    let mut route_key = None;
+
+    for attribute in input.attrs.iter().filter(|attribute| attribute.path().is_ident("model")) {
+        attribute.parse_nested_meta(|meta| {
+            let value = meta.value()?.parse::<LitStr>()?;
+
+            if meta.path.is_ident("table") {
+                table = Some(value);
+            } else if meta.path.is_ident("name") {
+                name = Some(value);
+            } else if meta.path.is_ident("primary_key") {
+                primary_key = Some(value);
+            } else if meta.path.is_ident("route_key") {
+                route_key = Some(value);
+            } else {
+                return Err(meta.error("unsupported model attribute"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    let table = table.ok_or_else(|| syn::Error::new_spanned(
+        &input.ident,
+        "missing #[model(table = \"...\")] attribute",
+    ))?;
+
+    Ok(ModelAttributes { table, name, primary_key, route_key })
+}
+
+fn model_fields(input: &DeriveInput) -> Result<Vec<ModelField>> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(syn::Error::new_spanned(&input.ident, "Model requires named fields")),
+        },
+        _ => return Err(syn::Error::new_spanned(&input.ident, "Model can only be derived for structs")),
+    };
+
+    fields.iter().map(model_field).collect()
+}
+
+fn model_field(field: &Field) -> Result<ModelField> {
+    let ident = field.ident.clone().expect("named fields have identifiers");
+    let mut column = ident.to_string();
+    let mut generated = false;
+    let mut skipped = false;
+
+    for attribute in field.attrs.iter() {
+        if attribute.path().is_ident("model") {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("generated") {
+                    generated = true;
+                    return Ok(());
+                }
+
+                Err(meta.error("unsupported model attribute"))
+            })?;
+        } else if attribute.path().is_ident("sqlx") {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    column = meta.value()?.parse::<LitStr>()?.value();
+                } else if meta.path.is_ident("skip") {
+                    skipped = true;
+                } else if meta.input.peek(syn::Token![=]) {
+                    meta.value()?.parse::<syn::Expr>()?;
+                }
+
+                Ok(())
+            })?;
+        }
+    }
+
+    Ok(ModelField { ident, ty: field.ty.clone(), column, generated, skipped })
+}
+
+fn find_field<'f>(
+    fields: &'f [ModelField],
+    name: Option<&LitStr>,
+    default: &str,
+    ident: &Ident,
+) -> Result<&'f ModelField> {
+    let wanted = name.map(LitStr::value).unwrap_or_else(|| default.to_string());
+
+    fields.iter()
+        .find(|field| field.ident == wanted)
+        .ok_or_else(|| syn::Error::new(
+            name.map(LitStr::span).unwrap_or_else(|| ident.span()),
+            format!("no field named `{}`", wanted),
+        ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expand;
+    use proc_macro2::TokenStream;
+    use quote::quote;
+    use syn::{parse_quote, DeriveInput};
+
+    fn expanded(input: DeriveInput) -> String {
+        expand(input).expect("the model should expand").to_string()
+    }
+
+    fn contains(expanded: &str, tokens: TokenStream) -> bool {
+        expanded.contains(&tokens.to_string())
+    }
+
+    #[test]
+    fn implements_model_with_defaults() {
+        let expanded = expanded(parse_quote! {
+            #[model(table = "users")]
+            struct User {
+                id: i64,
+                email: String,
+            }
+        });
+
+        assert!(contains(&expanded, quote! { const MODEL_NAME: &'static str = "User"; }));
+        assert!(contains(&expanded, quote! { const TABLE_NAME: &'static str = "users"; }));
+        assert!(contains(&expanded, quote! { const PRIMARY_KEY: &'static str = "id"; }));
+        assert!(contains(&expanded, quote! { type PrimaryKey = i64; }));
+        assert!(!contains(&expanded, quote! { ::database::HasRouteKey }));
+    }
+
+    #[test]
+    fn honours_name_primary_key_and_route_key() {
+        let expanded = expanded(parse_quote! {
+            #[model(table = "muscles", name = "Muscle", primary_key = "uuid", route_key = "slug")]
+            struct MuscleRow {
+                uuid: String,
+                #[sqlx(rename = "url_slug")]
+                slug: String,
+            }
+        });
+
+        assert!(contains(&expanded, quote! { const MODEL_NAME: &'static str = "Muscle"; }));
+        assert!(contains(&expanded, quote! { const PRIMARY_KEY: &'static str = "uuid"; }));
+        assert!(contains(&expanded, quote! { const ROUTE_KEY: &'static str = "url_slug"; }));
+        assert!(contains(&expanded, quote! { type RouteKey = String; }));
+    }
+
+    #[test]
+    fn writes_only_writable_columns() {
+        let expanded = expanded(parse_quote! {
+            #[model(table = "workouts")]
+            struct Workout {
+                #[model(generated)]
+                id: i64,
+                #[sqlx(rename = "title")]
+                name: String,
+                #[model(generated)]
+                volume: i64,
+                #[sqlx(skip)]
+                sets: Vec<i64>,
+                created_at: DateTime<Utc>,
+                updated_at: DateTime<Utc>,
+            }
+        });
+
+        assert!(contains(&expanded, quote! { .value("title", self.name.clone()) }));
+        assert!(contains(&expanded, quote! { .set("title", self.name.clone()) }));
+        assert!(contains(&expanded, quote! { .set("updated_at", ::chrono::Utc::now()) }));
+
+        for column in ["id", "volume", "sets", "created_at"] {
+            assert!(!expanded.contains(&format!(". value (\"{}\"", column)), "inserts {}", column);
+            assert!(!expanded.contains(&format!(". set (\"{}\"", column)), "updates {}", column);
+        }
+        assert!(!expanded.contains(". value (\"updated_at\""));
+    }
+
+    #[test]
+    fn inserts_primary_keys_that_are_not_generated() {
+        let expanded = expanded(parse_quote! {
+            #[model(table = "countries", primary_key = "code")]
+            struct Country {
+                code: String,
+                name: String,
+            }
+        });
+
+        assert!(contains(&expanded, quote! { .value("code", self.code.clone()) }));
+        assert!(!expanded.contains(". set (\"code\""));
+        assert!(contains(&expanded, quote! { .set("name", self.name.clone()) }));
+    }
+
+    #[test]
+    fn fails_to_delete_missing_rows() {
+        let expanded = expanded(parse_quote! {
+            #[model(table = "users")]
+            struct User {
+                id: i64,
+            }
+        });
+
+        assert!(contains(&expanded, quote! { if deleted == 0 }));
+        assert!(contains(&expanded, quote! { ::database::Error::ModelNotFound }));
+        assert!(contains(&expanded, quote! { search_value: primary_key.to_string(), }));
+    }
+
+    #[test]
+    fn carries_skipped_fields_over_to_the_returned_model() {
+        let expanded = expanded(parse_quote! {
+            #[model(table = "workouts")]
+            struct Workout {
+                id: i64,
+                #[sqlx(skip)]
+                sets: Vec<i64>,
+            }
+        });
+
+        assert!(contains(&expanded, quote! { let mut model = }));
+        assert!(contains(&expanded, quote! { model.sets = self.sets.clone(); }));
+        assert!(contains(&expanded, quote! { model.sets = ::core::mem::take(&mut self.sets); }));
+    }
+
+    #[test]
+    fn refreshes_models_without_writable_columns_on_save() {
+        let expanded = expanded(parse_quote! {
+            #[model(table = "tokens")]
+            struct Token {
+                id: i64,
+                created_at: DateTime<Utc>,
+            }
+        });
+
+        assert!(!expanded.contains(". update ()"));
+        assert!(contains(&expanded, quote! {
+            let model = <Self as ::database::Model>::find_by_pk(::database::Model::primary_key(self), &mut database).await?;
+        }));
+    }
+
+    #[test]
+    fn requires_a_table() {
+        let error = expand(parse_quote! {
+            struct User {
+                id: i64,
+            }
+        }).expect_err("a model without a table should not expand");
+
+        assert_eq!("missing #[model(table = \"...\")] attribute", error.to_string());
+    }
+
+    #[test]
+    fn requires_the_primary_key_field() {
+        let error = expand(parse_quote! {
+            #[model(table = "users", primary_key = "uuid")]
+            struct User {
+                id: i64,
+            }
+        }).expect_err("a model without its primary key should not expand");
+
+        assert_eq!("no field named `uuid`", error.to_string());
+    }
+
+    #[test]
+    fn rejects_unknown_attributes() {
+        let error = expand(parse_quote! {
+            #[model(table = "users", schema = "public")]
+            struct User {
+                id: i64,
+            }
+        }).expect_err("an unknown attribute should not expand");
+
+        assert_eq!("unsupported model attribute", error.to_string());
+    }
+
+    #[test]
+    fn rejects_tuple_structs() {
+        let error = expand(parse_quote! {
+            #[model(table = "users")]
+            struct User(i64);
+        }).expect_err("a tuple struct should not expand");
+
+        assert_eq!("Model requires named fields", error.to_string());
+    }
+}
@@ -0,0 +1,97 @@
+use crate::manager::{DatabaseManager, TransactionManager};
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
+use sqlx::{Describe, Either, Execute, Executor};
+use sqlx::postgres::{PgConnection, PgPool, PgQueryResult, PgRow, PgStatement, PgTypeInfo, Postgres};
+
+/// Anything queries can be run on: the pool behind a `&DatabaseManager`, or an
+/// open transaction. Model methods take a `Connection` so that several writes
+/// can share one transaction and be committed or rolled back together.
+pub trait Connection: Send {
+    fn executor(&mut self) -> DatabaseExecutor<'_>;
+}
+
+impl Connection for &DatabaseManager {
+    fn executor(&mut self) -> DatabaseExecutor<'_> {
+        DatabaseExecutor::Pool(self.connection())
+    }
+}
+
+impl Connection for TransactionManager<'_> {
+    fn executor(&mut self) -> DatabaseExecutor<'_> {
+        DatabaseExecutor::Transaction(TransactionManager::executor(self))
+    }
+}
+
+impl<C> Connection for &mut C
+where
+    C: Connection,
+{
+    fn executor(&mut self) -> DatabaseExecutor<'_> {
+        (**self).executor()
+    }
+}
+
+/// The executor handed out by a `Connection`, passing each query on to the
+/// pool or to the connection the transaction is running on.
+#[derive(Debug)]
+pub enum DatabaseExecutor<'c> {
+    Pool(&'c PgPool),
+    Transaction(&'c mut PgConnection),
+}
+
+impl<'c> Executor<'c> for DatabaseExecutor<'c> {
+    type Database = Postgres;
+
+    fn fetch_many<'e, 'q: 'e, E>(
+        self,
+        query: E,
+    ) -> BoxStream<'e, Result<Either<PgQueryResult, PgRow>, sqlx::Error>>
+    where
+        'c: 'e,
+        E: 'q + Execute<'q, Postgres>,
+    {
+        match self {
+            Self::Pool(pool) => pool.fetch_many(query),
+            Self::Transaction(connection) => connection.fetch_many(query),
+        }
+    }
+
+    fn fetch_optional<'e, 'q: 'e, E>(
+        self,
+        query: E,
+    ) -> BoxFuture<'e, Result<Option<PgRow>, sqlx::Error>>
+    where
+        'c: 'e,
+        E: 'q + Execute<'q, Postgres>,
+    {
+        match self {
+            Self::Pool(pool) => pool.fetch_optional(query),
+            Self::Transaction(connection) => connection.fetch_optional(query),
+        }
+    }
+
+    fn prepare_with<'e, 'q: 'e>(
+        self,
+        sql: &'q str,
+        parameters: &'e [PgTypeInfo],
+    ) -> BoxFuture<'e, Result<PgStatement<'q>, sqlx::Error>>
+    where
+        'c: 'e,
+    {
+        match self {
+            Self::Pool(pool) => pool.prepare_with(sql, parameters),
+            Self::Transaction(connection) => connection.prepare_with(sql, parameters),
+        }
+    }
+
+    fn describe<'e, 'q: 'e>(self, sql: &'q str) -> BoxFuture<'e, Result<Describe<Postgres>, sqlx::Error>>
+    where
+        'c: 'e,
+    {
+        match self {
+            Self::Pool(pool) => pool.describe(sql),
+            Self::Transaction(connection) => connection.describe(sql),
+        }
+    }
+}
@@ -1,7 +1,11 @@
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+    /// The statement clashed with other rows, through a unique or a foreign key constraint.
     #[error("{0}")]
-    ModelNotCreated(String),
+    Conflict(String),
+    /// The statement was rejected by a check constraint.
+    #[error("{0}")]
+    InvalidData(String),
     #[error("No {name} found where {search_key} = {search_value}.")]
     ModelNotFound {
         name: &'static str,
@@ -10,5 +14,21 @@ pub enum Error {
         search_value: String,
     },
     #[error("An unknown error has occurred.")]
-    Unknown(#[from] sqlx::Error),
+    Unknown(#[source] sqlx::Error),
+}
+
+impl From<sqlx::Error> for Error {
+    fn from(error: sqlx::Error) -> Self {
+        if let sqlx::Error::Database(db_error) = &error {
+            if db_error.is_foreign_key_violation() || db_error.is_unique_violation() {
+                return Self::Conflict(db_error.message().to_owned());
+            }
+
+            if db_error.is_check_violation() {
+                return Self::InvalidData(db_error.message().to_owned());
+            }
+        }
+
+        Self::Unknown(error)
+    }
 }
@@ -1,9 +1,16 @@
-pub(self) mod manager;
-pub(self) mod model;
-pub(self) mod query;
-pub(self) mod error;
+mod connection;
+mod manager;
+mod model;
+mod preload;
+mod query;
+mod error;
 
+pub use database_derive::Model;
+pub use connection::{Connection, DatabaseExecutor};
 pub use error::Error;
-pub use manager::{DatabaseManager, DatabaseManagerBuilder};
+pub use manager::{DatabaseManager, DatabaseManagerBuilder, TransactionManager};
 pub use model::{HasRouteKey, Model};
-pub use query::{SqlxAction, SqlxBindable, SqlxQuery};
+pub use preload::Preloaded;
+pub use query::{
+    DeleteAction, InsertAction, IntoBindables, SelectAction, SqlxAction, SqlxBindable, SqlxQuery, UpdateAction, WhereGroup,
+};
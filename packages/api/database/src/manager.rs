@@ -1,4 +1,4 @@
-use sqlx::Transaction;
+use sqlx::{Acquire, Transaction};
 use sqlx::postgres::{PgConnection, PgPool, PgPoolOptions, Postgres};
 
 #[derive(Clone, Debug)]
@@ -10,20 +10,26 @@ pub struct TransactionManager<'t>(Transaction<'t, Postgres>);
 
 impl TransactionManager<'_> {
     pub fn executor(&mut self) -> &mut PgConnection {
-        &mut *self.0
+        &mut self.0
     }
 
-    pub async fn commit(mut self) -> Result<(), sqlx::Error> {
+    /// Starts a transaction nested in this one. Rolling it back only undoes
+    /// what was done since the savepoint, keeping the rest of this transaction.
+    pub async fn savepoint(&mut self) -> Result<TransactionManager<'_>, sqlx::Error> {
+        Ok(TransactionManager(Acquire::begin(&mut self.0).await?))
+    }
+
+    pub async fn commit(self) -> Result<(), sqlx::Error> {
         self.0.commit().await
     }
 
-    pub async fn rollback(mut self) -> Result<(), sqlx::Error> {
+    pub async fn rollback(self) -> Result<(), sqlx::Error> {
         self.0.rollback().await
     }
 }
 
 impl DatabaseManager {
-    pub fn new() -> DatabaseManagerBuilder<NoUrl> {
+    pub fn builder() -> DatabaseManagerBuilder<NoUrl> {
         DatabaseManagerBuilder::new()
     }
 
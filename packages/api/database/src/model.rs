@@ -1,9 +1,10 @@
 use crate::error::Error;
-use crate::manager::DatabaseManager;
+use crate::connection::Connection;
+use crate::preload::Preloaded;
 use crate::query::{SqlxAction, SqlxBindable, SqlxQuery};
 use async_trait::async_trait;
 use sqlx::FromRow;
-use sqlx::postgres::{PgPool, PgRow};
+use sqlx::postgres::PgRow;
 
 #[async_trait]
 pub trait HasRouteKey
@@ -15,7 +16,10 @@ where
 
     fn route_key(&self) -> Self::RouteKey;
 
-    async fn find_by_route_key(key: Self::RouteKey, database: &DatabaseManager) -> Result<Self, Error> {
+    async fn find_by_route_key<C>(key: Self::RouteKey, database: C) -> Result<Self, Error>
+    where
+        C: Connection,
+    {
         Self::find(Self::ROUTE_KEY, key, database).await
     }
 }
@@ -32,35 +36,39 @@ where
     type PrimaryKey: SqlxBindable + Send + Sync + std::fmt::Display + Clone;
     fn primary_key(&self) -> Self::PrimaryKey;
 
-    async fn all(database: &DatabaseManager) -> Result<Vec<Self>, Error> {
+    async fn all<C>(mut database: C) -> Result<Vec<Self>, Error>
+    where
+        C: Connection,
+    {
         let results = Self::query()
             .select(&["*"])
-            .all::<&PgPool, Self>(database.connection())
+            .all::<_, Self>(database.executor())
             .await?;
 
         Ok(results)
     }
 
-    async fn count(database: &DatabaseManager) -> Result<i64, Error> {
-        let result = sqlx::query_as::<_, (i64,)>(format!(
-            "SELECT count(*) FROM {}",
-            Self::TABLE_NAME
-        ).as_str())
-        .fetch_one(database.connection())
-        .await
-        .map(|result| result.0)?;
+    async fn count<C>(mut database: C) -> Result<i64, Error>
+    where
+        C: Connection,
+    {
+        let result = Self::query()
+            .select(&[])
+            .count(database.executor())
+            .await?;
 
         Ok(result)
     }
 
-    async fn find<K>(key: &'static str, value: K, database: &DatabaseManager) -> Result<Self, Error>
+    async fn find<K, C>(key: &'static str, value: K, mut database: C) -> Result<Self, Error>
     where
+        C: Connection,
         K: SqlxBindable + Send + Sync + std::fmt::Display + Clone,
     {
         let model = Self::query()
             .select(&["*"])
             .and_where(key, "=", value.clone())
-            .one::<&PgPool, Self>(database.connection())
+            .one::<_, Self>(database.executor())
             .await
             .map_err(|error| {
                 use sqlx::Error as SqlxError;
@@ -79,26 +87,73 @@ where
         Ok(model)
     }
 
-    async fn find_by_pk(pk: Self::PrimaryKey, database: &DatabaseManager) -> Result<Self, Error> {
+    async fn find_by_pk<C>(pk: Self::PrimaryKey, database: C) -> Result<Self, Error>
+    where
+        C: Connection,
+    {
         Self::find(Self::PRIMARY_KEY, pk, database).await
     }
 
-    async fn has<TKey>(key: &'static str, value: TKey, database: &DatabaseManager) -> Result<bool, Error>
+    async fn has<TKey, C>(key: &'static str, value: TKey, mut database: C) -> Result<bool, Error>
     where
+        C: Connection,
         TKey: SqlxBindable + Send + Sync + std::fmt::Display + Clone,
     {
-        let columns = &[key];
         let result = Self::query()
-            .select(columns)
+            .select(&[])
             .and_where(key, "=", value)
-            .optional::<&PgPool, Self>(database.connection())
-            .await
-            .map(|result| result.is_some())?;
+            .exists(database.executor())
+            .await?;
 
         Ok(result)
     }
 
-    fn query<'q>() -> SqlxQuery {
+    /// Locks the model's row until the surrounding transaction ends. Rows referencing
+    /// the model can't be inserted meanwhile, which makes checks for them reliable.
+    async fn lock<C>(&self, mut database: C) -> Result<(), Error>
+    where
+        C: Connection,
+    {
+        Self::query()
+            .select(&["*"])
+            .and_where(Self::PRIMARY_KEY, "=", self.primary_key())
+            .for_update()
+            .optional::<_, Self>(database.executor())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Loads every model whose `column` holds one of `values` in a single query,
+    /// grouped by `key`. Used to load a relationship for a whole list of models
+    /// at once rather than running a query per model.
+    async fn preload<K, F, C>(
+        column: &'static str,
+        values: Vec<K>,
+        key: F,
+        mut database: C,
+    ) -> Result<Preloaded<K, Self>, Error>
+    where
+        C: Connection,
+        K: Eq + std::hash::Hash + Send + Sync,
+        Vec<K>: SqlxBindable + Send + Sync + 'static,
+        F: Fn(&Self) -> K + Send,
+    {
+        if values.is_empty() {
+            return Ok(Preloaded::default());
+        }
+
+        let models = Self::query()
+            .select(&["*"])
+            .where_in(column, values)
+            .order_by(Self::PRIMARY_KEY, true)
+            .all::<_, Self>(database.executor())
+            .await?;
+
+        Ok(Preloaded::new(models, key))
+    }
+
+    fn query() -> SqlxQuery {
         SqlxQuery::table(Self::TABLE_NAME)
     }
 }
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Models loaded for a batch of keys in a single query, grouped by
+/// the key each model belongs to.
+pub struct Preloaded<K, M> {
+    groups: HashMap<K, Vec<M>>,
+}
+
+impl<K, M> Preloaded<K, M>
+where
+    K: Eq + Hash,
+{
+    pub fn new(models: Vec<M>, key: impl Fn(&M) -> K) -> Self {
+        let mut groups: HashMap<K, Vec<M>> = HashMap::new();
+
+        for model in models {
+            groups.entry(key(&model)).or_default().push(model);
+        }
+
+        Self {
+            groups,
+        }
+    }
+
+    /// Returns every model loaded for `key`, in the order they were loaded.
+    pub fn get(&self, key: &K) -> &[M] {
+        self.groups.get(key)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns every loaded model, regardless of key.
+    pub fn iter(&self) -> impl Iterator<Item = &M> {
+        self.groups.values().flatten()
+    }
+
+    /// Returns the first model loaded for `key`, for relationships that hold a single model.
+    pub fn first(&self, key: &K) -> Option<&M> {
+        self.get(key).first()
+    }
+}
+
+impl<K, M> Default for Preloaded<K, M> {
+    fn default() -> Self {
+        Self {
+            groups: HashMap::new(),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use sqlx::{
     Encode, Type,
-    postgres::{PgArguments, Postgres},
+    postgres::{PgArguments, PgHasArrayType, Postgres},
     query::Query,
 };
 
@@ -37,9 +37,6 @@ macro_rules! impl_bindable {
     }
 }
 
-// Must be re-exported AFTER the macro is defined
-pub(crate) use impl_bindable;
-
 impl_bindable!(bool);
 impl_bindable!(i16, i32, i64);
 impl_bindable!(f32, f64);
@@ -58,6 +55,19 @@ where
     }
 }
 
+impl<T> SqlxBindable for Vec<T>
+where
+    T: for<'r> Encode<'r, Postgres> + Type<Postgres> + PgHasArrayType + Clone + Send + Sync + 'static,
+{
+    fn bind_to_query<'q>(
+        &'q self,
+        query: Query<'q, Postgres, PgArguments>,
+    ) -> Query<'q, Postgres, PgArguments> {
+        let query = query.bind(self.clone());
+        query
+    }
+}
+
 impl SqlxBindable for String {
     fn bind_to_query<'q>(
         &'q self,
@@ -97,3 +107,38 @@ impl SqlxBindable for &str {
         query
     }
 }
+
+/// Values bound to the placeholders of a raw expression, given as a tuple.
+pub trait IntoBindables<'a> {
+    fn into_bindables(self) -> Vec<Box<dyn SqlxBindable + 'a + Send + Sync>>;
+}
+
+impl<'a> IntoBindables<'a> for () {
+    fn into_bindables(self) -> Vec<Box<dyn SqlxBindable + 'a + Send + Sync>> {
+        Vec::new()
+    }
+}
+
+macro_rules! impl_into_bindables {
+    ($(($($name:ident), +)), *) => {
+        $(impl<'a, $($name), +> IntoBindables<'a> for ($($name,)+)
+        where
+            $($name: 'a + Send + SqlxBindable + Sync), +
+        {
+            #[allow(non_snake_case)]
+            fn into_bindables(self) -> Vec<Box<dyn SqlxBindable + 'a + Send + Sync>> {
+                let ($($name,)+) = self;
+                vec![$(Box::new($name)), +]
+            }
+        })*
+    }
+}
+
+impl_into_bindables!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F)
+);
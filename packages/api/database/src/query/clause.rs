@@ -1,9 +1,319 @@
-use super::bind::SqlxBindable;
+use super::bind::{IntoBindables, SqlxBindable};
+use std::fmt;
 
-pub struct WhereClause<'w> {
+pub enum Conjunction {
+    And,
+    Or,
+}
+
+impl fmt::Display for Conjunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::And => write!(f, "AND"),
+            Self::Or => write!(f, "OR"),
+        }
+    }
+}
+
+pub enum WhereClause<'w> {
+    /// `column operator $n`
+    Compare {
+        column: &'static str,
+        operator: &'static str,
+        value: Box<dyn SqlxBindable + 'w + Send + Sync>,
+    },
+    /// `column = ANY($n)`, or `column <> ALL($n)` when negated. The value is bound as an array.
+    In {
+        column: &'static str,
+        values: Box<dyn SqlxBindable + 'w + Send + Sync>,
+        negated: bool,
+    },
+    /// `column IS NULL`, or `column IS NOT NULL` when negated.
+    Null {
+        column: &'static str,
+        negated: bool,
+    },
+    /// `column BETWEEN $n AND $m`
+    Between {
+        column: &'static str,
+        low: Box<dyn SqlxBindable + 'w + Send + Sync>,
+        high: Box<dyn SqlxBindable + 'w + Send + Sync>,
+    },
+    /// A raw expression where every `?` outside of quoted text is replaced with
+    /// the placeholder of the next value. Operators spelled with a `?`, such as
+    /// the jsonb `?`, `?|` and `?&`, can't be used here; call their function
+    /// forms (`jsonb_exists`, `jsonb_exists_any`, ...) instead. Building one with
+    /// more or fewer values than placeholders panics.
+    Raw {
+        sql: String,
+        values: Vec<Box<dyn SqlxBindable + 'w + Send + Sync>>,
+    },
+    /// A parenthesised group of clauses.
+    Group(WhereGroup<'w>),
+}
+
+impl<'w> WhereClause<'w> {
+    /// Renders the clause, numbering its placeholders from `index` onwards
+    /// and advancing `index` past every value the clause binds.
+    pub fn to_sql(&self, index: &mut usize) -> String {
+        match self {
+            Self::Compare { column, operator, .. } => {
+                *index += 1;
+                format!("{} {} ${}", column, operator, *index - 1)
+            },
+            Self::In { column, negated: false, .. } => {
+                *index += 1;
+                format!("{} = ANY(${})", column, *index - 1)
+            },
+            Self::In { column, negated: true, .. } => {
+                *index += 1;
+                format!("{} <> ALL(${})", column, *index - 1)
+            },
+            Self::Null { column, negated: false } => format!("{} IS NULL", column),
+            Self::Null { column, negated: true } => format!("{} IS NOT NULL", column),
+            Self::Between { column, .. } => {
+                *index += 2;
+                format!("{} BETWEEN ${} AND ${}", column, *index - 2, *index - 1)
+            },
+            Self::Raw { sql, .. } => {
+                let segments = raw_segments(sql);
+                let mut rendered = String::with_capacity(sql.len());
+
+                for (position, segment) in segments.iter().enumerate() {
+                    if position > 0 {
+                        rendered.push_str(&format!("${}", index));
+                        *index += 1;
+                    }
+
+                    rendered.push_str(segment);
+                }
+
+                format!("({})", rendered)
+            },
+            Self::Group(group) => format!("({})", group.to_sql(index)),
+        }
+    }
+
+    pub fn binds(&self) -> Vec<&(dyn SqlxBindable + 'w + Send + Sync)> {
+        match self {
+            Self::Compare { value, .. } => vec![value.as_ref()],
+            Self::In { values, .. } => vec![values.as_ref()],
+            Self::Null { .. } => Vec::new(),
+            Self::Between { low, high, .. } => vec![low.as_ref(), high.as_ref()],
+            Self::Raw { values, .. } => values.iter().map(|value| value.as_ref()).collect(),
+            Self::Group(group) => group.binds(),
+        }
+    }
+}
+
+/// Splits a raw expression at every `?` placeholder outside of quoted text.
+fn raw_segments(sql: &str) -> Vec<&str> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+    let mut quote = None;
+
+    for (offset, character) in sql.char_indices() {
+        match (quote, character) {
+            (None, '?') => {
+                segments.push(&sql[start..offset]);
+                start = offset + 1;
+            },
+            (None, '\'' | '"') => quote = Some(character),
+            (Some(open), _) if open == character => quote = None,
+            _ => {},
+        }
+    }
+
+    segments.push(&sql[start..]);
+    segments
+}
+
+/// Builds a raw clause, checking that every placeholder has a value to bind.
+fn raw_clause<'w>(sql: String, values: Vec<Box<dyn SqlxBindable + 'w + Send + Sync>>) -> WhereClause<'w> {
+    let placeholders = raw_segments(&sql).len() - 1;
+
+    assert_eq!(
+        placeholders,
+        values.len(),
+        "raw clause `{}` has {} placeholders but {} values",
+        sql,
+        placeholders,
+        values.len(),
+    );
+
+    WhereClause::Raw { sql, values }
+}
+
+/// A list of where clauses, each joined to the previous one with AND or OR.
+#[derive(Default)]
+pub struct WhereGroup<'w> {
+    clauses: Vec<(Conjunction, WhereClause<'w>)>,
+}
+
+impl<'w> WhereGroup<'w> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.clauses.is_empty()
+    }
+
+    pub fn push(mut self, conjunction: Conjunction, clause: WhereClause<'w>) -> Self {
+        if let WhereClause::Group(group) = &clause {
+            if group.is_empty() {
+                return self;
+            }
+        }
+
+        self.clauses.push((conjunction, clause));
+        self
+    }
+
+    pub fn and_where<V>(self, column: &'static str, operator: &'static str, value: V) -> Self
+    where
+        V: 'w + Send + SqlxBindable + Sync
+    {
+        self.push(Conjunction::And, WhereClause::Compare { column, operator, value: Box::new(value) })
+    }
+
+    pub fn or_where<V>(self, column: &'static str, operator: &'static str, value: V) -> Self
+    where
+        V: 'w + Send + SqlxBindable + Sync
+    {
+        self.push(Conjunction::Or, WhereClause::Compare { column, operator, value: Box::new(value) })
+    }
+
+    pub fn where_in<V>(self, column: &'static str, values: Vec<V>) -> Self
+    where
+        Vec<V>: 'w + Send + SqlxBindable + Sync
+    {
+        self.push(Conjunction::And, WhereClause::In { column, values: Box::new(values), negated: false })
+    }
+
+    pub fn or_where_in<V>(self, column: &'static str, values: Vec<V>) -> Self
+    where
+        Vec<V>: 'w + Send + SqlxBindable + Sync
+    {
+        self.push(Conjunction::Or, WhereClause::In { column, values: Box::new(values), negated: false })
+    }
+
+    pub fn where_not_in<V>(self, column: &'static str, values: Vec<V>) -> Self
+    where
+        Vec<V>: 'w + Send + SqlxBindable + Sync
+    {
+        self.push(Conjunction::And, WhereClause::In { column, values: Box::new(values), negated: true })
+    }
+
+    pub fn where_null(self, column: &'static str) -> Self {
+        self.push(Conjunction::And, WhereClause::Null { column, negated: false })
+    }
+
+    pub fn or_where_null(self, column: &'static str) -> Self {
+        self.push(Conjunction::Or, WhereClause::Null { column, negated: false })
+    }
+
+    pub fn where_not_null(self, column: &'static str) -> Self {
+        self.push(Conjunction::And, WhereClause::Null { column, negated: true })
+    }
+
+    pub fn or_where_not_null(self, column: &'static str) -> Self {
+        self.push(Conjunction::Or, WhereClause::Null { column, negated: true })
+    }
+
+    pub fn where_between<V>(self, column: &'static str, low: V, high: V) -> Self
+    where
+        V: 'w + Send + SqlxBindable + Sync
+    {
+        self.push(Conjunction::And, WhereClause::Between { column, low: Box::new(low), high: Box::new(high) })
+    }
+
+    pub fn where_raw<B>(self, sql: impl Into<String>, values: B) -> Self
+    where
+        B: IntoBindables<'w>
+    {
+        self.push(Conjunction::And, raw_clause(sql.into(), values.into_bindables()))
+    }
+
+    pub fn or_where_raw<B>(self, sql: impl Into<String>, values: B) -> Self
+    where
+        B: IntoBindables<'w>
+    {
+        self.push(Conjunction::Or, raw_clause(sql.into(), values.into_bindables()))
+    }
+
+    pub fn where_group<F>(self, group: F) -> Self
+    where
+        F: FnOnce(WhereGroup<'w>) -> WhereGroup<'w>
+    {
+        self.push(Conjunction::And, WhereClause::Group(group(WhereGroup::new())))
+    }
+
+    pub fn or_where_group<F>(self, group: F) -> Self
+    where
+        F: FnOnce(WhereGroup<'w>) -> WhereGroup<'w>
+    {
+        self.push(Conjunction::Or, WhereClause::Group(group(WhereGroup::new())))
+    }
+
+    /// Renders the clauses, numbering placeholders from `index` onwards.
+    pub fn to_sql(&self, index: &mut usize) -> String {
+        let mut tokens = Vec::new();
+
+        for (position, (conjunction, clause)) in self.clauses.iter().enumerate() {
+            if position > 0 {
+                tokens.push(conjunction.to_string());
+            }
+
+            tokens.push(clause.to_sql(index));
+        }
+
+        tokens.join(" ")
+    }
+
+    pub fn binds(&self) -> Vec<&(dyn SqlxBindable + 'w + Send + Sync)> {
+        self.clauses.iter()
+            .flat_map(|(_, clause)| clause.binds())
+            .collect::<Vec<&(dyn SqlxBindable + 'w + Send + Sync)>>()
+    }
+}
+
+/// A value bound to a column in an INSERT or UPDATE statement.
+pub struct ValueClause<'v> {
     pub column: &'static str,
-    pub operator: &'static str,
-    pub value: Box<dyn SqlxBindable + 'w + Send + Sync>,
+    pub value: Box<dyn SqlxBindable + 'v + Send + Sync>,
+}
+
+pub enum JoinType {
+    Inner,
+    Left,
+}
+
+/// A joined table along with the column comparisons it is joined on.
+pub struct JoinClause {
+    pub kind: JoinType,
+    pub table: &'static str,
+    pub alias: Option<&'static str>,
+    pub conditions: Vec<(&'static str, &'static str, &'static str)>,
+}
+
+impl fmt::Display for JoinClause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            JoinType::Inner => "INNER JOIN",
+            JoinType::Left => "LEFT JOIN",
+        };
+        let table = match self.alias {
+            Some(alias) => format!("{} AS {}", self.table, alias),
+            None => self.table.to_string(),
+        };
+        let conditions = self.conditions.iter()
+            .map(|(first, operator, second)| format!("{} {} {}", first, operator, second))
+            .collect::<Vec<String>>()
+            .join(" AND ");
+
+        write!(f, "{} {} ON {}", kind, table, conditions)
+    }
 }
 
 pub enum OrderClause {
@@ -11,11 +321,59 @@ pub enum OrderClause {
     Descending(&'static str),
 }
 
-impl ToString for OrderClause {
-    fn to_string(&self) -> String {
+impl fmt::Display for OrderClause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            Self::Ascending(expr) => format!("{} ASC", expr),
-            Self::Descending(expr) => format!("{} DESC", expr),
+            Self::Ascending(expr) => write!(f, "{} ASC", expr),
+            Self::Descending(expr) => write!(f, "{} DESC", expr),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::WhereGroup;
+
+    #[test]
+    fn groups_join_clauses_with_their_conjunction() {
+        let group = WhereGroup::new()
+            .and_where("name", "=", 1)
+            .or_where_null("deleted_at")
+            .where_group(|group| group.and_where("id", ">", 2).or_where("id", "<", 3));
+
+        assert_eq!(
+            "name = $1 OR deleted_at IS NULL AND (id > $2 OR id < $3)",
+            group.to_sql(&mut 1),
+        );
+        assert_eq!(3, group.binds().len());
+    }
+
+    #[test]
+    fn raw_clauses_number_each_question_mark() {
+        let group = WhereGroup::new()
+            .and_where("id", "=", 1)
+            .where_raw("similarity(name, ?) > ? OR name = ?", (2, 3, 4));
+
+        assert_eq!(
+            "id = $1 AND (similarity(name, $2) > $3 OR name = $4)",
+            group.to_sql(&mut 1),
+        );
+    }
+
+    #[test]
+    fn raw_clauses_leave_question_marks_in_quoted_text_alone() {
+        let group = WhereGroup::new()
+            .where_raw(r#"name <> 'what''s up?' AND "odd?column" = ?"#, (1,));
+
+        assert_eq!(
+            r#"(name <> 'what''s up?' AND "odd?column" = $1)"#,
+            group.to_sql(&mut 1),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "has 2 placeholders but 1 values")]
+    fn raw_clauses_need_a_value_for_every_question_mark() {
+        WhereGroup::new().where_raw("name = ? OR 'quoted?' = ?", (1,));
+    }
+}
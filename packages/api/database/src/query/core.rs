@@ -1,9 +1,13 @@
 use super::bind::SqlxBindable;
+use super::delete::DeleteAction;
+use super::insert::InsertAction;
 use super::select::SelectAction;
+use super::update::UpdateAction;
 use async_trait::async_trait;
 use sqlx::{
-    Executor, FromRow,
-    postgres::{PgRow, Postgres},
+    Execute, Executor, FromRow,
+    postgres::{PgArguments, PgRow, Postgres},
+    query::{Query, QueryAs},
 };
 
 pub struct SqlxQuery {
@@ -20,29 +24,114 @@ impl SqlxQuery {
     pub fn select<'a>(self, columns: &'a[&'static str]) -> SelectAction<'a> {
         SelectAction::new(self.table, columns)
     }
+
+    pub fn insert<'a>(self) -> InsertAction<'a> {
+        InsertAction::new(self.table)
+    }
+
+    pub fn update<'a>(self) -> UpdateAction<'a> {
+        UpdateAction::new(self.table)
+    }
+
+    pub fn delete<'a>(self) -> DeleteAction<'a> {
+        DeleteAction::new(self.table)
+    }
 }
 
 #[async_trait]
 pub trait SqlxAction<'a> {
     fn sql(&self) -> String;
 
-    // Making the items be references to a Box rather than just a box
-    // prevents the value from being dropped when putting together the SQL string
-    // Not sure why.
-    fn binds(&self) -> Vec<&Box<dyn SqlxBindable + 'a + Send + Sync>>;
+    fn binds(&self) -> Vec<&(dyn SqlxBindable + 'a + Send + Sync)>;
 
-    async fn one<D, R>(&'a self, db: D) -> Result<R, sqlx::Error>
+    /// Prepares `sql` with the action's values bound to it.
+    fn query<'q>(&'q self, sql: &'q str) -> Query<'q, Postgres, PgArguments>
     where
-        D: for<'e> Executor<'e, Database = Postgres>,
-        R: for<'r> FromRow<'r, PgRow> + Send + Unpin;
+        'a: 'q,
+    {
+        let mut query = sqlx::query(sql);
+        for value in self.binds().into_iter() {
+            query = value.bind_to_query(query);
+        }
+
+        query
+    }
 
-    async fn optional<D, R>(&'a self, db: D) -> Result<Option<R>, sqlx::Error>
+    /// Prepares `sql` with the action's values bound to it, mapping the rows to `R`.
+    fn query_as<'q, R>(&'q self, sql: &'q str) -> QueryAs<'q, Postgres, R, PgArguments>
     where
-        D: for<'e> Executor<'e, Database = Postgres>,
-        R: for<'r> FromRow<'r, PgRow> + Send + Unpin;
+        'a: 'q,
+        R: for<'r> FromRow<'r, PgRow>,
+    {
+        let mut query = self.query(sql);
 
-    async fn all<D, R>(&'a self, db: D) -> Result<Vec<R>, sqlx::Error>
+        sqlx::query_as_with::<Postgres, R, PgArguments>(sql, query.take_arguments().unwrap())
+    }
+
+    /// Whether the action has nothing to do. Such an action never reaches the
+    /// database and behaves as if it matched no rows.
+    fn is_noop(&self) -> bool {
+        false
+    }
+
+    /// Runs the statement, returning the number of rows it affected.
+    async fn execute<'e, D>(&'a self, database: D) -> Result<u64, sqlx::Error>
     where
-        D: for<'e> Executor<'e, Database = Postgres>,
-        R: for<'r> FromRow<'r, PgRow> + Send + Unpin;
+        Self: Sync,
+        D: Executor<'e, Database = Postgres>,
+    {
+        if self.is_noop() {
+            return Ok(0);
+        }
+
+        let sql = self.sql();
+
+        let res = self.query(&sql).execute(database).await?;
+        Ok(res.rows_affected())
+    }
+
+    async fn one<'e, D, R>(&'a self, database: D) -> Result<R, sqlx::Error>
+    where
+        Self: Sync,
+        D: Executor<'e, Database = Postgres>,
+        R: for<'r> FromRow<'r, PgRow> + Send + Unpin,
+    {
+        if self.is_noop() {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        let sql = self.sql();
+
+        self.query_as(&sql).fetch_one(database).await
+    }
+
+    async fn optional<'e, D, R>(&'a self, database: D) -> Result<Option<R>, sqlx::Error>
+    where
+        Self: Sync,
+        D: Executor<'e, Database = Postgres>,
+        R: for<'r> FromRow<'r, PgRow> + Send + Unpin,
+    {
+        if self.is_noop() {
+            return Ok(None);
+        }
+
+        let sql = self.sql();
+
+        self.query_as(&sql).fetch_optional(database).await
+    }
+
+    async fn all<'e, D, R>(&'a self, database: D) -> Result<Vec<R>, sqlx::Error>
+    where
+        Self: Sync,
+        D: Executor<'e, Database = Postgres>,
+        R: for<'r> FromRow<'r, PgRow> + Send + Unpin,
+    {
+        if self.is_noop() {
+            return Ok(Vec::new());
+        }
+
+        let sql = self.sql();
+
+        self.query_as(&sql).fetch_all(database).await
+    }
 }
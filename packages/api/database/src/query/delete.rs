@@ -0,0 +1,171 @@
+use super::SqlxAction;
+use super::bind::{IntoBindables, SqlxBindable};
+use super::clause::WhereGroup;
+
+pub struct DeleteAction<'a> {
+    table: &'static str,
+    wheres: WhereGroup<'a>,
+    returning: &'a[&'static str],
+}
+
+impl<'a> DeleteAction<'a> {
+    pub fn new(table: &'static str) -> Self {
+        Self {
+            table,
+            wheres: WhereGroup::new(),
+            returning: &[],
+        }
+    }
+
+    pub fn and_where<V>(mut self, name: &'static str, operator: &'static str, value: V) -> Self
+    where
+        V: 'a + Send + SqlxBindable + Sync
+    {
+        self.wheres = self.wheres.and_where(name, operator, value);
+        self
+    }
+
+    pub fn or_where<V>(mut self, name: &'static str, operator: &'static str, value: V) -> Self
+    where
+        V: 'a + Send + SqlxBindable + Sync
+    {
+        self.wheres = self.wheres.or_where(name, operator, value);
+        self
+    }
+
+    pub fn where_in<V>(mut self, name: &'static str, values: Vec<V>) -> Self
+    where
+        Vec<V>: 'a + Send + SqlxBindable + Sync
+    {
+        self.wheres = self.wheres.where_in(name, values);
+        self
+    }
+
+    pub fn or_where_in<V>(mut self, name: &'static str, values: Vec<V>) -> Self
+    where
+        Vec<V>: 'a + Send + SqlxBindable + Sync
+    {
+        self.wheres = self.wheres.or_where_in(name, values);
+        self
+    }
+
+    pub fn where_not_in<V>(mut self, name: &'static str, values: Vec<V>) -> Self
+    where
+        Vec<V>: 'a + Send + SqlxBindable + Sync
+    {
+        self.wheres = self.wheres.where_not_in(name, values);
+        self
+    }
+
+    pub fn where_null(mut self, name: &'static str) -> Self {
+        self.wheres = self.wheres.where_null(name);
+        self
+    }
+
+    pub fn or_where_null(mut self, name: &'static str) -> Self {
+        self.wheres = self.wheres.or_where_null(name);
+        self
+    }
+
+    pub fn where_not_null(mut self, name: &'static str) -> Self {
+        self.wheres = self.wheres.where_not_null(name);
+        self
+    }
+
+    pub fn or_where_not_null(mut self, name: &'static str) -> Self {
+        self.wheres = self.wheres.or_where_not_null(name);
+        self
+    }
+
+    pub fn where_between<V>(mut self, name: &'static str, low: V, high: V) -> Self
+    where
+        V: 'a + Send + SqlxBindable + Sync
+    {
+        self.wheres = self.wheres.where_between(name, low, high);
+        self
+    }
+
+    /// Adds a raw expression, binding `values` to its `?` placeholders in order.
+    pub fn where_raw<B>(mut self, sql: impl Into<String>, values: B) -> Self
+    where
+        B: IntoBindables<'a>
+    {
+        self.wheres = self.wheres.where_raw(sql, values);
+        self
+    }
+
+    pub fn or_where_raw<B>(mut self, sql: impl Into<String>, values: B) -> Self
+    where
+        B: IntoBindables<'a>
+    {
+        self.wheres = self.wheres.or_where_raw(sql, values);
+        self
+    }
+
+    /// Adds a parenthesised group of clauses built by `group`.
+    pub fn where_group<F>(mut self, group: F) -> Self
+    where
+        F: FnOnce(WhereGroup<'a>) -> WhereGroup<'a>
+    {
+        self.wheres = self.wheres.where_group(group);
+        self
+    }
+
+    pub fn or_where_group<F>(mut self, group: F) -> Self
+    where
+        F: FnOnce(WhereGroup<'a>) -> WhereGroup<'a>
+    {
+        self.wheres = self.wheres.or_where_group(group);
+        self
+    }
+
+    pub fn returning(mut self, columns: &'a[&'static str]) -> Self {
+        self.returning = columns;
+        self
+    }
+}
+
+impl<'a> SqlxAction<'a> for DeleteAction<'a> {
+    fn sql(&self) -> String {
+        let mut tokens = vec![format!("DELETE FROM {}", self.table)];
+
+        if !self.wheres.is_empty() {
+            tokens.push("WHERE".to_string());
+
+            let mut index = 1;
+            tokens.push(self.wheres.to_sql(&mut index));
+        }
+
+        if !self.returning.is_empty() {
+            tokens.push(format!("RETURNING {}", self.returning.join(", ")));
+        }
+
+        tokens.join(" ")
+    }
+
+    fn binds(&self) -> Vec<&(dyn SqlxBindable + 'a + Send + Sync)> {
+        self.wheres.binds()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DeleteAction;
+    use crate::query::SqlxAction;
+
+    #[test]
+    fn sql_renders_every_kind_of_where() {
+        let action = DeleteAction::new("sessions")
+            .and_where("user_id", "=", 1)
+            .or_where_not_null("revoked_at")
+            .where_not_in("id", vec![2, 3])
+            .where_between("expires_at", 4, 5)
+            .returning(&["id"]);
+
+        assert_eq!(
+            "DELETE FROM sessions WHERE user_id = $1 OR revoked_at IS NOT NULL AND id <> ALL($2) AND expires_at BETWEEN $3 AND $4 RETURNING id",
+            action.sql(),
+        );
+        assert_eq!(4, action.binds().len());
+    }
+}
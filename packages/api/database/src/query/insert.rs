@@ -0,0 +1,68 @@
+use super::SqlxAction;
+use super::bind::SqlxBindable;
+use super::clause::ValueClause;
+
+pub struct InsertAction<'a> {
+    table: &'static str,
+    values: Vec<ValueClause<'a>>,
+    returning: &'a[&'static str],
+}
+
+impl<'a> InsertAction<'a> {
+    pub fn new(table: &'static str) -> Self {
+        Self {
+            table,
+            values: Vec::new(),
+            returning: &[],
+        }
+    }
+
+    pub fn value<V>(mut self, column: &'static str, value: V) -> Self
+    where
+        V: 'a + Send + SqlxBindable + Sync
+    {
+        self.values.push(ValueClause {
+            column,
+            value: Box::new(value),
+        });
+        self
+    }
+
+    pub fn returning(mut self, columns: &'a[&'static str]) -> Self {
+        self.returning = columns;
+        self
+    }
+}
+
+impl<'a> SqlxAction<'a> for InsertAction<'a> {
+    fn sql(&self) -> String {
+        let mut tokens = vec![format!("INSERT INTO {}", self.table)];
+
+        if self.values.is_empty() {
+            tokens.push("DEFAULT VALUES".to_string());
+        } else {
+            let columns = self.values.iter()
+                .map(|clause| clause.column)
+                .collect::<Vec<&str>>()
+                .join(", ");
+            let placeholders = (1..=self.values.len())
+                .map(|index| format!("${}", index))
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            tokens.push(format!("({}) VALUES ({})", columns, placeholders));
+        }
+
+        if !self.returning.is_empty() {
+            tokens.push(format!("RETURNING {}", self.returning.join(", ")));
+        }
+
+        tokens.join(" ")
+    }
+
+    fn binds(&self) -> Vec<&(dyn SqlxBindable + 'a + Send + Sync)> {
+        self.values.iter()
+            .map(|clause| clause.value.as_ref())
+            .collect::<Vec<&(dyn SqlxBindable + 'a + Send + Sync)>>()
+    }
+}
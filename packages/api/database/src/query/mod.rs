@@ -1,7 +1,15 @@
 mod bind;
 mod clause;
 mod core;
+mod delete;
+mod insert;
 mod select;
+mod update;
 
-pub use self::bind::SqlxBindable;
+pub use self::bind::{IntoBindables, SqlxBindable};
+pub use self::clause::WhereGroup;
 pub use self::core::{SqlxAction, SqlxQuery};
+pub use self::delete::DeleteAction;
+pub use self::insert::InsertAction;
+pub use self::select::SelectAction;
+pub use self::update::UpdateAction;
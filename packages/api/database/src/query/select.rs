@@ -1,39 +1,215 @@
 use super::SqlxAction;
-use super::bind::SqlxBindable;
-use super::clause::{OrderClause, WhereClause};
-use async_trait::async_trait;
-use sqlx::{Execute, Executor, FromRow, postgres::{PgArguments, PgRow, Postgres}};
+use super::bind::{IntoBindables, SqlxBindable};
+use super::clause::{JoinClause, JoinType, OrderClause, WhereGroup};
+use sqlx::{Decode, Execute, Executor, Type, postgres::{PgArguments, Postgres}};
 
 pub struct SelectAction<'a> {
     table: &'static str,
+    alias: Option<&'static str>,
     columns: &'a[&'static str],
-    wheres: Vec<WhereClause<'a>>,
+    joins: Vec<JoinClause>,
+    wheres: WhereGroup<'a>,
+    groups: Vec<&'static str>,
+    havings: WhereGroup<'a>,
     orders: Vec<OrderClause>,
     limit: Option<i64>,
     offset: Option<i64>,
+    for_update: bool,
 }
 
 impl<'a> SelectAction<'a> {
     pub fn new(table: &'static str, columns: &'a[&'static str]) -> Self {
         Self {
             table,
+            alias: None,
             columns,
-            wheres: Vec::new(),
+            joins: Vec::new(),
+            wheres: WhereGroup::new(),
+            groups: Vec::new(),
+            havings: WhereGroup::new(),
             orders: Vec::new(),
             limit: None,
             offset: None,
+            for_update: false,
         }
     }
 
+    /// Aliases the most recently added table, which is the joined table
+    /// after a join and the table being selected from otherwise.
+    pub fn alias(mut self, alias: &'static str) -> Self {
+        match self.joins.last_mut() {
+            Some(join) => join.alias = Some(alias),
+            None => self.alias = Some(alias),
+        }
+        self
+    }
+
+    pub fn inner_join(
+        self,
+        table: &'static str,
+        first: &'static str,
+        operator: &'static str,
+        second: &'static str,
+    ) -> Self {
+        self.join(JoinType::Inner, table, first, operator, second)
+    }
+
+    pub fn left_join(
+        self,
+        table: &'static str,
+        first: &'static str,
+        operator: &'static str,
+        second: &'static str,
+    ) -> Self {
+        self.join(JoinType::Left, table, first, operator, second)
+    }
+
+    /// Adds another column comparison to the ON clause of the most recent join.
+    pub fn join_on(mut self, first: &'static str, operator: &'static str, second: &'static str) -> Self {
+        if let Some(join) = self.joins.last_mut() {
+            join.conditions.push((first, operator, second));
+        }
+        self
+    }
+
+    fn join(
+        mut self,
+        kind: JoinType,
+        table: &'static str,
+        first: &'static str,
+        operator: &'static str,
+        second: &'static str,
+    ) -> Self {
+        self.joins.push(JoinClause {
+            kind,
+            table,
+            alias: None,
+            conditions: vec![(first, operator, second)],
+        });
+        self
+    }
+
     pub fn and_where<V>(mut self, name: &'static str, operator: &'static str, value: V) -> Self
     where
         V: 'a + Send + SqlxBindable + Sync
     {
-        self.wheres.push(WhereClause {
-            column: name,
-            operator,
-            value: Box::new(value),
-        });
+        self.wheres = self.wheres.and_where(name, operator, value);
+        self
+    }
+
+    pub fn or_where<V>(mut self, name: &'static str, operator: &'static str, value: V) -> Self
+    where
+        V: 'a + Send + SqlxBindable + Sync
+    {
+        self.wheres = self.wheres.or_where(name, operator, value);
+        self
+    }
+
+    pub fn where_in<V>(mut self, name: &'static str, values: Vec<V>) -> Self
+    where
+        Vec<V>: 'a + Send + SqlxBindable + Sync
+    {
+        self.wheres = self.wheres.where_in(name, values);
+        self
+    }
+
+    pub fn or_where_in<V>(mut self, name: &'static str, values: Vec<V>) -> Self
+    where
+        Vec<V>: 'a + Send + SqlxBindable + Sync
+    {
+        self.wheres = self.wheres.or_where_in(name, values);
+        self
+    }
+
+    pub fn where_not_in<V>(mut self, name: &'static str, values: Vec<V>) -> Self
+    where
+        Vec<V>: 'a + Send + SqlxBindable + Sync
+    {
+        self.wheres = self.wheres.where_not_in(name, values);
+        self
+    }
+
+    pub fn where_null(mut self, name: &'static str) -> Self {
+        self.wheres = self.wheres.where_null(name);
+        self
+    }
+
+    pub fn or_where_null(mut self, name: &'static str) -> Self {
+        self.wheres = self.wheres.or_where_null(name);
+        self
+    }
+
+    pub fn where_not_null(mut self, name: &'static str) -> Self {
+        self.wheres = self.wheres.where_not_null(name);
+        self
+    }
+
+    pub fn or_where_not_null(mut self, name: &'static str) -> Self {
+        self.wheres = self.wheres.or_where_not_null(name);
+        self
+    }
+
+    pub fn where_between<V>(mut self, name: &'static str, low: V, high: V) -> Self
+    where
+        V: 'a + Send + SqlxBindable + Sync
+    {
+        self.wheres = self.wheres.where_between(name, low, high);
+        self
+    }
+
+    /// Adds a raw expression, binding `values` to its `?` placeholders in order.
+    pub fn where_raw<B>(mut self, sql: impl Into<String>, values: B) -> Self
+    where
+        B: IntoBindables<'a>
+    {
+        self.wheres = self.wheres.where_raw(sql, values);
+        self
+    }
+
+    pub fn or_where_raw<B>(mut self, sql: impl Into<String>, values: B) -> Self
+    where
+        B: IntoBindables<'a>
+    {
+        self.wheres = self.wheres.or_where_raw(sql, values);
+        self
+    }
+
+    /// Adds a parenthesised group of clauses built by `group`.
+    pub fn where_group<F>(mut self, group: F) -> Self
+    where
+        F: FnOnce(WhereGroup<'a>) -> WhereGroup<'a>
+    {
+        self.wheres = self.wheres.where_group(group);
+        self
+    }
+
+    pub fn or_where_group<F>(mut self, group: F) -> Self
+    where
+        F: FnOnce(WhereGroup<'a>) -> WhereGroup<'a>
+    {
+        self.wheres = self.wheres.or_where_group(group);
+        self
+    }
+
+    pub fn group_by(mut self, name: &'static str) -> Self {
+        self.groups.push(name);
+        self
+    }
+
+    pub fn having<V>(mut self, expression: &'static str, operator: &'static str, value: V) -> Self
+    where
+        V: 'a + Send + SqlxBindable + Sync
+    {
+        self.havings = self.havings.and_where(expression, operator, value);
+        self
+    }
+
+    /// Adds a raw HAVING expression, binding `values` to its `?` placeholders in order.
+    pub fn having_raw<B>(mut self, sql: impl Into<String>, values: B) -> Self
+    where
+        B: IntoBindables<'a>
+    {
+        self.havings = self.havings.where_raw(sql, values);
         self
     }
 
@@ -55,92 +231,125 @@ impl<'a> SelectAction<'a> {
         self
     }
 
-    pub async fn one<D, R>(&'a self, database: D) -> Result<R, sqlx::Error>
+    /// Locks the selected rows until the surrounding transaction ends.
+    pub fn for_update(mut self) -> Self {
+        self.for_update = true;
+        self
+    }
+
+    /// Counts the rows matching the query, ignoring its order, limit and offset.
+    pub async fn count<'e, D>(&self, database: D) -> Result<i64, sqlx::Error>
     where
-        D: for<'e> Executor<'e, Database = Postgres>,
-        R: for<'r> FromRow<'r, PgRow> + Send + Unpin,
+        D: Executor<'e, Database = Postgres>,
     {
-        let sql = self.sql();
-        let values = self.binds();
+        let sql = match self.groups.is_empty() {
+            true => self.unordered_sql("count(*)"),
+            false => format!("SELECT count(*) FROM ({}) AS counted", self.unordered_sql("1")),
+        };
 
-        let mut query = sqlx::query(&sql);
-        for value in values.into_iter() {
-            query = value.bind_to_query(query);
-        }
-
-        let query = sqlx::query_as_with::<Postgres, R, PgArguments>(&sql, query.take_arguments().unwrap());
-
-        let res = query.fetch_one(database).await?;
-        Ok(res)
+        self.scalar(sql, database).await
     }
 
-    pub async fn optional<D, R>(&'a self, database: D) -> Result<Option<R>, sqlx::Error>
+    /// Returns whether any row matches the query.
+    pub async fn exists<'e, D>(&self, database: D) -> Result<bool, sqlx::Error>
     where
-        D: for<'e> Executor<'e, Database = Postgres>,
-        R: for<'r> FromRow<'r, PgRow> + Send + Unpin,
+        D: Executor<'e, Database = Postgres>,
     {
-        let sql = self.sql();
-        let values = self.binds();
+        let sql = format!("SELECT EXISTS ({})", self.unordered_sql("1"));
 
-        let mut query = sqlx::query(&sql);
-        for value in values.into_iter() {
-            query = value.bind_to_query(query);
-        }
+        self.scalar(sql, database).await
+    }
 
-        let query = sqlx::query_as_with::<Postgres, R, PgArguments>(&sql, query.take_arguments().unwrap());
+    /// Sums `column` over the matching rows. Postgres widens the result,
+    /// so integer columns sum to an `i64`. Returns `None` when no rows match.
+    pub async fn sum<'e, D, T>(&self, column: &'static str, database: D) -> Result<Option<T>, sqlx::Error>
+    where
+        D: Executor<'e, Database = Postgres>,
+        Option<T>: for<'r> Decode<'r, Postgres> + Type<Postgres> + Send + Unpin,
+    {
+        self.scalar(self.unordered_sql(&format!("SUM({})", column)), database).await
+    }
 
-        let res = query.fetch_optional(database).await?;
-        Ok(res)
+    /// Averages `column` over the matching rows. Returns `None` when no rows match.
+    pub async fn avg<'e, D>(&self, column: &'static str, database: D) -> Result<Option<f64>, sqlx::Error>
+    where
+        D: Executor<'e, Database = Postgres>,
+    {
+        self.scalar(self.unordered_sql(&format!("AVG({})::DOUBLE PRECISION", column)), database).await
     }
 
-    pub async fn all<D, R>(&'a self, database: D) -> Result<Vec<R>, sqlx::Error>
+    /// Returns the smallest value of `column` in the matching rows.
+    pub async fn min<'e, D, T>(&self, column: &'static str, database: D) -> Result<Option<T>, sqlx::Error>
     where
-        D: for<'e> Executor<'e, Database = Postgres>,
-        R: for<'r> FromRow<'r, PgRow> + Send + Unpin,
+        D: Executor<'e, Database = Postgres>,
+        Option<T>: for<'r> Decode<'r, Postgres> + Type<Postgres> + Send + Unpin,
     {
-        let sql = self.sql();
-        let values = self.binds();
+        self.scalar(self.unordered_sql(&format!("MIN({})", column)), database).await
+    }
 
-        let mut query = sqlx::query(&sql);
-        for value in values.into_iter() {
-            query = value.bind_to_query(query);
-        }
+    /// Returns the largest value of `column` in the matching rows.
+    pub async fn max<'e, D, T>(&self, column: &'static str, database: D) -> Result<Option<T>, sqlx::Error>
+    where
+        D: Executor<'e, Database = Postgres>,
+        Option<T>: for<'r> Decode<'r, Postgres> + Type<Postgres> + Send + Unpin,
+    {
+        self.scalar(self.unordered_sql(&format!("MAX({})", column)), database).await
+    }
 
-        let query = sqlx::query_as_with::<Postgres, R, PgArguments>(&sql, query.take_arguments().unwrap());
+    async fn scalar<'e, D, T>(&self, sql: String, database: D) -> Result<T, sqlx::Error>
+    where
+        D: Executor<'e, Database = Postgres>,
+        T: for<'r> Decode<'r, Postgres> + Type<Postgres> + Send + Unpin,
+    {
+        let mut query = self.query(&sql);
+        let query = sqlx::query_scalar_with::<Postgres, T, PgArguments>(&sql, query.take_arguments().unwrap());
 
-        let res = query.fetch_all(database).await?;
+        let res = query.fetch_one(database).await?;
         Ok(res)
     }
-}
 
-#[async_trait]
-impl<'a> SqlxAction<'a> for SelectAction<'a> {
-    fn sql(&self) -> String {
-        let mut tokens = vec!["SELECT".to_string()];
+    /// Renders everything up to and including the HAVING clause, selecting `columns`.
+    fn unordered_sql(&self, columns: &str) -> String {
+        let mut tokens = vec![format!("SELECT {}", columns)];
+
+        let table_token = match self.alias {
+            Some(alias) => format!("FROM {} AS {}", self.table, alias),
+            None => format!("FROM {}", self.table),
+        };
+        tokens.push(table_token);
 
-        if !self.columns.is_empty() {
-            let columns = self.columns.join(", ");
-            tokens.push(columns);
-        } else {
-            tokens.push("*".to_string());
+        for join in self.joins.iter() {
+            tokens.push(join.to_string());
         }
 
-        let table_token = format!("FROM {}", self.table);
-        tokens.push(table_token);
+        let mut index = 1;
 
         if !self.wheres.is_empty() {
             tokens.push("WHERE".to_string());
+            tokens.push(self.wheres.to_sql(&mut index));
+        }
 
-            let joined_wheres = self.wheres.iter().enumerate()
-                .map(|(index, clause)| {
-                    format!("{} {} ${}", clause.column, clause.operator, index + 1)
-                })
-                .collect::<Vec<String>>()
-                .join(" AND ");
+        if !self.groups.is_empty() {
+            tokens.push(format!("GROUP BY {}", self.groups.join(", ")));
+        }
 
-            tokens.push(joined_wheres);
+        if !self.havings.is_empty() {
+            tokens.push("HAVING".to_string());
+            tokens.push(self.havings.to_sql(&mut index));
         }
 
+        tokens.join(" ")
+    }
+}
+
+impl<'a> SqlxAction<'a> for SelectAction<'a> {
+    fn sql(&self) -> String {
+        let columns = match self.columns.is_empty() {
+            true => "*".to_string(),
+            false => self.columns.join(", "),
+        };
+        let mut tokens = vec![self.unordered_sql(&columns)];
+
         if !self.orders.is_empty() {
             tokens.push("ORDER BY".to_string());
 
@@ -160,6 +369,10 @@ impl<'a> SqlxAction<'a> for SelectAction<'a> {
             tokens.push(format!("OFFSET {}", offset));
         }
 
+        if self.for_update {
+            tokens.push("FOR UPDATE".to_string());
+        }
+
         let res = tokens.join(" ");
 
         eprintln!("{}", res);
@@ -167,33 +380,26 @@ impl<'a> SqlxAction<'a> for SelectAction<'a> {
         res
     }
 
-    fn binds(&self) -> Vec<&Box<dyn SqlxBindable + 'a + Send + Sync>> {
-        self.wheres.iter()
-            .map(|clause| &clause.value)
-            .collect::<Vec<&Box<dyn SqlxBindable + 'a + Send + Sync>>>()
+    fn binds(&self) -> Vec<&(dyn SqlxBindable + 'a + Send + Sync)> {
+        self.wheres.binds()
+            .into_iter()
+            .chain(self.havings.binds())
+            .collect::<Vec<&(dyn SqlxBindable + 'a + Send + Sync)>>()
     }
+}
 
-    async fn one<D, R>(&'a self, database: D) -> Result<R, sqlx::Error>
-    where
-        D: for<'e> Executor<'e, Database = Postgres>,
-        R: for<'r> FromRow<'r, PgRow> + Send + Unpin,
-    {
-        Self::one(self, database).await
-    }
+#[cfg(test)]
+mod tests {
+    use super::SelectAction;
+    use crate::query::SqlxAction;
 
-    async fn optional<D, R>(&'a self, database: D) -> Result<Option<R>, sqlx::Error>
-    where
-        D: for<'e> Executor<'e, Database = Postgres>,
-        R: for<'r> FromRow<'r, PgRow> + Send + Unpin,
-    {
-        Self::optional(self, database).await
-    }
+    #[test]
+    fn sql_locks_rows_after_limiting_them() {
+        let action = SelectAction::new("exercises", &["*"])
+            .and_where("id", "=", 1)
+            .limit(1)
+            .for_update();
 
-    async fn all<D, R>(&'a self, database: D) -> Result<Vec<R>, sqlx::Error>
-    where
-        D: for<'e> Executor<'e, Database = Postgres>,
-        R: for<'r> FromRow<'r, PgRow> + Send + Unpin,
-    {
-        Self::all(self, database).await
+        assert_eq!("SELECT * FROM exercises WHERE id = $1 LIMIT 1 FOR UPDATE", action.sql());
     }
 }
@@ -0,0 +1,223 @@
+use super::SqlxAction;
+use super::bind::{IntoBindables, SqlxBindable};
+use super::clause::{ValueClause, WhereGroup};
+
+pub struct UpdateAction<'a> {
+    table: &'static str,
+    values: Vec<ValueClause<'a>>,
+    wheres: WhereGroup<'a>,
+    returning: &'a[&'static str],
+}
+
+impl<'a> UpdateAction<'a> {
+    pub fn new(table: &'static str) -> Self {
+        Self {
+            table,
+            values: Vec::new(),
+            wheres: WhereGroup::new(),
+            returning: &[],
+        }
+    }
+
+    pub fn set<V>(mut self, column: &'static str, value: V) -> Self
+    where
+        V: 'a + Send + SqlxBindable + Sync
+    {
+        self.values.push(ValueClause {
+            column,
+            value: Box::new(value),
+        });
+        self
+    }
+
+    pub fn and_where<V>(mut self, name: &'static str, operator: &'static str, value: V) -> Self
+    where
+        V: 'a + Send + SqlxBindable + Sync
+    {
+        self.wheres = self.wheres.and_where(name, operator, value);
+        self
+    }
+
+    pub fn or_where<V>(mut self, name: &'static str, operator: &'static str, value: V) -> Self
+    where
+        V: 'a + Send + SqlxBindable + Sync
+    {
+        self.wheres = self.wheres.or_where(name, operator, value);
+        self
+    }
+
+    pub fn where_in<V>(mut self, name: &'static str, values: Vec<V>) -> Self
+    where
+        Vec<V>: 'a + Send + SqlxBindable + Sync
+    {
+        self.wheres = self.wheres.where_in(name, values);
+        self
+    }
+
+    pub fn or_where_in<V>(mut self, name: &'static str, values: Vec<V>) -> Self
+    where
+        Vec<V>: 'a + Send + SqlxBindable + Sync
+    {
+        self.wheres = self.wheres.or_where_in(name, values);
+        self
+    }
+
+    pub fn where_not_in<V>(mut self, name: &'static str, values: Vec<V>) -> Self
+    where
+        Vec<V>: 'a + Send + SqlxBindable + Sync
+    {
+        self.wheres = self.wheres.where_not_in(name, values);
+        self
+    }
+
+    pub fn where_null(mut self, name: &'static str) -> Self {
+        self.wheres = self.wheres.where_null(name);
+        self
+    }
+
+    pub fn or_where_null(mut self, name: &'static str) -> Self {
+        self.wheres = self.wheres.or_where_null(name);
+        self
+    }
+
+    pub fn where_not_null(mut self, name: &'static str) -> Self {
+        self.wheres = self.wheres.where_not_null(name);
+        self
+    }
+
+    pub fn or_where_not_null(mut self, name: &'static str) -> Self {
+        self.wheres = self.wheres.or_where_not_null(name);
+        self
+    }
+
+    pub fn where_between<V>(mut self, name: &'static str, low: V, high: V) -> Self
+    where
+        V: 'a + Send + SqlxBindable + Sync
+    {
+        self.wheres = self.wheres.where_between(name, low, high);
+        self
+    }
+
+    /// Adds a raw expression, binding `values` to its `?` placeholders in order.
+    pub fn where_raw<B>(mut self, sql: impl Into<String>, values: B) -> Self
+    where
+        B: IntoBindables<'a>
+    {
+        self.wheres = self.wheres.where_raw(sql, values);
+        self
+    }
+
+    pub fn or_where_raw<B>(mut self, sql: impl Into<String>, values: B) -> Self
+    where
+        B: IntoBindables<'a>
+    {
+        self.wheres = self.wheres.or_where_raw(sql, values);
+        self
+    }
+
+    /// Adds a parenthesised group of clauses built by `group`.
+    pub fn where_group<F>(mut self, group: F) -> Self
+    where
+        F: FnOnce(WhereGroup<'a>) -> WhereGroup<'a>
+    {
+        self.wheres = self.wheres.where_group(group);
+        self
+    }
+
+    pub fn or_where_group<F>(mut self, group: F) -> Self
+    where
+        F: FnOnce(WhereGroup<'a>) -> WhereGroup<'a>
+    {
+        self.wheres = self.wheres.or_where_group(group);
+        self
+    }
+
+    pub fn returning(mut self, columns: &'a[&'static str]) -> Self {
+        self.returning = columns;
+        self
+    }
+}
+
+impl<'a> SqlxAction<'a> for UpdateAction<'a> {
+    fn sql(&self) -> String {
+        let mut tokens = vec![format!("UPDATE {} SET", self.table)];
+
+        let assignments = self.values.iter().enumerate()
+            .map(|(index, clause)| format!("{} = ${}", clause.column, index + 1))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        tokens.push(assignments);
+
+        if !self.wheres.is_empty() {
+            tokens.push("WHERE".to_string());
+
+            let mut index = self.values.len() + 1;
+            tokens.push(self.wheres.to_sql(&mut index));
+        }
+
+        if !self.returning.is_empty() {
+            tokens.push(format!("RETURNING {}", self.returning.join(", ")));
+        }
+
+        tokens.join(" ")
+    }
+
+    fn binds(&self) -> Vec<&(dyn SqlxBindable + 'a + Send + Sync)> {
+        self.values.iter()
+            .map(|clause| clause.value.as_ref())
+            .chain(self.wheres.binds())
+            .collect::<Vec<&(dyn SqlxBindable + 'a + Send + Sync)>>()
+    }
+
+    /// Postgres rejects an `UPDATE` without any assignments, so an update with
+    /// nothing to set is never run and affects no rows.
+    fn is_noop(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UpdateAction;
+    use crate::query::SqlxAction;
+
+    #[test]
+    fn sql_numbers_values_before_wheres() {
+        let action = UpdateAction::new("users")
+            .set("first_name", 1)
+            .set("last_name", 2)
+            .and_where("id", "=", 3)
+            .returning(&["*"]);
+
+        assert_eq!(
+            "UPDATE users SET first_name = $1, last_name = $2 WHERE id = $3 RETURNING *",
+            action.sql(),
+        );
+        assert_eq!(3, action.binds().len());
+    }
+
+    #[test]
+    fn sql_numbers_grouped_and_raw_wheres_after_values() {
+        let action = UpdateAction::new("users")
+            .set("role", 1)
+            .where_in("id", vec![2, 3])
+            .where_group(|group| group.where_null("deleted_at").or_where_raw("deleted_at > ?", (4,)));
+
+        assert_eq!(
+            "UPDATE users SET role = $1 WHERE id = ANY($2) AND (deleted_at IS NULL OR (deleted_at > $3))",
+            action.sql(),
+        );
+        assert_eq!(3, action.binds().len());
+    }
+
+    #[test]
+    fn is_a_noop_without_values() {
+        let action = UpdateAction::new("users")
+            .and_where("id", "=", 3)
+            .returning(&["*"]);
+
+        assert!(action.is_noop());
+        assert!(!action.set("first_name", 1).is_noop());
+    }
+}